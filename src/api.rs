@@ -0,0 +1,118 @@
+use crate::feeds::FeedData;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+/// Latest data for one widget, as served by the HTTP API
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiWidget {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub title: String,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub data: FeedData,
+}
+
+/// Feed data shared between the TUI and the HTTP API server
+#[derive(Clone)]
+pub struct ApiState {
+    widgets: Arc<RwLock<Vec<ApiWidget>>>,
+    #[cfg_attr(not(feature = "http-api"), allow(dead_code))]
+    started: Instant,
+}
+
+impl ApiState {
+    pub fn new(widgets: Vec<ApiWidget>) -> Self {
+        Self {
+            widgets: Arc::new(RwLock::new(widgets)),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn update(&self, id: &str, data: &FeedData) {
+        let mut widgets = self.widgets.write().unwrap();
+        if let Some(widget) = widgets.iter_mut().find(|w| w.id == id) {
+            widget.data = data.clone();
+            widget.updated_at = Some(Utc::now());
+        }
+    }
+}
+
+#[cfg(feature = "http-api")]
+pub use server::spawn_server;
+
+#[cfg(not(feature = "http-api"))]
+pub async fn spawn_server(_state: ApiState, _bind: &str, _port: u16) -> anyhow::Result<()> {
+    anyhow::bail!("feedtui was built without the `http-api` feature")
+}
+
+#[cfg(feature = "http-api")]
+mod server {
+    use super::ApiState;
+    use anyhow::{Context, Result};
+    use axum::extract::{Path, State};
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::{Json, Router};
+    use serde_json::{json, Value};
+
+    pub async fn spawn_server(state: ApiState, bind: &str, port: u16) -> Result<()> {
+        let addr = format!("{}:{}", bind, port);
+        let listener = tokio::net::TcpListener::bind(&addr)
+            .await
+            .with_context(|| format!("could not listen on {}", addr))?;
+
+        let app = Router::new()
+            .route("/health", get(health))
+            .route("/widgets", get(list_widgets))
+            .route("/widgets/:id/items", get(widget_items))
+            .with_state(state);
+
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+        Ok(())
+    }
+
+    async fn health(State(state): State<ApiState>) -> Json<Value> {
+        Json(json!({
+            "status": "ok",
+            "uptime_secs": state.started.elapsed().as_secs(),
+            "widgets": state.widgets.read().unwrap().len(),
+        }))
+    }
+
+    async fn list_widgets(State(state): State<ApiState>) -> Json<Value> {
+        let widgets = state.widgets.read().unwrap();
+        Json(Value::Array(
+            widgets
+                .iter()
+                .map(|w| {
+                    json!({
+                        "id": w.id,
+                        "type": w.kind,
+                        "title": w.title,
+                        "updated_at": w.updated_at,
+                        "item_count": w.data.item_titles().len(),
+                    })
+                })
+                .collect(),
+        ))
+    }
+
+    async fn widget_items(
+        State(state): State<ApiState>,
+        Path(id): Path<String>,
+    ) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+        let widgets = state.widgets.read().unwrap();
+        match widgets.iter().find(|w| w.id == id) {
+            Some(widget) => Ok(Json(json!(widget))),
+            None => Err((
+                StatusCode::NOT_FOUND,
+                Json(json!({ "error": format!("no widget with id {:?}", id) })),
+            )),
+        }
+    }
+}
@@ -1,118 +1,453 @@
-use crate::config::{Config, WidgetConfig};
-use crate::creature::persistence::{default_creature_path, load_or_create_creature, save_creature};
-use crate::creature::Creature;
-use crate::event::{Event, EventHandler};
-use crate::feeds::{FeedData, FeedMessage};
+use crate::api::{self, ApiState, ApiWidget};
+use crate::briefing;
+use crate::clipboard;
+use crate::config::{AlertKind, Config, QuietRefresh, WidgetConfig};
+use crate::crash;
+use crate::creature::persistence::{
+    active_creature_path, default_roster_dir, list_creatures, load_creature,
+    load_or_create_creature, new_creature_path, save_creature, set_active_creature,
+};
+use crate::creature::reactions::Reaction;
+use crate::creature::{CareAction, Creature, CreatureSpecies, SkillEffect};
+use crate::event::{Chords, Event, EventHandler, KeyInput};
+use crate::feeds::cache::FeedCache;
+use crate::feeds::channel::{self, FeedReceiver, FeedSender};
+use crate::feeds::filter::{self, Filter};
+use crate::feeds::http;
+use crate::feeds::retry::RetryPolicy;
+use crate::feeds::rss::{self, DiscoveredFeed};
+use crate::feeds::{FeedData, FeedFetcher, FeedMessage, Progress};
+use crate::ipc::{self, ControlCommand, ControlRequest};
+use crate::notify::Notifier;
+use crate::quiet::{self, QuietHours};
+use crate::random;
+use crate::shell::{self, Multiplexer};
+use crate::speech::Speaker;
+use crate::stats::UsageStats;
 use crate::ui::article_reader::ArticleReader;
-use crate::ui::creature_menu::CreatureMenu;
+use crate::ui::choice::{ChoiceDialog, ChoiceResult};
+use crate::ui::confirm::ConfirmDialog;
+use crate::ui::creature_menu::{CreatureMenu, RosterAction};
+use crate::ui::effects::{Cue, Effects};
+use crate::ui::icons::{self, Icon};
+use crate::ui::input::{InputResult, TextInput};
+use crate::ui::layout::{self, Placement};
+use crate::ui::log_viewer::LogViewer;
+use crate::ui::palette;
+use crate::ui::qr::QrOverlay;
+use crate::ui::snake::{self, Snake};
+use crate::ui::stats_view::StatsView;
 use crate::ui::widgets::{
-    creature::CreatureWidget, github::GithubWidget, hackernews::HackernewsWidget, rss::RssWidget,
-    sports::SportsWidget, stocks::StocksWidget, youtube::YoutubeWidget, FeedWidget,
+    create_widget, creature::CreatureWidget, digest::DigestWidget, github::GithubWidget,
+    rss::RssWidget, sports::SportsWidget, FeedWidget, Handled, SelectedItem, WidgetRequest,
 };
 use anyhow::Result;
+use chrono::{Local, Utc};
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture, KeyCode, KeyModifiers},
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use futures::future::join_all;
+use futures::FutureExt;
 use ratatui::{
-    backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout, Rect},
+    backend::{CrosstermBackend, TestBackend},
+    buffer::Buffer,
+    layout::{Position, Rect},
     Frame, Terminal,
 };
-use std::io::{self, Stdout};
+use std::io::{self, Stdout, Write};
+use std::panic::AssertUnwindSafe;
 use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch, Notify};
+use tokio::task::JoinHandle;
+
+/// Tick rate while the user is active or something is animating
+const ACTIVE_TICK_RATE: Duration = Duration::from_millis(250);
+/// Tick rate once the dashboard has been left alone for `IDLE_AFTER`
+const IDLE_TICK_RATE: Duration = Duration::from_secs(1);
+const IDLE_AFTER: Duration = Duration::from_secs(10);
+/// Refresh interval scale with the Speed Read skill
+const REFRESH_BOOST: f64 = 0.75;
+/// How often "updated 3m ago" labels are redrawn
+const FRESHNESS_REDRAW: Duration = Duration::from_secs(15);
+/// How often usage time is counted towards the stats
+const USAGE_TICK: Duration = Duration::from_secs(10);
+/// Time after the last key or click still counted as using the dashboard
+const USAGE_IDLE_AFTER: Duration = Duration::from_secs(300);
+const USAGE_SAVE_EVERY: Duration = Duration::from_secs(60);
+
+/// Something done with a widget's item, for the usage stats
+enum Usage {
+    Opened,
+    Read,
+}
+
+/// A destructive action waiting on the confirmation dialog
+enum ConfirmAction {
+    ResetCreature,
+    /// Mark notifications read for the GitHub widget at this index
+    MarkNotificationsRead(usize),
+}
+
+/// What the text input popup is collecting
+enum InputTarget {
+    RenameCreature,
+    /// Name for a newly hatched creature
+    HatchCreature,
+    /// A feed URL for the RSS widget at this index
+    AddFeed(usize),
+}
 
 pub struct App {
     config: Config,
     widgets: Vec<Box<dyn FeedWidget>>,
     selected_widget: usize,
+    /// Page of each widget; index-aligned with `widgets`
+    pages: Vec<usize>,
+    /// Which widgets stay when the terminal is too small for all of them
+    priorities: Vec<i32>,
+    current_page: usize,
+    /// Tells fetch loops which page is showing so hidden widgets can pause
+    page_tx: watch::Sender<usize>,
+    quiet_hours: QuietHours,
+    /// Do-not-disturb, quiet regardless of `quiet_hours`
+    dnd: bool,
+    /// Whether alerts are held back now; fetch loops watch it to slow down
+    quiet_tx: watch::Sender<bool>,
     should_quit: bool,
-    feed_rx: mpsc::UnboundedReceiver<FeedMessage>,
-    feed_tx: mpsc::UnboundedSender<FeedMessage>,
+    /// Set when the screen needs redrawing
+    dirty: bool,
+    feed_rx: FeedReceiver,
+    feed_tx: FeedSender,
+    control_rx: mpsc::UnboundedReceiver<ControlRequest>,
+    control_tx: mpsc::UnboundedSender<ControlRequest>,
+    /// Wakes a widget's fetch loop early; index-aligned with `widgets`
+    refresh_signals: Vec<Arc<Notify>>,
+    /// Approximate size of each widget's latest data; index-aligned with `widgets`
+    retained_bytes: Vec<usize>,
+    /// Compiled `filters`; index-aligned with `widgets`
+    filters: Vec<Vec<Filter>>,
+    /// When each widget last got fresh data; index-aligned with `widgets`
+    last_updated: Vec<Option<Instant>>,
+    /// Each widget's polling interval, or `None` for streams; index-aligned
+    /// with `widgets`
+    refresh_intervals: Vec<Option<Duration>>,
+    freshness_drawn: Instant,
+    /// Last data fetched for each widget, shown at once on the next launch
+    cache: FeedCache,
+    cache_path: PathBuf,
+    /// Running fetch loops, aborted on shutdown
+    fetch_tasks: Vec<JoinHandle<()>>,
+    /// Shared with the HTTP API server when `[api] enabled = true`
+    api_state: Option<ApiState>,
+    /// Holds a save file for each creature in the roster
+    roster_dir: PathBuf,
+    /// Save file of the active creature
     creature_path: PathBuf,
     creature_widget_idx: Option<usize>,
+    digest_widget_idxs: Vec<usize>,
     last_xp_tick: Instant,
+    last_input: Instant,
+    usage: UsageStats,
+    usage_path: PathBuf,
+    last_usage_tick: Instant,
+    usage_saved: Instant,
+    /// Where each visible widget was drawn in the last frame, for mouse input
+    widget_areas: Vec<(usize, Rect)>,
+    chords: Chords,
     creature_menu: CreatureMenu,
+    snake: Snake,
     article_reader: ArticleReader,
+    log_viewer: LogViewer,
+    stats_view: StatsView,
+    text_input: TextInput,
+    input_target: Option<InputTarget>,
+    confirm: ConfirmDialog,
+    choice: ChoiceDialog,
+    /// Looking for the feeds a site offers, for the RSS widget at the index
+    feed_discovery: Option<(usize, JoinHandle<Result<Vec<DiscoveredFeed>>>)>,
+    /// Feeds a site offered, waiting for one to be picked in `choice`
+    feed_choices: Option<(usize, Vec<DiscoveredFeed>)>,
+    qr: QrOverlay,
+    effects: Effects,
+    notifier: Notifier,
+    speaker: Speaker,
+    confirm_action: Option<ConfirmAction>,
+    /// Article text to hand to the pager once the main loop can give up
+    /// the terminal
+    pager_text: Option<String>,
     status_message: Option<(String, Instant)>,
 }
 
 impl App {
     pub fn new(config: Config) -> Self {
-        let (feed_tx, feed_rx) = mpsc::unbounded_channel();
+        http::configure(&config);
+        icons::configure(config.general.icons);
+        palette::configure(&config.general.theme);
+        let (feed_tx, feed_rx) = channel::channel();
+        let (control_tx, control_rx) = mpsc::unbounded_channel();
 
         // Load or create creature
-        let creature_path = default_creature_path();
+        let roster_dir = default_roster_dir();
+        let creature_path = active_creature_path(&roster_dir).unwrap_or_else(|e| {
+            tracing::warn!("Could not find the active creature: {}", e);
+            new_creature_path(&roster_dir, "tui")
+        });
         let creature = load_or_create_creature(&creature_path).unwrap_or_else(|e| {
-            eprintln!("Warning: Could not load creature: {}", e);
+            tracing::warn!("Could not load creature: {}", e);
             Creature::default()
         });
 
         let mut widgets: Vec<Box<dyn FeedWidget>> = Vec::new();
         let mut creature_widget_idx = None;
+        let mut digest_widget_idxs = Vec::new();
 
         for widget_config in &config.widgets {
-            let widget: Box<dyn FeedWidget> = match widget_config {
-                WidgetConfig::Hackernews(cfg) => Box::new(HackernewsWidget::new(cfg.clone())),
-                WidgetConfig::Stocks(cfg) => Box::new(StocksWidget::new(cfg.clone())),
-                WidgetConfig::Rss(cfg) => Box::new(RssWidget::new(cfg.clone())),
-                WidgetConfig::Sports(cfg) => Box::new(SportsWidget::new(cfg.clone())),
-                WidgetConfig::Github(cfg) => Box::new(GithubWidget::new(cfg.clone())),
-                WidgetConfig::Youtube(cfg) => Box::new(YoutubeWidget::new(cfg.clone())),
-                WidgetConfig::Creature(cfg) => {
-                    creature_widget_idx = Some(widgets.len());
-                    Box::new(CreatureWidget::new(cfg.clone(), creature.clone()))
-                }
-            };
-            widgets.push(widget);
+            match widget_config {
+                WidgetConfig::Creature(_) => creature_widget_idx = Some(widgets.len()),
+                WidgetConfig::Digest(_) => digest_widget_idxs.push(widgets.len()),
+                _ => {}
+            }
+            widgets.push(create_widget(widget_config, &creature));
         }
+        let refresh_signals = widgets.iter().map(|_| Arc::new(Notify::new())).collect();
+        let retained_bytes = vec![0; widgets.len()];
+        let filters = config
+            .widgets
+            .iter()
+            .map(|cfg| Filter::compile_all(cfg.filters()))
+            .collect();
+        let last_updated = vec![None; widgets.len()];
+        let refresh_intervals = vec![None; widgets.len()];
+        let chords = Chords::new(Duration::from_millis(config.general.chord_timeout_ms));
+        let article_reader = ArticleReader::new(&config.reader);
+        let pages: Vec<usize> = config
+            .widgets
+            .iter()
+            .map(|cfg| cfg.position().page)
+            .collect();
+        let priorities: Vec<i32> = config
+            .widgets
+            .iter()
+            .map(|cfg| cfg.position().priority)
+            .collect();
+        let current_page = pages.iter().copied().min().unwrap_or(0);
+        let selected_widget = pages.iter().position(|&p| p == current_page).unwrap_or(0);
+        let (page_tx, _) = watch::channel(current_page);
+        let quiet_hours = QuietHours::new(&config.quiet_hours);
+        let quiet = quiet_hours.contains(Local::now().naive_local());
+        let (quiet_tx, _) = watch::channel(quiet);
+
+        let api_state = config.api.enabled.then(|| {
+            ApiState::new(
+                widgets
+                    .iter()
+                    .zip(&config.widgets)
+                    .map(|(widget, widget_config)| ApiWidget {
+                        id: widget.id(),
+                        kind: widget_config.kind().to_string(),
+                        title: widget.title().to_string(),
+                        updated_at: None,
+                        data: FeedData::Loading,
+                    })
+                    .collect(),
+            )
+        });
 
-        Self {
+        let cache_path = FeedCache::default_path();
+        let cache = FeedCache::load(&cache_path).unwrap_or_else(|e| {
+            tracing::warn!("Could not load the feed cache: {}", e);
+            FeedCache::default()
+        });
+        let usage_path = UsageStats::default_path();
+        let usage = UsageStats::load(&usage_path).unwrap_or_else(|e| {
+            tracing::warn!("Could not load usage stats: {}", e);
+            UsageStats::default()
+        });
+
+        let mut effects = Effects::new(config.effects.clone());
+        effects.set_muted(quiet);
+        let notifier = Notifier::new(&config);
+
+        let mut app = Self {
             config,
             widgets,
-            selected_widget: 0,
+            selected_widget,
+            pages,
+            priorities,
+            current_page,
+            page_tx,
+            quiet_hours,
+            dnd: false,
+            quiet_tx,
             should_quit: false,
+            dirty: true,
             feed_rx,
             feed_tx,
+            control_rx,
+            control_tx,
+            refresh_signals,
+            retained_bytes,
+            filters,
+            last_updated,
+            refresh_intervals,
+            freshness_drawn: Instant::now(),
+            cache,
+            cache_path,
+            fetch_tasks: Vec::new(),
+            api_state,
+            roster_dir,
             creature_path,
             creature_widget_idx,
+            digest_widget_idxs,
             last_xp_tick: Instant::now(),
+            last_input: Instant::now(),
+            usage,
+            usage_path,
+            last_usage_tick: Instant::now(),
+            usage_saved: Instant::now(),
+            widget_areas: Vec::new(),
+            chords,
             creature_menu: CreatureMenu::default(),
-            article_reader: ArticleReader::default(),
+            snake: Snake::default(),
+            article_reader,
+            log_viewer: LogViewer::new(),
+            stats_view: StatsView::default(),
+            text_input: TextInput::new(),
+            input_target: None,
+            confirm: ConfirmDialog::new(),
+            choice: ChoiceDialog::new(),
+            feed_discovery: None,
+            feed_choices: None,
+            qr: QrOverlay::default(),
+            effects,
+            notifier,
+            speaker: Speaker::default(),
+            confirm_action: None,
+            pager_text: None,
             status_message: None,
+        };
+        app.show_cached_feeds();
+        app
+    }
+
+    /// Fill widgets from the cache until their first fetch comes in
+    fn show_cached_feeds(&mut self) {
+        for idx in 0..self.widgets.len() {
+            let id = self.widgets[idx].id();
+            let Some(cached) = self.cache.get(&id).cloned() else {
+                continue;
+            };
+            let mut data = cached.data;
+            filter::apply(&self.filters[idx], &mut data);
+            self.enforce_memory_limits(idx, &mut data);
+            if let Some(ref state) = self.api_state {
+                state.update(&id, &data);
+            }
+            let age = (Utc::now() - cached.fetched_at)
+                .to_std()
+                .unwrap_or_default();
+            self.last_updated[idx] = Instant::now().checked_sub(age);
+            self.update_digests(idx, &data);
+            self.widgets[idx].update_data(data);
         }
     }
 
     pub async fn run(&mut self) -> Result<()> {
         let mut terminal = Self::setup_terminal()?;
 
-        // Set up panic hook to restore terminal
-        let original_hook = std::panic::take_hook();
-        std::panic::set_hook(Box::new(move |panic| {
-            Self::restore_terminal_panic();
-            original_hook(panic);
-        }));
+        // Restore the terminal and write a crash report if drawing panics
+        crash::mark_ui_thread();
+        crash::install_hook();
 
         // Start feed fetchers
         self.start_feed_fetchers();
 
+        // Accept commands from `feedtui ctl` and other tools
+        let control_socket = match ipc::spawn_server(self.control_tx.clone()).await {
+            Ok(socket) => Some(socket),
+            Err(e) => {
+                self.set_status(&format!("Control socket disabled: {}", e));
+                None
+            }
+        };
+
+        if let Some(state) = self.api_state.clone() {
+            let api = &self.config.api;
+            if let Err(e) = api::spawn_server(state, &api.bind, api.port).await {
+                self.set_status(&format!("HTTP API disabled: {}", e));
+            }
+        }
+
+        briefing::spawn_scheduler(self.config.clone());
+
         // Event handler
-        let tick_rate = Duration::from_millis(250);
-        let mut events = EventHandler::new(tick_rate);
+        let mut events = EventHandler::new(ACTIVE_TICK_RATE);
 
         // Main loop
         while !self.should_quit {
             // Update creature
-            self.tick_creature();
+            if self.tick_creature() {
+                self.dirty = true;
+            }
+            self.tick_usage();
+            if self.update_quiet() {
+                self.dirty = true;
+            }
+
+            // Clear expired status messages and unfinished key sequences
+            if self.clear_expired_status() | self.chords.expire() {
+                self.dirty = true;
+            }
 
-            // Clear expired status messages
-            self.clear_expired_status();
+            if self.article_reader.poll_image() {
+                self.dirty = true;
+            }
+            for widget in &mut self.widgets {
+                if widget.poll() {
+                    self.dirty = true;
+                }
+            }
+            if self.poll_feed_discovery() {
+                self.dirty = true;
+            }
+            if self.effects.tick() {
+                self.dirty = true;
+            }
+            if self.spinner_visible() {
+                self.dirty = true;
+            }
+            if self.freshness_drawn.elapsed() >= FRESHNESS_REDRAW {
+                self.freshness_drawn = Instant::now();
+                self.dirty = true;
+            }
+            if self.snake.tick() {
+                if let Some(points) = self.snake.take_reward() {
+                    self.reward_minigame(points);
+                }
+                self.dirty = true;
+            }
+            if let Some(result) = self.article_reader.poll_translation() {
+                if let Err(e) = result {
+                    self.set_status(&format!("Translation failed: {}", e));
+                }
+                self.dirty = true;
+            }
 
-            // Draw UI
-            terminal.draw(|frame| self.render(frame))?;
+            // Draw UI only when something on screen changed
+            if self.dirty {
+                terminal.draw(|frame| self.render(frame))?;
+                self.article_reader.draw_image(terminal.backend_mut())?;
+                self.dirty = false;
+            }
+            events.set_tick_rate(self.tick_rate());
 
             // Handle events
             tokio::select! {
@@ -123,45 +458,181 @@ impl App {
                 }
                 Some(msg) = self.feed_rx.recv() => {
                     self.handle_feed_message(msg);
+                    self.dirty = true;
+                }
+                Some(request) = self.control_rx.recv() => {
+                    let result = self.handle_control_command(request.command);
+                    let _ = request.reply.send(result);
+                    self.dirty = true;
                 }
             }
+
+            if let Some(text) = self.pager_text.take() {
+                events.pause().await;
+                let result = self.run_pager(&mut terminal, &text);
+                events.resume();
+                if let Err(e) = result {
+                    self.set_status(&format!("Pager failed: {}", e));
+                }
+                self.dirty = true;
+            }
         }
 
+        self.stop_feed_fetchers();
+        self.speaker.stop();
+        drop(control_socket);
+
         // Save creature state before exiting
         self.save_creature_state();
+        self.save_usage();
+        if let Err(e) = self.cache.save(&self.cache_path) {
+            tracing::warn!("Could not save the feed cache: {}", e);
+        }
+
+        // Kitty keeps images on a layer of their own until told to remove them
+        self.article_reader.hide();
+        self.article_reader.draw_image(terminal.backend_mut())?;
 
         Self::restore_terminal(&mut terminal)?;
         Ok(())
     }
 
-    fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
+    pub fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        execute!(
+            stdout,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste
+        )?;
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
         Ok(terminal)
     }
 
-    fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
+    pub fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
         disable_raw_mode()?;
         execute!(
             terminal.backend_mut(),
             LeaveAlternateScreen,
-            DisableMouseCapture
+            DisableMouseCapture,
+            DisableBracketedPaste
         )?;
         terminal.show_cursor()?;
         Ok(())
     }
 
-    fn restore_terminal_panic() {
-        let _ = disable_raw_mode();
-        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+    /// Hand the terminal to the configured pager until it exits
+    fn run_pager(
+        &mut self,
+        terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+        text: &str,
+    ) -> Result<()> {
+        let command = self
+            .config
+            .reader
+            .pager
+            .clone()
+            .or_else(|| std::env::var("PAGER").ok().filter(|p| !p.is_empty()))
+            .unwrap_or_else(|| "less".to_string());
+
+        Self::restore_terminal(terminal)?;
+        let result = Self::pipe_to(&command, text);
+        enable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste
+        )?;
+        terminal.clear()?;
+        self.article_reader.invalidate_image();
+        result
+    }
+
+    fn pipe_to(command: &str, text: &str) -> Result<()> {
+        let mut child = shell::command(command).stdin(Stdio::piped()).spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // The pager may quit before reading everything
+            let _ = stdin.write_all(text.as_bytes());
+        }
+        let status = child.wait()?;
+        if !status.success() {
+            anyhow::bail!("`{}` exited with {}", command, status);
+        }
+        Ok(())
     }
 
     fn handle_event(&mut self, event: Event) {
+        if matches!(event, Event::Key(_) | Event::Mouse(_)) {
+            self.last_input = Instant::now();
+        }
+        // Ticks only matter while the log viewer is tailing new lines
+        if !matches!(event, Event::Tick) || self.log_viewer.visible {
+            self.dirty = true;
+        }
+
         match event {
             Event::Key(key) => {
+                // A pending question must be answered before anything else
+                if self.confirm.visible {
+                    match self.confirm.handle_key(key) {
+                        Some(true) => self.run_confirmed(),
+                        Some(false) => self.confirm_action = None,
+                        None => {}
+                    }
+                    return;
+                }
+
+                if self.choice.visible {
+                    match self.choice.handle_key(key) {
+                        ChoiceResult::Chosen(i) => {
+                            if let Some((idx, mut feeds)) = self.feed_choices.take() {
+                                if i < feeds.len() {
+                                    self.follow_feed(idx, feeds.swap_remove(i).url);
+                                }
+                            }
+                        }
+                        ChoiceResult::Cancelled => self.feed_choices = None,
+                        ChoiceResult::Choosing => {}
+                    }
+                    return;
+                }
+
+                // Any key dismisses the QR code
+                if self.qr.visible {
+                    self.qr.hide();
+                    return;
+                }
+
+                // A text field takes every key while it is open
+                if self.text_input.visible {
+                    self.handle_input_key(key);
+                    return;
+                }
+
+                // The log viewer sits on top of everything else
+                if self.log_viewer.visible {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('L') => self.log_viewer.toggle(),
+                        KeyCode::Down | KeyCode::Char('j') => self.log_viewer.scroll_down(),
+                        KeyCode::Up | KeyCode::Char('k') => self.log_viewer.scroll_up(),
+                        KeyCode::Char('q') => self.should_quit = true,
+                        _ => {}
+                    }
+                    return;
+                }
+
+                if self.stats_view.visible {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('U') => self.stats_view.toggle(),
+                        KeyCode::Char('q') => self.should_quit = true,
+                        _ => {}
+                    }
+                    return;
+                }
+
                 // If article reader is visible, route events there first
                 if self.article_reader.visible {
                     match key.code {
@@ -171,111 +642,909 @@ impl App {
                         KeyCode::PageDown => self.article_reader.page_down(10),
                         KeyCode::PageUp => self.article_reader.page_up(10),
                         KeyCode::Char('o') => self.open_current_in_browser(),
+                        KeyCode::Char('p') => self.pager_text = self.article_reader.plain_text(),
+                        KeyCode::Char('Q') => self.show_qr(self.article_reader.item().cloned()),
+                        KeyCode::Char('s') => self.toggle_speech(self.article_reader.spoken_text()),
+                        KeyCode::Char('T') => self
+                            .article_reader
+                            .toggle_translation(&self.config.translate),
+                        KeyCode::Char('y') => {
+                            self.copy_item(self.article_reader.item().cloned(), false)
+                        }
+                        KeyCode::Char('Y') => {
+                            self.copy_item(self.article_reader.item().cloned(), true)
+                        }
                         _ => {}
                     }
                     return;
                 }
 
+                // Games are launched from the creature menu and sit above it
+                if self.snake.visible {
+                    self.snake.handle_key(key);
+                    if let Some(points) = self.snake.take_reward() {
+                        self.reward_minigame(points);
+                    }
+                    return;
+                }
+
                 // If creature menu is visible, route events there
                 if self.creature_menu.visible {
                     match key.code {
                         KeyCode::Char('t') | KeyCode::Esc => self.creature_menu.toggle(),
                         KeyCode::Tab => self.creature_menu.next_tab(),
                         KeyCode::BackTab => self.creature_menu.prev_tab(),
+                        KeyCode::Char('f') => self.care_for_creature(CareAction::Feed),
+                        KeyCode::Char('p') => self.care_for_creature(CareAction::Play),
+                        KeyCode::Char('s') => self.care_for_creature(CareAction::Rest),
+                        KeyCode::Char('g') => self.snake.start(),
                         KeyCode::Down | KeyCode::Char('j') => {
                             if let Some(creature) = self.get_creature() {
                                 self.creature_menu.scroll_down(&creature);
                             }
                         }
                         KeyCode::Up | KeyCode::Char('k') => self.creature_menu.scroll_up(),
-                        KeyCode::Enter => {
-                            if let Some(idx) = self.creature_widget_idx {
-                                if let Some(widget) = self.widgets.get_mut(idx) {
-                                    if let Some(creature_widget) = widget
-                                        .as_any_mut()
+                        KeyCode::Right | KeyCode::Char('l') => self.creature_menu.next_list(),
+                        KeyCode::Left | KeyCode::Char('h') => self.creature_menu.prev_list(),
+                        KeyCode::Char('R') => {
+                            if let Some(creature) = self.get_creature() {
+                                self.ask_confirm(
+                                    ConfirmAction::ResetCreature,
+                                    format!(
+                                        "Reset {}? Level, XP, points and unlocks will be lost.",
+                                        creature.name
+                                    ),
+                                );
+                            }
+                        }
+                        KeyCode::Char('n') => {
+                            if let Some(creature) = self.get_creature() {
+                                self.open_input(
+                                    InputTarget::RenameCreature,
+                                    format!("Rename {}", creature.name),
+                                    &creature.name,
+                                );
+                            }
+                        }
+                        KeyCode::Enter => match self.creature_menu.roster_action() {
+                            Some(RosterAction::Switch(path)) => self.switch_creature(path),
+                            Some(RosterAction::Hatch) => self.prompt_hatch(),
+                            None => {
+                                let boosted = self.refresh_boosted();
+                                let menu = &mut self.creature_menu;
+                                let changed = match self.creature_widget_idx {
+                                    Some(idx) => self
+                                        .widgets
+                                        .get_mut(idx)
+                                        .and_then(|w| w.as_any_mut())
                                         .and_then(|w| w.downcast_mut::<CreatureWidget>())
-                                    {
-                                        self.creature_menu.select(creature_widget.creature_mut());
+                                        .is_some_and(|w| menu.select(w.creature_mut())),
+                                    None => false,
+                                };
+                                if changed {
+                                    self.save_creature_state();
+                                }
+                                // Skills can change how often feeds refresh
+                                if self.refresh_boosted() != boosted {
+                                    for idx in 0..self.widgets.len() {
+                                        self.restart_fetcher(idx);
                                     }
                                 }
                             }
-                        }
+                        },
                         KeyCode::Char('q') => self.should_quit = true,
                         _ => {}
                     }
                     return;
                 }
 
+                // Two-key sequences are resolved before single keys
+                let key = match self.chords.feed(key) {
+                    KeyInput::Pending => return,
+                    KeyInput::Chord(leader, second) => {
+                        self.handle_chord(leader, second);
+                        return;
+                    }
+                    KeyInput::Key(key) => key,
+                };
+
+                // The focused widget gets first say over its own keys
+                if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
+                    match widget.handle_key(key) {
+                        Handled::Ignored => {}
+                        Handled::Consumed => return,
+                        Handled::Request(request) => {
+                            self.handle_widget_request(request);
+                            return;
+                        }
+                    }
+                }
+
                 // Normal event handling
                 match key.code {
                     KeyCode::Char('q') => self.should_quit = true,
                     KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                         self.should_quit = true
                     }
+                    // A failed widget retries on its own, as its footer offers
+                    KeyCode::Char('r')
+                        if self
+                            .widgets
+                            .get(self.selected_widget)
+                            .is_some_and(|w| w.error().is_some()) =>
+                    {
+                        self.refresh_selected()
+                    }
                     KeyCode::Char('r') => self.refresh_all(),
                     KeyCode::Char('t') => self.toggle_creature_menu(),
+                    KeyCode::Char('L') => self.log_viewer.toggle(),
+                    KeyCode::Char('U') => self.stats_view.toggle(),
+                    KeyCode::Char('D') => self.toggle_dnd(),
                     KeyCode::Char('o') => self.open_selected_in_browser(),
-                    KeyCode::Enter => self.open_article_reader(),
+                    KeyCode::Char('c') => self.open_selected_discussion(),
+                    KeyCode::Char('Q') => self.show_qr(self.selected_item()),
+                    KeyCode::Char('s') => {
+                        self.toggle_speech(self.selected_item().map(|item| item.title))
+                    }
+                    KeyCode::Char('y') => self.copy_item(self.selected_item(), false),
+                    KeyCode::Char('Y') => self.copy_item(self.selected_item(), true),
+                    KeyCode::Enter | KeyCode::Char('v') => self.open_article_reader(),
                     KeyCode::Tab => self.next_widget(),
                     KeyCode::BackTab => self.prev_widget(),
+                    KeyCode::Char(']') => self.switch_page(true),
+                    KeyCode::Char('[') => self.switch_page(false),
                     KeyCode::Down | KeyCode::Char('j') => self.scroll_down(),
                     KeyCode::Up | KeyCode::Char('k') => self.scroll_up(),
-                    KeyCode::Left | KeyCode::Char('h') => self.switch_tab_prev(),
-                    KeyCode::Right | KeyCode::Char('l') => self.switch_tab_next(),
                     _ => {}
                 }
             }
             Event::Tick => {}
-            Event::Resize(_, _) => {}
-            Event::Mouse(_) => {}
+            Event::Resize(_, _) => self.article_reader.invalidate_image(),
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
+            // Pasted text arrives in one piece so it is never replayed as
+            // keybindings; only a text field accepts it
+            Event::Paste(text) => {
+                if self.text_input.visible {
+                    self.text_input.paste(&text);
+                }
+            }
+        }
+    }
+
+    fn ask_confirm(&mut self, action: ConfirmAction, message: String) {
+        self.confirm.open(message);
+        self.confirm_action = Some(action);
+    }
+
+    fn run_confirmed(&mut self) {
+        match self.confirm_action.take() {
+            Some(ConfirmAction::ResetCreature) => self.reset_creature(),
+            Some(ConfirmAction::MarkNotificationsRead(idx)) => self.mark_notifications_read(idx),
+            None => {}
+        }
+    }
+
+    /// Browsing now and then turns up an item for the creature
+    fn roll_item_drop(&mut self) {
+        let Some(creature) = self.creature_mut() else {
+            return;
+        };
+        if let Some(item) = creature.roll_drop(random::unit()) {
+            let message = format!("{} found a {}!", creature.name, item.name);
+            self.set_status(&message);
+            self.save_creature_state();
+        }
+    }
+
+    fn reward_minigame(&mut self, points: u32) {
+        let Some(creature) = self.creature_mut() else {
+            return;
+        };
+        creature.points += points;
+        let message = format!("{} earned {} points", creature.name, points);
+        if points > 0 {
+            self.set_status(&message);
+            self.save_creature_state();
+        }
+    }
+
+    fn care_for_creature(&mut self, action: CareAction) {
+        let Some(creature) = self.creature_mut() else {
+            return;
+        };
+        let message = if creature.care(action) {
+            format!(
+                "{}: {} (-{} pts)",
+                creature.name,
+                action.name(),
+                action.cost()
+            )
+        } else if creature.points < action.cost() {
+            format!("{} needs {} points", action.name(), action.cost())
+        } else {
+            format!("{} is too tired to play", creature.name)
+        };
+        self.set_status(&message);
+        self.save_creature_state();
+    }
+
+    /// Finish what the focused widget started with a key of its own
+    fn handle_widget_request(&mut self, request: WidgetRequest) {
+        let idx = self.selected_widget;
+        match request {
+            WidgetRequest::Save => self.save_creature_state(),
+            WidgetRequest::MarkAllRead => self.ask_confirm(
+                ConfirmAction::MarkNotificationsRead(idx),
+                "Mark all GitHub notifications as read?".to_string(),
+            ),
+            WidgetRequest::AddFeed => {
+                let title = format!("Add feed to {}", self.widgets[idx].title());
+                self.open_input(InputTarget::AddFeed(idx), title, "");
+            }
+            WidgetRequest::ExportCalendar => {
+                let Some(sports) = self.widgets[idx]
+                    .as_any()
+                    .and_then(|w| w.downcast_ref::<SportsWidget>())
+                else {
+                    return;
+                };
+                match sports.export_calendar() {
+                    Ok((path, count)) => {
+                        self.set_status(&format!("Exported {} games to {}", count, path.display()))
+                    }
+                    Err(e) => self.set_status(&format!("Calendar export failed: {:#}", e)),
+                }
+            }
+        }
+    }
+
+    fn reset_creature(&mut self) {
+        let Some(idx) = self.creature_widget_idx else {
+            return;
+        };
+        if let Some(creature_widget) = self.widgets[idx]
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<CreatureWidget>())
+        {
+            *creature_widget.creature_mut() = Creature::default();
+        }
+        self.save_creature_state();
+        self.set_status("Tui has been reset");
+    }
+
+    fn mark_notifications_read(&mut self, idx: usize) {
+        let Some(github) = self.widgets[idx]
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<GithubWidget>())
+        else {
+            return;
+        };
+        let fetcher = github.mark_all_read();
+        let refresh = self.refresh_signals[idx].clone();
+        tokio::spawn(async move {
+            match fetcher.mark_all_read().await {
+                Ok(()) => refresh.notify_one(),
+                Err(e) => tracing::warn!("could not mark notifications read: {:#}", e),
+            }
+        });
+    }
+
+    fn open_input(&mut self, target: InputTarget, title: String, initial: &str) {
+        let history_key = match target {
+            InputTarget::RenameCreature | InputTarget::HatchCreature => "creature-name",
+            InputTarget::AddFeed(_) => "feed-url",
+        };
+        self.text_input.open(title, initial, history_key);
+        self.input_target = Some(target);
+    }
+
+    fn handle_input_key(&mut self, key: KeyEvent) {
+        let value = match self.text_input.handle_key(key) {
+            InputResult::Editing => return,
+            InputResult::Cancelled => {
+                self.input_target = None;
+                return;
+            }
+            InputResult::Submitted(value) => value,
+        };
+
+        match self.input_target.take() {
+            Some(InputTarget::RenameCreature) => self.rename_creature(value),
+            Some(InputTarget::HatchCreature) => self.hatch_creature(value),
+            Some(InputTarget::AddFeed(idx)) => self.add_feed(idx, value),
+            None => {}
+        }
+    }
+
+    fn rename_creature(&mut self, name: String) {
+        let Some(idx) = self.creature_widget_idx else {
+            return;
+        };
+        if let Some(creature_widget) = self.widgets[idx]
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<CreatureWidget>())
+        {
+            creature_widget.creature_mut().name = name;
+        }
+        self.save_creature_state();
+    }
+
+    fn refresh_roster(&mut self) {
+        let roster = list_creatures(&self.roster_dir).unwrap_or_else(|e| {
+            tracing::warn!("Could not list creatures: {}", e);
+            Vec::new()
+        });
+        self.creature_menu
+            .set_roster(roster, self.creature_path.clone());
+    }
+
+    fn prompt_hatch(&mut self) {
+        let Some(creature) = self.get_creature() else {
+            return;
+        };
+        if self.creature_menu.can_hatch(&creature) {
+            self.open_input(
+                InputTarget::HatchCreature,
+                "Name your new creature".to_string(),
+                "",
+            );
+        } else {
+            self.set_status("Level up to hatch another creature");
+        }
+    }
+
+    fn hatch_creature(&mut self, name: String) {
+        let species = CreatureSpecies::all();
+        let species = species[(random::unit() * species.len() as f64) as usize].clone();
+        let path = new_creature_path(&self.roster_dir, &name);
+        match save_creature(&Creature::new(name, species), &path) {
+            Ok(()) => self.switch_creature(path),
+            Err(e) => self.set_status(&format!("Could not hatch: {}", e)),
+        }
+    }
+
+    /// Save the active creature and make the one saved at `path` active
+    fn switch_creature(&mut self, path: PathBuf) {
+        if path == self.creature_path {
+            return;
+        }
+        let mut creature = match load_creature(&path) {
+            Ok(Some(creature)) => creature,
+            Ok(None) => return,
+            Err(e) => {
+                self.set_status(&format!("Could not load creature: {}", e));
+                return;
+            }
+        };
+        self.save_creature_state();
+        creature.start_session();
+
+        let message = format!("{} is now your companion", creature.name);
+        if let Some(active) = self.creature_mut() {
+            *active = creature;
+        }
+        self.creature_path = path;
+        if let Err(e) = set_active_creature(&self.creature_path) {
+            tracing::warn!("Could not remember the active creature: {}", e);
+        }
+        self.save_creature_state();
+        self.refresh_roster();
+        self.set_status(&message);
+    }
+
+    /// Look for the feeds at a site or feed URL; several are offered as a
+    /// choice once found
+    fn add_feed(&mut self, idx: usize, url: String) {
+        let Some(WidgetConfig::Rss(cfg)) = self.config.widgets.get(idx) else {
+            return;
+        };
+        let timeout = http::timeout(cfg.timeout_secs);
+        if let Some((_, task)) = self.feed_discovery.take() {
+            task.abort();
+        }
+        self.set_status(&format!("Looking for feeds at {}", url));
+        let task = tokio::spawn(async move { rss::discover(&url, timeout).await });
+        self.feed_discovery = Some((idx, task));
+    }
+
+    /// Pick up finished feed discovery; true if anything changed
+    fn poll_feed_discovery(&mut self) -> bool {
+        let finished = self
+            .feed_discovery
+            .as_ref()
+            .is_some_and(|(_, task)| task.is_finished());
+        if !finished {
+            return false;
+        }
+        let Some((idx, task)) = self.feed_discovery.take() else {
+            return false;
+        };
+        let result = match task.now_or_never() {
+            Some(Ok(result)) => result,
+            Some(Err(e)) => Err(e.into()),
+            None => return false,
+        };
+
+        match result {
+            Ok(mut feeds) if feeds.len() == 1 => self.follow_feed(idx, feeds.remove(0).url),
+            Ok(feeds) => {
+                let title = format!("{} feeds found", feeds.len());
+                self.choice
+                    .open(title, feeds.iter().map(DiscoveredFeed::label).collect());
+                self.feed_choices = Some((idx, feeds));
+            }
+            Err(e) => self.set_status(&format!("Could not add feed: {:#}", e)),
+        }
+        true
+    }
+
+    /// Follow another feed for the rest of the session; the config file is
+    /// left untouched
+    fn follow_feed(&mut self, idx: usize, url: String) {
+        let Some(rss) = self.widgets[idx]
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<RssWidget>())
+        else {
+            return;
+        };
+        if !rss.add_feed(url.clone()) {
+            self.set_status("Already following that feed");
+            return;
+        }
+        if let Some(WidgetConfig::Rss(cfg)) = self.config.widgets.get_mut(idx) {
+            cfg.feeds.push(url);
+        }
+        self.restart_fetcher(idx);
+        self.set_status("Feed added");
+    }
+
+    /// Scroll whatever is under the cursor: an open overlay, or otherwise the
+    /// hovered widget even if another widget has focus
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        let down = match mouse.kind {
+            MouseEventKind::ScrollDown => true,
+            MouseEventKind::ScrollUp => false,
+            MouseEventKind::Moved if self.config.general.focus_follows_mouse => {
+                self.focus_hovered(mouse.column, mouse.row);
+                return;
+            }
+            _ => return,
+        };
+
+        if self.log_viewer.visible {
+            if down {
+                self.log_viewer.scroll_down();
+            } else {
+                self.log_viewer.scroll_up();
+            }
+        } else if self.article_reader.visible {
+            if down {
+                self.article_reader.scroll_down();
+            } else {
+                self.article_reader.scroll_up();
+            }
+        } else if !self.creature_menu.visible {
+            if let Some(idx) = self.widget_at(mouse.column, mouse.row) {
+                if down {
+                    self.widgets[idx].scroll_down();
+                } else {
+                    self.widgets[idx].scroll_up();
+                }
+            }
+        }
+    }
+
+    /// Focus the widget under the mouse, unless an overlay has the screen
+    fn focus_hovered(&mut self, column: u16, row: u16) {
+        if self.log_viewer.visible
+            || self.stats_view.visible
+            || self.article_reader.visible
+            || self.creature_menu.visible
+            || self.text_input.visible
+            || self.confirm.visible
+            || self.choice.visible
+            || self.qr.visible
+        {
+            return;
+        }
+        if let Some(idx) = self.widget_at(column, row) {
+            if idx != self.selected_widget {
+                self.select_widget(idx);
+            }
+        }
+    }
+
+    /// The widget drawn at a screen position in the last frame
+    fn widget_at(&self, column: u16, row: u16) -> Option<usize> {
+        self.widget_areas
+            .iter()
+            .find(|(_, area)| area.contains(Position::new(column, row)))
+            .map(|&(idx, _)| idx)
+    }
+
+    /// Act on a completed two-key sequence
+    fn handle_chord(&mut self, leader: char, second: KeyCode) {
+        match (leader, second) {
+            ('g', KeyCode::Char('g')) => {
+                if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
+                    widget.scroll_to_top();
+                }
+            }
+            ('g', KeyCode::Char('t')) => self.switch_page(true),
+            ('g', KeyCode::Char('T')) => self.switch_page(false),
+            (' ', KeyCode::Char('r')) => self.refresh_selected(),
+            (' ', KeyCode::Char('l')) => self.log_viewer.toggle(),
+            (_, KeyCode::Esc) => {}
+            (leader, _) => self.set_status(&format!(
+                "No binding for {} {}",
+                key_name(leader),
+                Icon::Ellipsis.glyph()
+            )),
         }
     }
 
     fn handle_feed_message(&mut self, msg: FeedMessage) {
-        for widget in &mut self.widgets {
-            if widget.id() == msg.widget_id {
-                widget.update_data(msg.data.clone());
+        let Some(idx) = self.widgets.iter().position(|w| w.id() == msg.widget_id) else {
+            return;
+        };
+        let mut data = msg.data;
+        if let FeedData::Progress { .. } = data {
+            self.widgets[idx].update_data(data);
+            return;
+        }
+        self.cache.insert(&msg.widget_id, &data);
+        filter::apply(&self.filters[idx], &mut data);
+        self.enforce_memory_limits(idx, &mut data);
+
+        if let Some(ref state) = self.api_state {
+            state.update(&msg.widget_id, &data);
+        }
+        let mut alert = None;
+        if let Some(creature_widget) = self
+            .creature_widget_idx
+            .and_then(|creature_idx| self.widgets[creature_idx].as_any_mut())
+            .and_then(|w| w.downcast_mut::<CreatureWidget>())
+        {
+            let reaction = creature_widget.observe(&data);
+            let creature = creature_widget.creature();
+            if let Some(reaction @ Reaction::Worried { .. }) = reaction {
+                if creature.has_skill_effect(&SkillEffect::StockAlert) {
+                    alert = Some(reaction.message(&creature.name));
+                }
+            }
+        }
+        if let Some(message) = alert {
+            self.alert(AlertKind::Stocks, &message);
+        }
+        if !matches!(data, FeedData::Loading | FeedData::Error(_)) {
+            self.last_updated[idx] = Some(Instant::now());
+            self.update_digests(idx, &data);
+            let title = self.widgets[idx].title().to_string();
+            self.usage
+                .record_fetch(Local::now().date_naive(), &msg.widget_id, &title);
+        }
+        self.widgets[idx].update_data(data);
+    }
+
+    fn update_digests(&mut self, idx: usize, data: &FeedData) {
+        let widget_id = self.widgets[idx].id();
+        let source = self.widgets[idx].title().to_string();
+        for &digest_idx in &self.digest_widget_idxs {
+            if let Some(digest) = self.widgets[digest_idx]
+                .as_any_mut()
+                .and_then(|w| w.downcast_mut::<DigestWidget>())
+            {
+                digest.observe(&widget_id, &source, data);
+                self.last_updated[digest_idx] = Some(Instant::now());
+            }
+        }
+    }
+
+    /// Drop the oldest items beyond the widget's `max_retained_items`, then
+    /// more if all widgets together would exceed `memory_budget_mb`
+    fn enforce_memory_limits(&mut self, idx: usize, data: &mut FeedData) {
+        let general = &self.config.general;
+        let max_items = self.config.widgets[idx]
+            .max_retained_items()
+            .unwrap_or(general.max_retained_items);
+        data.truncate(max_items);
+
+        let budget = general.memory_budget_mb.saturating_mul(1024 * 1024);
+        let others: usize = self
+            .retained_bytes
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != idx)
+            .map(|(_, bytes)| bytes)
+            .sum();
+        let allowed = budget.saturating_sub(others);
+
+        let before = data.item_count();
+        let mut bytes = data.approx_bytes();
+        while bytes > allowed {
+            let count = data.item_count();
+            let keep = (count * allowed / bytes).min(count.saturating_sub(1));
+            data.truncate(keep);
+            if data.item_count() == count {
                 break;
             }
+            bytes = data.approx_bytes();
+        }
+        if data.item_count() < before {
+            tracing::debug!(
+                widget = %self.widgets[idx].id(),
+                "memory budget reached, kept {} of {} items",
+                data.item_count(),
+                before
+            );
         }
+        self.retained_bytes[idx] = bytes;
     }
 
-    fn start_feed_fetchers(&self) {
-        for widget in &self.widgets {
-            let tx = self.feed_tx.clone();
-            let widget_id = widget.id();
-            let fetcher = widget.create_fetcher();
-            let refresh_interval = Duration::from_secs(self.config.general.refresh_interval_secs);
-
-            tokio::spawn(async move {
-                loop {
-                    match fetcher.fetch().await {
-                        Ok(data) => {
-                            let _ = tx.send(FeedMessage {
-                                widget_id: widget_id.clone(),
-                                data,
-                            });
-                        }
-                        Err(e) => {
-                            let _ = tx.send(FeedMessage {
-                                widget_id: widget_id.clone(),
-                                data: FeedData::Error(e.to_string()),
-                            });
-                        }
+    fn start_feed_fetchers(&mut self) {
+        self.stop_feed_fetchers();
+        for idx in 0..self.widgets.len() {
+            let task = self.spawn_fetcher(idx);
+            self.fetch_tasks.push(task);
+        }
+    }
+
+    /// Whether the creature's skills speed up refreshing
+    fn refresh_boosted(&self) -> bool {
+        self.get_creature()
+            .is_some_and(|c| c.has_skill_effect(&SkillEffect::RefreshBoost))
+    }
+
+    /// Replace one widget's fetch loop, e.g. after its sources changed
+    fn restart_fetcher(&mut self, idx: usize) {
+        self.fetch_tasks[idx].abort();
+        self.fetch_tasks[idx] = self.spawn_fetcher(idx);
+    }
+
+    fn spawn_fetcher(&mut self, idx: usize) -> JoinHandle<()> {
+        let retry = RetryPolicy::from_config(&self.config.general);
+        let widget = &self.widgets[idx];
+        let tx = self.feed_tx.clone();
+        let page = self.pages[idx];
+        let mut page_rx = self.page_tx.subscribe();
+        let mut quiet_rx = self.quiet_tx.subscribe();
+        let quiet_refresh = self.quiet_hours.refresh;
+        let refresh = self.refresh_signals[idx].clone();
+        let widget_id = widget.id();
+        let fetcher = widget.create_fetcher();
+        let mut refresh_interval = fetcher.refresh_interval().unwrap_or(Duration::from_secs(
+            self.config.general.refresh_interval_secs,
+        ));
+        if self.refresh_boosted() {
+            refresh_interval = refresh_interval.mul_f64(REFRESH_BOOST);
+        }
+        self.refresh_intervals[idx] = (!fetcher.supports_streaming()).then_some(refresh_interval);
+
+        // Streams stay connected on hidden pages; only polling pauses
+        if fetcher.supports_streaming() {
+            return tokio::spawn(Self::run_stream(
+                fetcher,
+                widget_id,
+                tx,
+                refresh,
+                refresh_interval,
+            ));
+        }
+
+        let progress = {
+            let (tx, widget_id) = (tx.clone(), widget_id.clone());
+            Progress::new(move |done, total| {
+                let _ = tx.send(FeedMessage {
+                    widget_id: widget_id.clone(),
+                    data: FeedData::Progress { done, total },
+                });
+            })
+        };
+
+        tokio::spawn(async move {
+            loop {
+                // Widgets on a hidden page wait for it, then refresh at once
+                if page_rx.wait_for(|current| *current == page).await.is_err() {
+                    return;
+                }
+                if quiet_refresh == QuietRefresh::Paused
+                    && quiet_rx.wait_for(|quiet| !*quiet).await.is_err()
+                {
+                    return;
+                }
+
+                // A manual refresh drops the fetch in flight and starts over
+                let result = tokio::select! {
+                    result = retry.fetch(fetcher.as_ref(), &progress) => result,
+                    _ = refresh.notified() => continue,
+                };
+                match result {
+                    Ok(data) => {
+                        tracing::debug!(widget = %widget_id, "fetched");
+                        let _ = tx.send(FeedMessage {
+                            widget_id: widget_id.clone(),
+                            data,
+                        });
+                    }
+                    Err(e) => {
+                        tracing::warn!(widget = %widget_id, "fetch failed: {:#}", e);
+                        let _ = tx.send(FeedMessage {
+                            widget_id: widget_id.clone(),
+                            data: FeedData::Error(http::error_message(&e)),
+                        });
+                    }
+                }
+                let interval = if quiet_refresh == QuietRefresh::Slow && *quiet_rx.borrow() {
+                    refresh_interval.mul_f64(quiet::SLOW_REFRESH)
+                } else {
+                    refresh_interval
+                };
+                tokio::select! {
+                    _ = tokio::time::sleep(retry.jittered(interval)) => {}
+                    _ = refresh.notified() => {}
+                    _ = page_rx.changed() => {}
+                    _ = quiet_rx.changed() => {}
+                }
+            }
+        })
+    }
+
+    /// Abort every fetch loop along with any requests still in flight
+    fn stop_feed_fetchers(&mut self) {
+        for task in self.fetch_tasks.drain(..) {
+            task.abort();
+        }
+    }
+
+    /// Forward pushed updates from a streaming source, resubscribing after
+    /// `retry_interval` whenever the stream ends or right away on a refresh
+    async fn run_stream(
+        fetcher: Box<dyn FeedFetcher>,
+        widget_id: String,
+        tx: FeedSender,
+        refresh: Arc<Notify>,
+        retry_interval: Duration,
+    ) {
+        loop {
+            let (data_tx, mut data_rx) = mpsc::unbounded_channel();
+            let forward_tx = tx.clone();
+            let forward_id = widget_id.clone();
+            let forward = tokio::spawn(async move {
+                while let Some(data) = data_rx.recv().await {
+                    let msg = FeedMessage {
+                        widget_id: forward_id.clone(),
+                        data,
+                    };
+                    if forward_tx.send(msg).is_err() {
+                        break;
                     }
-                    tokio::time::sleep(refresh_interval).await;
                 }
             });
+
+            let stream = AssertUnwindSafe(fetcher.subscribe(data_tx)).catch_unwind();
+            let result = tokio::select! {
+                result = stream => match result {
+                    Ok(result) => result,
+                    Err(panic) => Err(anyhow::anyhow!(
+                        "stream panicked: {}",
+                        crash::panic_message(&*panic)
+                    )),
+                },
+                // A manual refresh reconnects right away. Sources such as
+                // webhooks keep a sender of their own, so the forwarder
+                // would never see the channel close.
+                _ = refresh.notified() => {
+                    forward.abort();
+                    continue;
+                }
+            };
+            let _ = forward.await;
+            if tx.is_closed() {
+                return;
+            }
+            if let Err(e) = result {
+                tracing::warn!(widget = %widget_id, "stream ended: {:#}", e);
+                let _ = tx.send(FeedMessage {
+                    widget_id: widget_id.clone(),
+                    data: FeedData::Error(http::error_message(&e)),
+                });
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(retry_interval) => {}
+                _ = refresh.notified() => {}
+            }
+        }
+    }
+
+    /// Run every widget's fetcher once and apply the results
+    pub async fn fetch_once(&mut self) {
+        let retry = RetryPolicy::from_config(&self.config.general);
+        let fetches = self.widgets.iter().map(|widget| {
+            let fetcher = widget.create_fetcher();
+            async move { retry.fetch(fetcher.as_ref(), &Progress::none()).await }
+        });
+        let results = join_all(fetches).await;
+
+        for (widget, result) in self.widgets.iter_mut().zip(results) {
+            widget.update_data(result.unwrap_or_else(|e| FeedData::Error(http::error_message(&e))));
+        }
+    }
+
+    /// Render a single frame off-screen and return the resulting buffer
+    pub fn render_to_buffer(&mut self, width: u16, height: u16) -> Result<Buffer> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height))?;
+        terminal.draw(|frame| self.render(frame))?;
+        Ok(terminal.backend().buffer().clone())
+    }
+
+    fn refresh_selected(&mut self) {
+        if let Some(refresh) = self.refresh_signals.get(self.selected_widget) {
+            refresh.notify_one();
+            self.set_status("Refreshing widget");
         }
     }
 
     fn refresh_all(&self) {
-        // Fetchers run continuously, so this triggers an immediate refresh
-        // by restarting the fetchers (simplified for now)
+        for refresh in &self.refresh_signals {
+            refresh.notify_one();
+        }
+    }
+
+    /// Find a widget by id, title (case-insensitive), or 1-based index
+    fn find_widget(&self, name: &str) -> Option<usize> {
+        self.widgets
+            .iter()
+            .position(|w| w.id() == name)
+            .or_else(|| {
+                self.widgets
+                    .iter()
+                    .position(|w| w.title().eq_ignore_ascii_case(name))
+            })
+            .or_else(|| {
+                name.parse::<usize>()
+                    .ok()
+                    .filter(|&n| n >= 1 && n <= self.widgets.len())
+                    .map(|n| n - 1)
+            })
+    }
+
+    fn handle_control_command(&mut self, command: ControlCommand) -> Result<(), String> {
+        match command {
+            ControlCommand::Refresh(None) => self.refresh_all(),
+            ControlCommand::Refresh(Some(name)) => {
+                let idx = self
+                    .find_widget(&name)
+                    .ok_or_else(|| format!("no widget named {:?}", name))?;
+                self.refresh_signals[idx].notify_one();
+            }
+            ControlCommand::Focus(name) => {
+                let idx = self
+                    .find_widget(&name)
+                    .ok_or_else(|| format!("no widget named {:?}", name))?;
+                if self.pages[idx] != self.current_page {
+                    self.show_page(self.pages[idx]);
+                }
+                self.select_widget(idx);
+            }
+            ControlCommand::Notify(message) => self.alert(AlertKind::Notify, &message),
+            ControlCommand::Quit => self.should_quit = true,
+        }
+        Ok(())
     }
 
     fn toggle_creature_menu(&mut self) {
         self.creature_menu.toggle();
+        if self.creature_menu.visible {
+            self.refresh_roster();
+        }
+    }
+
+    fn creature_mut(&mut self) -> Option<&mut Creature> {
+        let widget = self.widgets.get_mut(self.creature_widget_idx?)?;
+        widget
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<CreatureWidget>())
+            .map(|w| w.creature_mut())
     }
 
     fn get_creature(&self) -> Option<Creature> {
@@ -292,99 +1561,112 @@ impl App {
         None
     }
 
+    fn select_widget(&mut self, idx: usize) {
+        if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
+            widget.set_selected(false);
+        }
+        self.selected_widget = idx;
+        self.widgets[idx].set_selected(true);
+    }
+
+    /// Indices of the widgets on the current page
+    fn visible_widgets(&self) -> Vec<usize> {
+        (0..self.widgets.len())
+            .filter(|&idx| self.pages[idx] == self.current_page)
+            .collect()
+    }
+
     fn next_widget(&mut self) {
-        if !self.widgets.is_empty() {
-            self.widgets[self.selected_widget].set_selected(false);
-            self.selected_widget = (self.selected_widget + 1) % self.widgets.len();
-            self.widgets[self.selected_widget].set_selected(true);
+        let visible = self.visible_widgets();
+        if visible.is_empty() {
+            return;
         }
+        let next = match visible.iter().position(|&idx| idx == self.selected_widget) {
+            Some(pos) => visible[(pos + 1) % visible.len()],
+            None => visible[0],
+        };
+        self.select_widget(next);
     }
 
     fn prev_widget(&mut self) {
-        if !self.widgets.is_empty() {
-            self.widgets[self.selected_widget].set_selected(false);
-            self.selected_widget = if self.selected_widget == 0 {
-                self.widgets.len() - 1
-            } else {
-                self.selected_widget - 1
-            };
-            self.widgets[self.selected_widget].set_selected(true);
+        let visible = self.visible_widgets();
+        if visible.is_empty() {
+            return;
         }
+        let prev = match visible.iter().position(|&idx| idx == self.selected_widget) {
+            Some(pos) => visible[(pos + visible.len() - 1) % visible.len()],
+            None => visible[0],
+        };
+        self.select_widget(prev);
     }
 
-    fn scroll_down(&mut self) {
-        if !self.widgets.is_empty() {
-            self.widgets[self.selected_widget].scroll_down();
+    /// Move to the next or previous page that has widgets, wrapping around
+    fn switch_page(&mut self, forward: bool) {
+        let mut pages = self.pages.clone();
+        pages.sort_unstable();
+        pages.dedup();
+        if pages.len() < 2 {
+            return;
         }
+
+        let pos = pages
+            .iter()
+            .position(|&p| p == self.current_page)
+            .unwrap_or(0);
+        let next = if forward {
+            (pos + 1) % pages.len()
+        } else {
+            (pos + pages.len() - 1) % pages.len()
+        };
+        self.show_page(pages[next]);
+        self.set_status(&format!("Page {}/{}", next + 1, pages.len()));
     }
 
-    fn scroll_up(&mut self) {
-        if !self.widgets.is_empty() {
-            self.widgets[self.selected_widget].scroll_up();
+    fn show_page(&mut self, page: usize) {
+        self.current_page = page;
+        self.page_tx.send_replace(page);
+        if let Some(&first) = self.visible_widgets().first() {
+            self.select_widget(first);
         }
     }
 
-    fn switch_tab_next(&mut self) {
+    fn scroll_down(&mut self) {
         if !self.widgets.is_empty() {
-            if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
-                if let Some(github_widget) = widget
-                    .as_any_mut()
-                    .and_then(|w| w.downcast_mut::<GithubWidget>())
-                {
-                    github_widget.next_tab();
-                }
-            }
+            self.widgets[self.selected_widget].scroll_down();
         }
     }
 
-    fn switch_tab_prev(&mut self) {
+    fn scroll_up(&mut self) {
         if !self.widgets.is_empty() {
-            if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
-                if let Some(github_widget) = widget
-                    .as_any_mut()
-                    .and_then(|w| w.downcast_mut::<GithubWidget>())
-                {
-                    github_widget.prev_tab();
-                }
-            }
+            self.widgets[self.selected_widget].scroll_up();
         }
     }
 
     fn render(&mut self, frame: &mut Frame) {
         let area = frame.area();
 
-        // Calculate grid dimensions
-        let (max_row, max_col) = self.calculate_grid_dimensions();
-
-        // Create row constraints
-        let row_constraints: Vec<Constraint> = (0..=max_row)
-            .map(|_| Constraint::Ratio(1, (max_row + 1) as u32))
-            .collect();
-
-        let rows = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(row_constraints)
-            .split(area);
-
-        // Create column constraints for each row
-        for row_idx in 0..=max_row {
-            let col_constraints: Vec<Constraint> = (0..=max_col)
-                .map(|_| Constraint::Ratio(1, (max_col + 1) as u32))
-                .collect();
-
-            let cols = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints(col_constraints)
-                .split(rows[row_idx]);
-
-            // Render widgets in their positions
-            for (widget_idx, widget) in self.widgets.iter().enumerate() {
-                let pos = widget.position();
-                if pos.0 == row_idx && pos.1 <= max_col {
-                    let cell = cols[pos.1];
-                    widget.render(frame, cell, widget_idx == self.selected_widget);
+        let placements: Vec<Placement> = self
+            .visible_widgets()
+            .into_iter()
+            .map(|idx| {
+                let (row, col) = self.widgets[idx].position();
+                Placement {
+                    idx,
+                    row,
+                    col,
+                    priority: self.priorities[idx],
                 }
-            }
+            })
+            .collect();
+        self.widget_areas = layout::arrange(
+            area,
+            &placements,
+            self.selected_widget,
+            self.config.general.compact_width,
+        );
+        for &(idx, cell) in &self.widget_areas {
+            self.widgets[idx].render(frame, cell, idx == self.selected_widget);
+            self.render_freshness(frame, idx, cell);
         }
 
         // Render creature menu overlay if visible
@@ -393,20 +1675,85 @@ impl App {
                 self.creature_menu.render(frame, area, &creature);
             }
         }
+        self.snake.render(frame, area);
 
         // Render article reader overlay if visible
         if self.article_reader.visible {
             self.article_reader.render(frame, area);
         }
 
+        // Render log viewer overlay if visible
+        self.log_viewer.render(frame, area);
+        let earned = self
+            .creature_widget_idx
+            .and_then(|idx| self.widgets[idx].as_any())
+            .and_then(|w| w.downcast_ref::<CreatureWidget>())
+            .map(|w| w.creature().achievements.as_slice());
+        self.stats_view.render(frame, area, &self.usage, earned);
+        self.text_input.render(frame, area);
+        self.confirm.render(frame, area);
+        self.choice.render(frame, area);
+        self.qr.render(frame, area);
+        self.effects.render(frame, area);
+
         // Render status message if present
         self.render_status_message(frame, area);
+        self.render_pending_chord(frame, area);
+        self.render_quiet_badge(frame, area);
+
+        if icons::ascii() {
+            icons::asciify(frame.buffer_mut());
+        }
+    }
+
+    /// Whether a widget on screen is still loading for the first time
+    fn spinner_visible(&self) -> bool {
+        self.widget_areas.iter().any(|&(idx, _)| {
+            Some(idx) != self.creature_widget_idx
+                && !self.digest_widget_idxs.contains(&idx)
+                && self.last_updated[idx].is_none()
+                && self.widgets[idx].error().is_none()
+        })
+    }
+
+    /// "updated 3m ago" on the right of a widget's top border, flagged once
+    /// the data is older than two refresh intervals
+    fn render_freshness(&self, frame: &mut Frame, idx: usize, area: Rect) {
+        use ratatui::{
+            style::{Color, Style},
+            text::Span,
+        };
+
+        let Some(updated) = self.last_updated[idx] else {
+            return;
+        };
+        let age = updated.elapsed();
+        let stale = self.refresh_intervals[idx].is_some_and(|interval| age > interval * 2);
+        let (text, style) = if stale {
+            (
+                format!(" {} updated {} ", Icon::Warning.glyph(), format_age(age)),
+                Style::default().fg(palette::palette().negative),
+            )
+        } else {
+            (
+                format!(" updated {} ", format_age(age)),
+                Style::default().fg(Color::DarkGray),
+            )
+        };
+        let width = Span::raw(text.as_str()).width() as u16;
+        // Leave the widget's own title room
+        let title_width = Span::raw(self.widgets[idx].title()).width() as u16 + 4;
+        if area.width < title_width + width + 2 {
+            return;
+        }
+        let x = area.right() - width - 1;
+        frame.buffer_mut().set_string(x, area.y, text, style);
     }
 
     fn render_status_message(&self, frame: &mut Frame, area: Rect) {
         if let Some((message, _)) = &self.status_message {
-            use ratatui::widgets::{Block, Borders, Clear, Paragraph};
             use ratatui::style::{Color, Style};
+            use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
             let width = (message.len() + 4).min(area.width as usize) as u16;
             let x = area.width.saturating_sub(width).saturating_sub(2);
@@ -428,21 +1775,85 @@ impl App {
         }
     }
 
-    fn calculate_grid_dimensions(&self) -> (usize, usize) {
-        let mut max_row = 0;
-        let mut max_col = 0;
+    /// Show the first key of an unfinished sequence in the bottom-left corner
+    fn render_pending_chord(&self, frame: &mut Frame, area: Rect) {
+        if let Some(leader) = self.chords.pending() {
+            use ratatui::style::{Color, Style};
+            use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+            let text = format!("{} {}", key_name(leader), Icon::Ellipsis.glyph());
+            let width = (text.chars().count() as u16 + 4).min(area.width);
+            let chord_area = Rect::new(2.min(area.width), area.height.saturating_sub(3), width, 3)
+                .intersection(area);
 
-        for widget in &self.widgets {
-            let (row, col) = widget.position();
-            max_row = max_row.max(row);
-            max_col = max_col.max(col);
+            frame.render_widget(Clear, chord_area);
+            let paragraph = Paragraph::new(text)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Cyan)),
+                )
+                .style(Style::default().fg(Color::White));
+            frame.render_widget(paragraph, chord_area);
         }
+    }
 
-        (max_row, max_col)
+    /// Mark the top-right corner while alerts are held back
+    fn render_quiet_badge(&self, frame: &mut Frame, area: Rect) {
+        if !self.is_quiet() {
+            return;
+        }
+        use ratatui::style::{Color, Style};
+        use ratatui::widgets::Paragraph;
+
+        let label = if self.dnd { "DND" } else { "Quiet" };
+        let text = format!(" {} {} ", Icon::Quiet.glyph(), label);
+        let width = (text.chars().count() as u16).min(area.width);
+        let badge_area =
+            Rect::new(area.width.saturating_sub(width + 2), 0, width, 1).intersection(area);
+        frame.render_widget(
+            Paragraph::new(text).style(Style::default().fg(Color::Black).bg(Color::Magenta)),
+            badge_area,
+        );
     }
 
-    /// Tick the creature widget for animations and XP
-    fn tick_creature(&mut self) {
+    /// Tick quickly while the user is active or something is animating, and
+    /// slow down when the dashboard is left alone
+    fn tick_rate(&self) -> Duration {
+        if self.snake.is_running() {
+            return snake::STEP;
+        }
+        let overlay_open = self.creature_menu.visible
+            || self.article_reader.visible
+            || self.log_viewer.visible
+            || self.stats_view.visible
+            || self.text_input.visible
+            || self.confirm.visible
+            || self.choice.visible
+            || self.qr.visible;
+        let creature_animating = self
+            .creature_widget_idx
+            .and_then(|idx| self.widgets.get(idx))
+            .and_then(|w| w.as_any())
+            .and_then(|w| w.downcast_ref::<CreatureWidget>())
+            .is_some_and(|w| w.is_animating());
+
+        if overlay_open
+            || creature_animating
+            || self.effects.is_flashing()
+            || self.status_message.is_some()
+            || self.last_input.elapsed() < IDLE_AFTER
+        {
+            ACTIVE_TICK_RATE
+        } else {
+            IDLE_TICK_RATE
+        }
+    }
+
+    /// Tick the creature widget for animations and XP; returns whether it
+    /// needs redrawing
+    fn tick_creature(&mut self) -> bool {
+        let mut changed = false;
         if let Some(idx) = self.creature_widget_idx {
             // Tick animation
             if let Some(widget) = self.widgets.get_mut(idx) {
@@ -450,17 +1861,21 @@ impl App {
                     .as_any_mut()
                     .and_then(|w| w.downcast_mut::<CreatureWidget>())
                 {
-                    creature_widget.tick();
+                    changed = creature_widget.tick();
 
                     // Award XP every 10 seconds
                     if self.last_xp_tick.elapsed().as_secs() >= 10 {
                         let xp = creature_widget.creature_mut().tick_session(10);
-                        creature_widget.creature_mut().add_experience(xp);
+                        if !creature_widget.creature_mut().add_experience(xp).is_empty() {
+                            self.effects.trigger(Cue::LevelUp);
+                        }
                         self.last_xp_tick = Instant::now();
+                        changed = true;
                     }
                 }
             }
         }
+        changed
     }
 
     /// Open the article reader for the currently selected item
@@ -472,6 +1887,8 @@ impl App {
         if let Some(widget) = self.widgets.get(self.selected_widget) {
             if let Some(item) = widget.get_selected_item() {
                 self.article_reader.show(item);
+                self.record_usage(Usage::Read);
+                self.roll_item_drop();
             } else {
                 self.set_status("No item selected");
             }
@@ -488,6 +1905,8 @@ impl App {
             if let Some(item) = widget.get_selected_item() {
                 if let Some(url) = item.url {
                     self.open_url(&url);
+                    self.record_usage(Usage::Opened);
+                    self.roll_item_drop();
                 } else {
                     self.set_status("No URL available");
                 }
@@ -497,6 +1916,78 @@ impl App {
         }
     }
 
+    /// Open the comments page of the selected item, where it has one
+    fn open_selected_discussion(&mut self) {
+        let Some(widget) = self.widgets.get(self.selected_widget) else {
+            return;
+        };
+        match widget.get_selected_discussion_url() {
+            Some(url) => {
+                self.open_url(&url);
+                self.record_usage(Usage::Opened);
+                self.roll_item_drop();
+            }
+            None => self.set_status("No discussion for this item"),
+        }
+    }
+
+    fn selected_item(&self) -> Option<SelectedItem> {
+        self.widgets.get(self.selected_widget)?.get_selected_item()
+    }
+
+    /// Read `text` aloud, or stop reading if something already is
+    fn toggle_speech(&mut self, text: Option<String>) {
+        if self.speaker.is_speaking() {
+            self.speaker.stop();
+            self.set_status("Stopped reading");
+            return;
+        }
+        let Some(text) = text else {
+            self.set_status("No item selected");
+            return;
+        };
+        match self
+            .speaker
+            .speak(self.config.speech.command.as_deref(), &text)
+        {
+            Ok(()) => self.set_status("Reading aloud; press s to stop"),
+            Err(e) => self.set_status(&format!("Could not read aloud: {}", e)),
+        }
+    }
+
+    fn show_qr(&mut self, item: Option<SelectedItem>) {
+        match item.and_then(|item| item.url) {
+            Some(url) => {
+                if let Err(e) = self.qr.show(&url) {
+                    self.set_status(&format!("Could not make a QR code: {}", e));
+                }
+            }
+            None => self.set_status("No URL available"),
+        }
+    }
+
+    /// Copy an item's URL, or a Markdown link to it with `as_link`
+    fn copy_item(&mut self, item: Option<SelectedItem>, as_link: bool) {
+        let Some(item) = item else {
+            self.set_status("No item selected");
+            return;
+        };
+        let Some(url) = item.url.as_deref() else {
+            self.set_status("No URL available");
+            return;
+        };
+
+        let (text, what) = if as_link {
+            (format!("[{}]({})", item.title, url), "link")
+        } else {
+            (url.to_string(), "URL")
+        };
+        match clipboard::copy(&text) {
+            Ok(()) => self.set_status(&format!("Copied {}", what)),
+            Err(e) => self.set_status(&format!("Could not copy: {}", e)),
+        }
+    }
+
     /// Open the current article reader item in browser
     fn open_current_in_browser(&mut self) {
         if let Some(url) = self.article_reader.get_url() {
@@ -507,26 +1998,178 @@ impl App {
         }
     }
 
-    /// Open a URL in the default browser
-    fn open_url(&mut self, url: &str) {
+    fn open_in_browser(&mut self, url: &str) {
         match open::that(url) {
             Ok(_) => self.set_status("Opening in browser..."),
             Err(e) => self.set_status(&format!("Failed to open browser: {}", e)),
         }
     }
 
+    /// Open a URL with the focused widget's opener, `general.opener`, or
+    /// else the default browser
+    fn open_url(&mut self, url: &str) {
+        let opener = self
+            .config
+            .widgets
+            .get(self.selected_widget)
+            .and_then(|w| w.opener())
+            .or(self.config.general.opener.as_deref());
+        let Some(opener) = opener.map(str::to_string) else {
+            self.open_in_browser(url);
+            return;
+        };
+        let mut command = match opener.strip_prefix("pane:") {
+            // `pane:` commands run in a new tmux or wezterm pane
+            Some(pane_command) => match Multiplexer::detect() {
+                Some(multiplexer) => {
+                    shell::command(&multiplexer.split(&shell::with_url(pane_command.trim(), url)))
+                }
+                None => {
+                    self.open_in_browser(url);
+                    return;
+                }
+            },
+            None => shell::opener(&opener, url),
+        };
+        // Quiet, so the opener can't draw over the dashboard
+        let spawned = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        match spawned {
+            Ok(mut child) => {
+                std::thread::spawn(move || child.wait());
+                self.set_status("Opening...");
+            }
+            Err(e) => self.set_status(&format!("Failed to run `{}`: {}", opener, e)),
+        }
+    }
+
+    /// Show an alert, ring the bell, and pass it on to the notifier's
+    /// channels
+    fn alert(&mut self, kind: AlertKind, message: &str) {
+        self.set_status(message);
+        if self.is_quiet() {
+            return;
+        }
+        self.effects.trigger(Cue::Alert);
+        self.notifier.send(kind, message);
+    }
+
+    fn is_quiet(&self) -> bool {
+        *self.quiet_tx.borrow()
+    }
+
+    /// Follow `quiet_hours` and do-not-disturb; returns whether quiet
+    /// started or ended
+    fn update_quiet(&mut self) -> bool {
+        let quiet = self.dnd || self.quiet_hours.contains(Local::now().naive_local());
+        let changed = self.quiet_tx.send_if_modified(|current| {
+            let changed = *current != quiet;
+            *current = quiet;
+            changed
+        });
+        if changed {
+            self.effects.set_muted(quiet);
+        }
+        changed
+    }
+
+    fn toggle_dnd(&mut self) {
+        self.dnd = !self.dnd;
+        self.update_quiet();
+        if self.dnd {
+            self.set_status("Do not disturb on");
+        } else if self.is_quiet() {
+            self.set_status("Do not disturb off; quiet hours continue");
+        } else {
+            self.set_status("Do not disturb off");
+        }
+    }
+
     /// Set a status message that will be displayed briefly
     fn set_status(&mut self, message: &str) {
         self.status_message = Some((message.to_string(), Instant::now()));
     }
 
-    /// Clear expired status messages
-    fn clear_expired_status(&mut self) {
+    /// Clear expired status messages; returns whether one was cleared
+    fn clear_expired_status(&mut self) -> bool {
         if let Some((_, time)) = &self.status_message {
             if time.elapsed() > Duration::from_secs(3) {
                 self.status_message = None;
+                return true;
             }
         }
+        false
+    }
+
+    /// Count time spent on the dashboard, and on the selected widget, while
+    /// the user is around; saved every minute or so
+    fn tick_usage(&mut self) {
+        let elapsed = self.last_usage_tick.elapsed();
+        if elapsed < USAGE_TICK {
+            return;
+        }
+        self.last_usage_tick = Instant::now();
+        if self.last_input.elapsed() < USAGE_IDLE_AFTER {
+            let today = Local::now().date_naive();
+            let focused = self
+                .widgets
+                .get(self.selected_widget)
+                .map(|w| (w.id(), w.title().to_string()));
+            self.usage.record_active(
+                today,
+                elapsed.as_secs(),
+                focused
+                    .as_ref()
+                    .map(|(id, title)| (id.as_str(), title.as_str())),
+            );
+            self.check_achievements();
+        }
+        if self.usage_saved.elapsed() >= USAGE_SAVE_EVERY {
+            self.save_usage();
+        }
+    }
+
+    /// Count an item opened or read from the selected widget
+    fn record_usage(&mut self, usage: Usage) {
+        let Some(widget) = self.widgets.get(self.selected_widget) else {
+            return;
+        };
+        let (id, title) = (widget.id(), widget.title().to_string());
+        let today = Local::now().date_naive();
+        match usage {
+            Usage::Opened => self.usage.record_opened(today, &id, &title),
+            Usage::Read => {
+                self.usage.record_read(today, &id, &title);
+                if let Some(creature) = self.creature_mut() {
+                    creature.learn();
+                }
+            }
+        }
+        self.check_achievements();
+    }
+
+    /// Award the creature achievements the stats have newly earned
+    fn check_achievements(&mut self) {
+        let totals = self.usage.totals();
+        let Some(creature) = self.creature_mut() else {
+            return;
+        };
+        let earned = creature.earn_achievements(&totals);
+        if let Some(name) = earned.last() {
+            self.set_status(&format!("Achievement unlocked: {}", name));
+            self.effects.trigger(Cue::LevelUp);
+            self.save_creature_state();
+        }
+    }
+
+    fn save_usage(&mut self) {
+        self.usage_saved = Instant::now();
+        if let Err(e) = self.usage.save(&self.usage_path) {
+            tracing::warn!("Could not save usage stats: {}", e);
+        }
     }
 
     /// Save creature state to disk
@@ -538,10 +2181,59 @@ impl App {
                     .and_then(|w| w.downcast_ref::<CreatureWidget>())
                 {
                     if let Err(e) = save_creature(creature_widget.creature(), &self.creature_path) {
-                        eprintln!("Warning: Could not save creature state: {}", e);
+                        tracing::warn!("Could not save creature state: {}", e);
                     }
                 }
             }
         }
     }
 }
+
+/// A coarse age such as `just now` or `3m ago`
+fn format_age(age: Duration) -> String {
+    match age.as_secs() {
+        0..=59 => "just now".to_string(),
+        secs @ 60..=3599 => format!("{}m ago", secs / 60),
+        secs @ 3600..=86399 => format!("{}h ago", secs / 3600),
+        secs => format!("{}d ago", secs / 86400),
+    }
+}
+
+/// How a leader key is shown to the user
+fn key_name(key: char) -> String {
+    match key {
+        ' ' => "space".to_string(),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::webhook::{WebhookFetcher, WebhookState};
+
+    #[tokio::test]
+    async fn refresh_resubscribes_a_stream() {
+        let state = Arc::new(WebhookState::new("127.0.0.1", 0, 10));
+        let (tx, mut rx) = channel::channel();
+        let refresh = Arc::new(Notify::new());
+        let task = tokio::spawn(App::run_stream(
+            Box::new(WebhookFetcher::new(state)),
+            "webhook-1".to_string(),
+            tx,
+            refresh.clone(),
+            Duration::from_secs(60),
+        ));
+
+        let timeout = Duration::from_secs(5);
+        assert!(tokio::time::timeout(timeout, rx.recv())
+            .await
+            .unwrap()
+            .is_some());
+        refresh.notify_one();
+        // Each subscription starts with a snapshot of the events so far
+        let resubscribed = tokio::time::timeout(timeout, rx.recv()).await;
+        assert!(matches!(resubscribed, Ok(Some(_))));
+        task.abort();
+    }
+}
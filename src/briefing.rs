@@ -0,0 +1,377 @@
+use crate::config::{BriefingConfig, Config};
+use crate::digest::{self, DigestItem, Ranker};
+use crate::feeds::{FeedData, SportsEvent, StockQuote};
+use crate::headless::{self, FetchReport};
+use crate::smtp::{self, Email};
+use crate::snapshot::escape_html;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveTime};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Everything that goes into one briefing
+pub struct Briefing {
+    pub generated_at: DateTime<Local>,
+    pub headlines: Vec<DigestItem>,
+    pub portfolio: Vec<StockQuote>,
+    pub scores: Vec<SportsEvent>,
+    /// Widgets that could not be fetched, with why
+    pub failures: Vec<(String, String)>,
+}
+
+impl Briefing {
+    pub fn compile(config: &BriefingConfig, report: &FetchReport) -> Self {
+        // Stocks and sports get sections of their own
+        let ranker = Ranker::new(&config.keywords, &[], f64::INFINITY);
+        let favorite_teams: Vec<String> = config
+            .favorite_teams
+            .iter()
+            .map(|t| t.to_lowercase())
+            .collect();
+        let is_favorite = |team: &str| {
+            let team = team.to_lowercase();
+            favorite_teams.iter().any(|f| team.contains(f.as_str()))
+        };
+
+        let mut candidates = Vec::new();
+        let mut portfolio = Vec::new();
+        let mut scores = Vec::new();
+        let mut failures = Vec::new();
+        for widget in &report.widgets {
+            match &widget.data {
+                FeedData::Stocks(quotes) => portfolio.extend(quotes.iter().cloned()),
+                FeedData::Sports(events) => scores.extend(
+                    events
+                        .iter()
+                        .filter(|e| {
+                            favorite_teams.is_empty()
+                                || is_favorite(&e.home_team)
+                                || is_favorite(&e.away_team)
+                        })
+                        .cloned(),
+                ),
+                FeedData::Error(e) => failures.push((widget.title.clone(), e.clone())),
+                data => candidates.extend(ranker.rank(&widget.title, data)),
+            }
+        }
+
+        Self {
+            generated_at: report.fetched_at.with_timezone(&Local),
+            headlines: digest::top(candidates, config.max_headlines),
+            portfolio,
+            scores,
+            failures,
+        }
+    }
+
+    fn heading(&self) -> String {
+        format!("Briefing for {}", self.generated_at.format("%A, %B %-d"))
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "# {}\n\n_Generated at {}_\n",
+            self.heading(),
+            self.generated_at.format("%H:%M")
+        );
+
+        if !self.headlines.is_empty() {
+            out.push_str("\n## Headlines\n\n");
+            for (i, item) in self.headlines.iter().enumerate() {
+                let title = match &item.url {
+                    Some(url) => format!("[{}]({})", item.title, url),
+                    None => item.title.clone(),
+                };
+                let _ = write!(out, "{}. {} — {}", i + 1, title, item.source);
+                if !item.reason.is_empty() {
+                    let _ = write!(out, ", {}", item.reason);
+                }
+                out.push('\n');
+            }
+        }
+
+        if !self.portfolio.is_empty() {
+            out.push_str("\n## Portfolio\n\n| Symbol | Price | Change |\n|---|---:|---:|\n");
+            for q in &self.portfolio {
+                let _ = writeln!(
+                    out,
+                    "| {} | ${:.2} | {:+.2}% |",
+                    q.symbol, q.price, q.change_percent
+                );
+            }
+        }
+
+        if !self.scores.is_empty() {
+            out.push_str("\n## Scores\n\n");
+            for event in &self.scores {
+                let _ = writeln!(out, "- {}", score_line(event));
+            }
+        }
+
+        if !self.failures.is_empty() {
+            out.push_str("\n## Unavailable\n\n");
+            for (title, error) in &self.failures {
+                let _ = writeln!(out, "- {}: {}", title, error);
+            }
+        }
+        out
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{0}</title>\n</head>\n<body>\n<h1>{0}</h1>\n<p><em>Generated at {1}</em></p>\n",
+            escape_html(&self.heading()),
+            self.generated_at.format("%H:%M")
+        );
+
+        if !self.headlines.is_empty() {
+            out.push_str("<h2>Headlines</h2>\n<ol>\n");
+            for item in &self.headlines {
+                let title = escape_html(&item.title);
+                let title = match &item.url {
+                    Some(url) => format!("<a href=\"{}\">{}</a>", escape_attr(url), title),
+                    None => title,
+                };
+                let mut source = escape_html(&item.source);
+                if !item.reason.is_empty() {
+                    let _ = write!(source, ", {}", escape_html(&item.reason));
+                }
+                let _ = writeln!(out, "<li>{} — {}</li>", title, source);
+            }
+            out.push_str("</ol>\n");
+        }
+
+        if !self.portfolio.is_empty() {
+            out.push_str(
+                "<h2>Portfolio</h2>\n<table>\n<tr><th>Symbol</th><th>Price</th><th>Change</th></tr>\n",
+            );
+            for q in &self.portfolio {
+                let _ = writeln!(
+                    out,
+                    "<tr><td>{}</td><td>${:.2}</td><td>{:+.2}%</td></tr>",
+                    escape_html(&q.symbol),
+                    q.price,
+                    q.change_percent
+                );
+            }
+            out.push_str("</table>\n");
+        }
+
+        if !self.scores.is_empty() {
+            out.push_str("<h2>Scores</h2>\n<ul>\n");
+            for event in &self.scores {
+                let _ = writeln!(out, "<li>{}</li>", escape_html(&score_line(event)));
+            }
+            out.push_str("</ul>\n");
+        }
+
+        if !self.failures.is_empty() {
+            out.push_str("<h2>Unavailable</h2>\n<ul>\n");
+            for (title, error) in &self.failures {
+                let _ = writeln!(
+                    out,
+                    "<li>{}: {}</li>",
+                    escape_html(title),
+                    escape_html(error)
+                );
+            }
+            out.push_str("</ul>\n");
+        }
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+fn score_line(event: &SportsEvent) -> String {
+    match (event.away_score, event.home_score) {
+        (Some(a), Some(h)) => format!(
+            "{} {} - {} {} ({})",
+            event.away_team, a, h, event.home_team, event.status
+        ),
+        _ => format!(
+            "{} vs {} ({})",
+            event.away_team, event.home_team, event.status
+        ),
+    }
+}
+
+fn escape_attr(s: &str) -> String {
+    escape_html(s).replace('"', "&quot;")
+}
+
+fn is_html(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm"))
+}
+
+/// The configured output file, with `~/` expanded
+pub fn output_path(config: &BriefingConfig) -> PathBuf {
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    match config.output.as_deref() {
+        Some(path) => match path.strip_prefix("~/") {
+            Some(rest) => home.join(rest),
+            None => PathBuf::from(path),
+        },
+        None => home.join(".feedtui").join("briefing.md"),
+    }
+}
+
+/// Fetch every widget once, write a briefing to `path`, and email it too
+/// if `email` is set
+async fn write_briefing(config: &Config, path: &Path, email: bool) -> Result<()> {
+    let report = headless::fetch_all(config).await;
+    let briefing = Briefing::compile(&config.briefing, &report);
+    let contents = if is_html(path) {
+        briefing.to_html()
+    } else {
+        briefing.to_markdown()
+    };
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, contents).with_context(|| format!("writing {}", path.display()))?;
+
+    if email {
+        let smtp = config
+            .smtp
+            .as_ref()
+            .context("no [smtp] server configured")?;
+        let email = Email {
+            subject: briefing.heading(),
+            text: briefing.to_markdown(),
+            html: briefing.to_html(),
+        };
+        smtp::send(smtp, &email).await?;
+    }
+    Ok(())
+}
+
+/// Entry point for `feedtui briefing`
+pub async fn run_briefing(config: &Config, out: Option<PathBuf>, email: bool) -> Result<()> {
+    let path = out.unwrap_or_else(|| output_path(&config.briefing));
+    write_briefing(config, &path, email).await?;
+    println!("Briefing written to {}", path.display());
+    if email {
+        println!("Briefing emailed");
+    }
+    Ok(())
+}
+
+/// Write a briefing at each of `[briefing] times` while the dashboard runs,
+/// emailing it when `[smtp]` is set
+pub fn spawn_scheduler(config: Config) {
+    let times: Vec<NaiveTime> = config
+        .briefing
+        .times
+        .iter()
+        .filter_map(|time| {
+            let parsed = NaiveTime::parse_from_str(time.trim(), "%H:%M").ok();
+            if parsed.is_none() {
+                tracing::warn!("Ignoring briefing time {:?}; expected HH:MM", time);
+            }
+            parsed
+        })
+        .collect();
+    if times.is_empty() {
+        return;
+    }
+
+    let path = output_path(&config.briefing);
+    let email = config.smtp.is_some();
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(until_next(&times, Local::now())).await;
+            match write_briefing(&config, &path, email).await {
+                Ok(()) => tracing::info!("Briefing written to {}", path.display()),
+                Err(e) => tracing::warn!("Could not write briefing: {:#}", e),
+            }
+        }
+    });
+}
+
+/// Time left until the soonest of `times`, today or tomorrow
+fn until_next(times: &[NaiveTime], now: DateTime<Local>) -> Duration {
+    let now_time = now.time();
+    times
+        .iter()
+        .map(|&time| {
+            let wait = time - now_time;
+            if wait <= chrono::Duration::zero() {
+                wait + chrono::Duration::days(1)
+            } else {
+                wait
+            }
+        })
+        .min()
+        .and_then(|wait| wait.to_std().ok())
+        .unwrap_or(Duration::from_secs(24 * 60 * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::HnStory;
+    use crate::headless::WidgetSnapshot;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn waits_for_the_next_time_of_day() {
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let now = Local.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap();
+        let times = [at(7, 30), at(18, 0)];
+        assert_eq!(until_next(&times, now), Duration::from_secs(10 * 3600));
+        assert_eq!(
+            until_next(&[at(7, 30)], now),
+            Duration::from_secs(23 * 3600 + 30 * 60)
+        );
+    }
+
+    #[test]
+    fn markdown_has_a_section_per_kind_of_data() {
+        let report = FetchReport {
+            fetched_at: Utc::now(),
+            widgets: vec![
+                WidgetSnapshot {
+                    id: "hackernews-1".to_string(),
+                    kind: "hackernews".to_string(),
+                    title: "Hacker News".to_string(),
+                    data: FeedData::HackerNews(vec![HnStory {
+                        id: 7,
+                        title: "Show HN: feedtui".to_string(),
+                        url: Some("https://example.com".to_string()),
+                        score: 321,
+                        by: "pg".to_string(),
+                        descendants: 12,
+                    }]),
+                },
+                WidgetSnapshot {
+                    id: "stocks-1".to_string(),
+                    kind: "stocks".to_string(),
+                    title: "Stocks".to_string(),
+                    data: FeedData::Stocks(vec![StockQuote {
+                        symbol: "AAPL".to_string(),
+                        price: 190.5,
+                        change: 2.0,
+                        change_percent: 1.06,
+                        name: "Apple".to_string(),
+                    }]),
+                },
+                WidgetSnapshot {
+                    id: "rss-1".to_string(),
+                    kind: "rss".to_string(),
+                    title: "News".to_string(),
+                    data: FeedData::Error("timed out".to_string()),
+                },
+            ],
+        };
+        let markdown = Briefing::compile(&BriefingConfig::default(), &report).to_markdown();
+        assert!(markdown
+            .contains("1. [Show HN: feedtui](https://example.com) — Hacker News, 321 points"));
+        assert!(markdown.contains("| AAPL | $190.50 | +1.06% |"));
+        assert!(markdown.contains("- News: timed out"));
+        assert!(!markdown.contains("## Scores"));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::shell;
+use anyhow::Result;
+use base64::Engine;
+use std::io::Write;
+use std::process::Stdio;
+
+/// Local clipboard tools, tried in order when not connected over SSH
+const LOCAL_TOOLS: &[&str] = &[
+    "pbcopy",
+    "wl-copy",
+    "xclip -selection clipboard",
+    "xsel --clipboard --input",
+    "clip.exe",
+];
+
+/// Put `text` on the clipboard. The OSC 52 escape reaches the clipboard of
+/// whichever machine the terminal runs on, including over SSH; a local tool
+/// covers terminals that ignore it.
+pub fn copy(text: &str) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    stdout.write_all(osc52(text, std::env::var_os("TMUX").is_some()).as_bytes())?;
+    stdout.flush()?;
+
+    if std::env::var_os("SSH_CONNECTION").is_none() {
+        for tool in LOCAL_TOOLS {
+            if copy_with(tool, text).is_ok() {
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn copy_with(tool: &str, text: &str) -> Result<()> {
+    let mut child = shell::command(tool)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    if !child.wait()?.success() {
+        anyhow::bail!("{} failed", tool);
+    }
+    Ok(())
+}
+
+/// The OSC 52 sequence setting the clipboard, wrapped so tmux passes it on
+fn osc52(text: &str, tmux: bool) -> String {
+    let data = base64::engine::general_purpose::STANDARD.encode(text);
+    let sequence = format!("\x1b]52;c;{}\x07", data);
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn osc52_encodes_text_and_wraps_for_tmux() {
+        assert_eq!(osc52("hi", false), "\x1b]52;c;aGk=\x07");
+        assert_eq!(osc52("hi", true), "\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\");
+    }
+}
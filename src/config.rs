@@ -1,6 +1,9 @@
-use anyhow::Result;
+use crate::feeds::filter::Filter;
+use anyhow::{Context, Result};
+use chrono::Weekday;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -8,6 +11,34 @@ pub struct Config {
     pub general: GeneralConfig,
     #[serde(default)]
     pub widgets: Vec<WidgetConfig>,
+    #[serde(default)]
+    pub api: ApiConfig,
+    #[serde(default)]
+    pub reader: ReaderConfig,
+    #[serde(default)]
+    pub speech: SpeechConfig,
+    #[serde(default)]
+    pub translate: TranslateConfig,
+    #[serde(default)]
+    pub sync: SyncConfig,
+    #[serde(default)]
+    pub effects: EffectsConfig,
+    #[serde(default)]
+    pub quiet_hours: QuietHoursConfig,
+    #[serde(default)]
+    pub briefing: BriefingConfig,
+    /// Mail server for emailing briefings; none unless `[smtp]` is set
+    #[serde(default)]
+    pub smtp: Option<SmtpConfig>,
+    /// Bot that alerts are sent to, if `[telegram]` is set
+    #[serde(default)]
+    pub telegram: Option<TelegramConfig>,
+    /// Chat webhooks that alerts are posted to
+    #[serde(default)]
+    pub alert_webhooks: Vec<AlertWebhookConfig>,
+    /// Requests per minute by host, on top of the built-in API limits
+    #[serde(default)]
+    pub rate_limits: HashMap<String, u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +47,115 @@ pub struct GeneralConfig {
     pub refresh_interval_secs: u64,
     #[serde(default = "default_theme")]
     pub theme: String,
+    /// Tries per fetch before showing an error (1 disables retries)
+    #[serde(default = "default_retry_attempts")]
+    pub retry_attempts: u32,
+    /// Delay before the first retry, doubled for each further retry
+    #[serde(default = "default_retry_base_delay_ms")]
+    pub retry_base_delay_ms: u64,
+    /// Random spread for retry delays and refresh intervals (0.1 = ±10%)
+    #[serde(default = "default_jitter")]
+    pub jitter: f64,
+    /// Seconds before a request is abandoned
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Items a widget keeps; older ones are dropped
+    #[serde(default = "default_max_retained_items")]
+    pub max_retained_items: usize,
+    /// Rough cap on feed data held by all widgets together, in megabytes
+    #[serde(default = "default_memory_budget_mb")]
+    pub memory_budget_mb: usize,
+    /// How long to wait for the second key of a sequence like `g g`
+    #[serde(default = "default_chord_timeout_ms")]
+    pub chord_timeout_ms: u64,
+    /// Below this many columns widgets are stacked in a single column
+    #[serde(default = "default_compact_width")]
+    pub compact_width: u16,
+    /// Focus whichever widget the mouse is over
+    #[serde(default)]
+    pub focus_follows_mouse: bool,
+    /// `ascii` draws everything, borders and bars included, in plain ASCII
+    /// for terminals and consoles without Unicode; `auto` picks it from the
+    /// locale
+    #[serde(default)]
+    pub icons: IconStyle,
+    /// Command that opens links, with `{url}` standing for the link (added
+    /// at the end if missing); the system's default browser if unset
+    #[serde(default)]
+    pub opener: Option<String>,
+}
+
+/// One test on an item field; set any of `matches`, `min`, `max_age_hours`
+/// and `is`, and the item must pass all of them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterConfig {
+    pub field: String,
+    /// Regular expression the field must match
+    #[serde(default)]
+    pub matches: Option<String>,
+    /// Smallest number allowed, e.g. a story's score
+    #[serde(default)]
+    pub min: Option<f64>,
+    /// Largest age allowed for a timestamp
+    #[serde(default)]
+    pub max_age_hours: Option<f64>,
+    /// Required value of a yes/no field, e.g. `unread`
+    #[serde(default)]
+    pub is: Option<bool>,
+}
+
+/// Order of a list widget's items. Widgets offer the ones that fit their
+/// data and fall back to the first of those.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// As the source lists them
+    #[default]
+    Feed,
+    /// Newest first
+    Date,
+    /// Highest first
+    Score,
+    /// Grouped by feed or channel
+    Source,
+    /// Alphabetical by title
+    Title,
+}
+
+impl SortOrder {
+    pub fn name(self) -> &'static str {
+        match self {
+            SortOrder::Feed => "feed",
+            SortOrder::Date => "date",
+            SortOrder::Score => "score",
+            SortOrder::Source => "source",
+            SortOrder::Title => "title",
+        }
+    }
+
+    /// `self` if `offered` has it, otherwise the first offered
+    pub fn within(self, offered: &[SortOrder]) -> SortOrder {
+        if offered.contains(&self) {
+            self
+        } else {
+            offered[0]
+        }
+    }
+
+    /// The order after `self` among `offered`, wrapping around
+    pub fn next_within(self, offered: &[SortOrder]) -> SortOrder {
+        let idx = offered.iter().position(|&order| order == self).unwrap_or(0);
+        offered[(idx + 1) % offered.len()]
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IconStyle {
+    #[default]
+    Auto,
+    Unicode,
+    Ascii,
 }
 
 fn default_refresh_interval() -> u64 {
@@ -26,11 +166,363 @@ fn default_theme() -> String {
     "dark".to_string()
 }
 
+fn default_retry_attempts() -> u32 {
+    3
+}
+
+fn default_retry_base_delay_ms() -> u64 {
+    500
+}
+
+fn default_jitter() -> f64 {
+    0.1
+}
+
+fn default_timeout_secs() -> u64 {
+    15
+}
+
+fn default_max_retained_items() -> usize {
+    500
+}
+
+fn default_memory_budget_mb() -> usize {
+    64
+}
+
+fn default_chord_timeout_ms() -> u64 {
+    1000
+}
+
+fn default_compact_width() -> u16 {
+    100
+}
+
 impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
             refresh_interval_secs: default_refresh_interval(),
             theme: default_theme(),
+            retry_attempts: default_retry_attempts(),
+            retry_base_delay_ms: default_retry_base_delay_ms(),
+            jitter: default_jitter(),
+            timeout_secs: default_timeout_secs(),
+            max_retained_items: default_max_retained_items(),
+            memory_budget_mb: default_memory_budget_mb(),
+            chord_timeout_ms: default_chord_timeout_ms(),
+            compact_width: default_compact_width(),
+            focus_follows_mouse: false,
+            icons: IconStyle::default(),
+            opener: None,
+        }
+    }
+}
+
+/// Local HTTP API (requires the `http-api` feature)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_api_bind")]
+    pub bind: String,
+    #[serde(default = "default_api_port")]
+    pub port: u16,
+}
+
+fn default_api_bind() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_api_port() -> u16 {
+    7878
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: default_api_bind(),
+            port: default_api_port(),
+        }
+    }
+}
+
+/// Article reader opened with Enter or `v`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReaderConfig {
+    /// Widest the text column gets, in columns
+    #[serde(default = "default_reader_max_width")]
+    pub max_width: usize,
+    /// How to show thumbnails and `og:image` previews
+    #[serde(default)]
+    pub images: ImageMode,
+    /// Command the article is piped to with `p`; defaults to `$PAGER`,
+    /// then `less`
+    #[serde(default)]
+    pub pager: Option<String>,
+}
+
+/// Terminal graphics protocol for images in the article reader
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageMode {
+    /// Use whichever protocol the terminal is known to support
+    #[default]
+    Auto,
+    Kitty,
+    Iterm,
+    /// Never download or draw images
+    Off,
+}
+
+fn default_reader_max_width() -> usize {
+    80
+}
+
+impl Default for ReaderConfig {
+    fn default() -> Self {
+        Self {
+            max_width: default_reader_max_width(),
+            images: ImageMode::default(),
+            pager: None,
+        }
+    }
+}
+
+/// Reading items aloud with `s`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpeechConfig {
+    /// Command the text is piped to, such as `espeak --stdin` or a `piper`
+    /// pipeline; defaults to `say` on macOS and `espeak --stdin` elsewhere
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+/// Translating articles in the reader with `T`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranslateConfig {
+    #[serde(default)]
+    pub provider: TranslateProvider,
+    /// Language code to translate into, such as `en` or `de`
+    #[serde(default = "default_translate_target")]
+    pub target: String,
+    /// Replaces the provider's public API, e.g. for a self-hosted
+    /// LibreTranslate
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    #[serde(default)]
+    pub api_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TranslateProvider {
+    #[default]
+    Libretranslate,
+    Deepl,
+}
+
+fn default_translate_target() -> String {
+    "en".to_string()
+}
+
+impl Default for TranslateConfig {
+    fn default() -> Self {
+        Self {
+            provider: TranslateProvider::default(),
+            target: default_translate_target(),
+            endpoint: None,
+            api_key: None,
+        }
+    }
+}
+
+/// Where `feedtui creature sync` keeps the roster
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncConfig {
+    /// A directory (committed and pushed if it is a git checkout), or a
+    /// WebDAV or S3 URL that accepts GET and PUT
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Basic auth for URL targets
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+/// A summary of the dashboard written to a file, on demand or on a schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BriefingConfig {
+    /// Local times, such as `07:30`, at which the running dashboard writes one
+    #[serde(default)]
+    pub times: Vec<String>,
+    /// Where to write it (`.html` for HTML, otherwise Markdown); defaults to
+    /// `~/.feedtui/briefing.md`
+    #[serde(default)]
+    pub output: Option<String>,
+    #[serde(default = "default_briefing_headlines")]
+    pub max_headlines: usize,
+    /// Headline words that move a story up
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Only these teams' games are listed, if any are given
+    #[serde(default)]
+    pub favorite_teams: Vec<String>,
+}
+
+fn default_briefing_headlines() -> usize {
+    10
+}
+
+impl Default for BriefingConfig {
+    fn default() -> Self {
+        Self {
+            times: Vec::new(),
+            output: None,
+            max_headlines: default_briefing_headlines(),
+            keywords: Vec::new(),
+            favorite_teams: Vec::new(),
+        }
+    }
+}
+
+/// How the connection to the mail server is secured
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with STARTTLS, usually on port 587
+    #[default]
+    Starttls,
+    /// TLS from the start, usually on port 465
+    Tls,
+    /// No encryption, for a relay on localhost
+    None,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    /// Defaults to the usual port for `security`
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+impl SmtpConfig {
+    pub fn port(&self) -> u16 {
+        self.port.unwrap_or(match self.security {
+            SmtpSecurity::Starttls => 587,
+            SmtpSecurity::Tls => 465,
+            SmtpSecurity::None => 25,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramConfig {
+    /// Token from @BotFather
+    pub bot_token: String,
+    /// Chat the bot posts to: a user or group id, or `@channelname`
+    pub chat_id: String,
+}
+
+/// What raised an alert, for routing it to the right channels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertKind {
+    /// A big stock drop seen by the creature's Stock Alert skill
+    Stocks,
+    /// A message from `feedtui ctl notify`
+    Notify,
+}
+
+/// Payload shape expected by a chat service's incoming webhooks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    #[default]
+    Slack,
+    Discord,
+    Mattermost,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertWebhookConfig {
+    pub url: String,
+    #[serde(default)]
+    pub format: WebhookFormat,
+    /// Kinds of alert posted here; all of them if empty
+    #[serde(default)]
+    pub alerts: Vec<AlertKind>,
+    /// Channel to post in instead of the webhook's own (Slack and Mattermost)
+    #[serde(default)]
+    pub channel: Option<String>,
+}
+
+/// Terminal bell and screen flash for events worth noticing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectsConfig {
+    #[serde(default)]
+    pub bell: bool,
+    /// Briefly flash a border around the screen
+    #[serde(default)]
+    pub flash: bool,
+    #[serde(default = "default_effect_enabled")]
+    pub level_up: bool,
+    /// Creature alerts and `feedtui ctl notify` messages
+    #[serde(default = "default_effect_enabled")]
+    pub alerts: bool,
+    /// When the bell stays silent, such as `22:00-07:00`
+    #[serde(default)]
+    pub quiet_hours: Option<String>,
+}
+
+fn default_effect_enabled() -> bool {
+    true
+}
+
+/// Times when alerts go no further than the status line and the bell stays
+/// silent, optionally refreshing less
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuietHoursConfig {
+    /// Ranges such as `22:00-07:00`
+    #[serde(default)]
+    pub hours: Vec<String>,
+    /// Days a range can start on, such as `["sat", "sun"]`; every day if empty
+    #[serde(default)]
+    pub days: Vec<Weekday>,
+    #[serde(default)]
+    pub refresh: QuietRefresh,
+}
+
+/// How polling widgets refresh during quiet hours
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuietRefresh {
+    #[default]
+    Normal,
+    /// Four times less often
+    Slow,
+    /// Not at all until quiet hours end
+    Paused,
+}
+
+impl Default for EffectsConfig {
+    fn default() -> Self {
+        Self {
+            bell: false,
+            flash: false,
+            level_up: default_effect_enabled(),
+            alerts: default_effect_enabled(),
+            quiet_hours: None,
         }
     }
 }
@@ -45,6 +537,188 @@ pub enum WidgetConfig {
     Creature(CreatureConfig),
     Github(GithubConfig),
     Youtube(YoutubeConfig),
+    Script(ScriptConfig),
+    Wasm(WasmConfig),
+    Scrape(ScrapeConfig),
+    Graphql(GraphqlConfig),
+    Webhook(WebhookConfig),
+    Sse(SseConfig),
+    Digest(DigestConfig),
+    Wallet(WalletConfig),
+    Odds(OddsConfig),
+}
+
+impl WidgetConfig {
+    /// The `type` name used for this widget in the config file
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WidgetConfig::Stocks(_) => "stocks",
+            WidgetConfig::Hackernews(_) => "hackernews",
+            WidgetConfig::Sports(_) => "sports",
+            WidgetConfig::Rss(_) => "rss",
+            WidgetConfig::Creature(_) => "creature",
+            WidgetConfig::Github(_) => "github",
+            WidgetConfig::Youtube(_) => "youtube",
+            WidgetConfig::Script(_) => "script",
+            WidgetConfig::Wasm(_) => "wasm",
+            WidgetConfig::Scrape(_) => "scrape",
+            WidgetConfig::Graphql(_) => "graphql",
+            WidgetConfig::Webhook(_) => "webhook",
+            WidgetConfig::Sse(_) => "sse",
+            WidgetConfig::Digest(_) => "digest",
+            WidgetConfig::Wallet(_) => "wallet",
+            WidgetConfig::Odds(_) => "odds",
+        }
+    }
+
+    /// The widget's `max_retained_items`, if it has one
+    pub fn max_retained_items(&self) -> Option<usize> {
+        match self {
+            WidgetConfig::Stocks(cfg) => cfg.max_retained_items,
+            WidgetConfig::Hackernews(cfg) => cfg.max_retained_items,
+            WidgetConfig::Sports(cfg) => cfg.max_retained_items,
+            WidgetConfig::Rss(cfg) => cfg.max_retained_items,
+            WidgetConfig::Github(cfg) => cfg.max_retained_items,
+            WidgetConfig::Youtube(cfg) => cfg.max_retained_items,
+            WidgetConfig::Script(cfg) => cfg.max_retained_items,
+            WidgetConfig::Wasm(cfg) => cfg.max_retained_items,
+            WidgetConfig::Scrape(cfg) => cfg.max_retained_items,
+            WidgetConfig::Graphql(cfg) => cfg.max_retained_items,
+            WidgetConfig::Sse(cfg) => cfg.max_retained_items,
+            WidgetConfig::Wallet(cfg) => cfg.max_retained_items,
+            WidgetConfig::Odds(cfg) => cfg.max_retained_items,
+            WidgetConfig::Creature(_) | WidgetConfig::Webhook(_) | WidgetConfig::Digest(_) => None,
+        }
+    }
+
+    /// The widget's item filters
+    pub fn filters(&self) -> &[FilterConfig] {
+        match self {
+            WidgetConfig::Stocks(cfg) => &cfg.filters,
+            WidgetConfig::Hackernews(cfg) => &cfg.filters,
+            WidgetConfig::Sports(cfg) => &cfg.filters,
+            WidgetConfig::Rss(cfg) => &cfg.filters,
+            WidgetConfig::Github(cfg) => &cfg.filters,
+            WidgetConfig::Youtube(cfg) => &cfg.filters,
+            WidgetConfig::Script(cfg) => &cfg.filters,
+            WidgetConfig::Wasm(cfg) => &cfg.filters,
+            WidgetConfig::Scrape(cfg) => &cfg.filters,
+            WidgetConfig::Graphql(cfg) => &cfg.filters,
+            WidgetConfig::Webhook(cfg) => &cfg.filters,
+            WidgetConfig::Sse(cfg) => &cfg.filters,
+            WidgetConfig::Wallet(cfg) => &cfg.filters,
+            WidgetConfig::Odds(cfg) => &cfg.filters,
+            WidgetConfig::Creature(_) | WidgetConfig::Digest(_) => &[],
+        }
+    }
+
+    /// The widget's own `opener`, if it has one
+    pub fn opener(&self) -> Option<&str> {
+        match self {
+            WidgetConfig::Stocks(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Hackernews(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Sports(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Rss(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Github(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Youtube(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Script(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Wasm(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Scrape(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Graphql(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Webhook(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Sse(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Wallet(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Odds(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Digest(cfg) => cfg.opener.as_deref(),
+            WidgetConfig::Creature(_) => None,
+        }
+    }
+
+    /// The widget's id, once `Config::assign_widget_ids` has run
+    pub fn id(&self) -> &str {
+        match self {
+            WidgetConfig::Stocks(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Hackernews(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Sports(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Rss(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Creature(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Github(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Youtube(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Script(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Wasm(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Scrape(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Graphql(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Webhook(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Sse(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Wallet(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Odds(cfg) => cfg.id.as_deref(),
+            WidgetConfig::Digest(cfg) => cfg.id.as_deref(),
+        }
+        .unwrap_or_default()
+    }
+
+    pub fn title(&self) -> &str {
+        match self {
+            WidgetConfig::Stocks(cfg) => &cfg.title,
+            WidgetConfig::Hackernews(cfg) => &cfg.title,
+            WidgetConfig::Sports(cfg) => &cfg.title,
+            WidgetConfig::Rss(cfg) => &cfg.title,
+            WidgetConfig::Creature(cfg) => &cfg.title,
+            WidgetConfig::Github(cfg) => &cfg.title,
+            WidgetConfig::Youtube(cfg) => &cfg.title,
+            WidgetConfig::Script(cfg) => &cfg.title,
+            WidgetConfig::Wasm(cfg) => &cfg.title,
+            WidgetConfig::Scrape(cfg) => &cfg.title,
+            WidgetConfig::Graphql(cfg) => &cfg.title,
+            WidgetConfig::Webhook(cfg) => &cfg.title,
+            WidgetConfig::Sse(cfg) => &cfg.title,
+            WidgetConfig::Wallet(cfg) => &cfg.title,
+            WidgetConfig::Odds(cfg) => &cfg.title,
+            WidgetConfig::Digest(cfg) => &cfg.title,
+        }
+    }
+
+    fn id_mut(&mut self) -> &mut Option<String> {
+        match self {
+            WidgetConfig::Stocks(cfg) => &mut cfg.id,
+            WidgetConfig::Hackernews(cfg) => &mut cfg.id,
+            WidgetConfig::Sports(cfg) => &mut cfg.id,
+            WidgetConfig::Rss(cfg) => &mut cfg.id,
+            WidgetConfig::Creature(cfg) => &mut cfg.id,
+            WidgetConfig::Github(cfg) => &mut cfg.id,
+            WidgetConfig::Youtube(cfg) => &mut cfg.id,
+            WidgetConfig::Script(cfg) => &mut cfg.id,
+            WidgetConfig::Wasm(cfg) => &mut cfg.id,
+            WidgetConfig::Scrape(cfg) => &mut cfg.id,
+            WidgetConfig::Graphql(cfg) => &mut cfg.id,
+            WidgetConfig::Webhook(cfg) => &mut cfg.id,
+            WidgetConfig::Sse(cfg) => &mut cfg.id,
+            WidgetConfig::Wallet(cfg) => &mut cfg.id,
+            WidgetConfig::Odds(cfg) => &mut cfg.id,
+            WidgetConfig::Digest(cfg) => &mut cfg.id,
+        }
+    }
+
+    pub fn position(&self) -> &Position {
+        match self {
+            WidgetConfig::Stocks(cfg) => &cfg.position,
+            WidgetConfig::Hackernews(cfg) => &cfg.position,
+            WidgetConfig::Sports(cfg) => &cfg.position,
+            WidgetConfig::Rss(cfg) => &cfg.position,
+            WidgetConfig::Creature(cfg) => &cfg.position,
+            WidgetConfig::Github(cfg) => &cfg.position,
+            WidgetConfig::Youtube(cfg) => &cfg.position,
+            WidgetConfig::Script(cfg) => &cfg.position,
+            WidgetConfig::Wasm(cfg) => &cfg.position,
+            WidgetConfig::Scrape(cfg) => &cfg.position,
+            WidgetConfig::Graphql(cfg) => &cfg.position,
+            WidgetConfig::Webhook(cfg) => &cfg.position,
+            WidgetConfig::Sse(cfg) => &cfg.position,
+            WidgetConfig::Wallet(cfg) => &cfg.position,
+            WidgetConfig::Odds(cfg) => &cfg.position,
+            WidgetConfig::Digest(cfg) => &cfg.position,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +727,19 @@ pub struct CreatureConfig {
     pub title: String,
     #[serde(default)]
     pub show_on_startup: bool,
+    /// Teams whose scoring excites the creature; matched within team names
+    #[serde(default)]
+    pub favorite_teams: Vec<String>,
+    /// Headline words that make the creature curious
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// A stock falling by this many percent worries the creature
+    #[serde(default = "default_big_move_percent")]
+    pub big_move_percent: f64,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
     pub position: Position,
 }
 
@@ -60,10 +747,55 @@ fn default_creature_title() -> String {
     "Tui".to_string()
 }
 
+fn default_big_move_percent() -> f64 {
+    3.0
+}
+
+/// Ranks the best items of every other widget into one list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestConfig {
+    #[serde(default = "default_digest_title")]
+    pub title: String,
+    #[serde(default = "default_digest_max_items")]
+    pub max_items: usize,
+    /// Headline words that move an item up
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Teams whose games make the list; matched within team names
+    #[serde(default)]
+    pub favorite_teams: Vec<String>,
+    /// Stocks moving by at least this many percent make the list
+    #[serde(default = "default_big_move_percent")]
+    pub big_move_percent: f64,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    pub position: Position,
+}
+
+fn default_digest_title() -> String {
+    "Digest".to_string()
+}
+
+fn default_digest_max_items() -> usize {
+    15
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub row: usize,
     pub col: usize,
+    /// Page the widget is shown on; `[` and `]` switch pages
+    #[serde(default)]
+    pub page: usize,
+    /// Higher-priority widgets stay on screen longest when the terminal is
+    /// too small to show them all
+    #[serde(default)]
+    pub priority: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,6 +803,22 @@ pub struct StocksConfig {
     #[serde(default = "default_stocks_title")]
     pub title: String,
     pub symbols: Vec<String>,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
     pub position: Position,
 }
 
@@ -78,6 +826,117 @@ fn default_stocks_title() -> String {
     "Stocks".to_string()
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OddsConfig {
+    #[serde(default = "default_odds_title")]
+    pub title: String,
+    /// Key for The Odds API (https://the-odds-api.com)
+    pub api_key: String,
+    /// Same short names as the sports widget, or Odds API sport keys
+    pub leagues: Vec<String>,
+    /// Only games involving these teams are listed; all if empty
+    #[serde(default)]
+    pub favorite_teams: Vec<String>,
+    /// Where the bookmakers are: `us`, `uk`, `eu` or `au`
+    #[serde(default = "default_odds_regions")]
+    pub regions: String,
+    /// Bookmaker key, such as `draftkings`; the first one listed if unset
+    #[serde(default)]
+    pub bookmaker: Option<String>,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    pub position: Position,
+}
+
+fn default_odds_title() -> String {
+    "Odds".to_string()
+}
+
+fn default_odds_regions() -> String {
+    "us".to_string()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CryptoAsset {
+    Btc,
+    Eth,
+}
+
+impl CryptoAsset {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            CryptoAsset::Btc => "BTC",
+            CryptoAsset::Eth => "ETH",
+        }
+    }
+}
+
+/// A public address whose balance is looked up; no keys are ever needed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletAddress {
+    pub asset: CryptoAsset,
+    pub address: String,
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletConfig {
+    #[serde(default = "default_wallet_title")]
+    pub title: String,
+    pub addresses: Vec<WalletAddress>,
+    /// Currency values are shown in, as a CoinGecko code such as `eur`
+    #[serde(default = "default_wallet_currency")]
+    pub currency: String,
+    /// Ethereum JSON-RPC endpoint balances are read from
+    #[serde(default = "default_eth_rpc")]
+    pub eth_rpc: String,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    pub position: Position,
+}
+
+fn default_wallet_title() -> String {
+    "Wallet".to_string()
+}
+
+fn default_wallet_currency() -> String {
+    "usd".to_string()
+}
+
+fn default_eth_rpc() -> String {
+    "https://ethereum-rpc.publicnode.com".to_string()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HackernewsConfig {
     #[serde(default = "default_hn_title")]
@@ -86,6 +945,25 @@ pub struct HackernewsConfig {
     pub story_count: usize,
     #[serde(default = "default_story_type")]
     pub story_type: String,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Initial order of the list; `S` cycles through the others
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
     pub position: Position,
 }
 
@@ -106,6 +984,29 @@ pub struct SportsConfig {
     #[serde(default = "default_sports_title")]
     pub title: String,
     pub leagues: Vec<String>,
+    /// Teams whose games are exported to the calendar; all games if empty
+    #[serde(default)]
+    pub favorite_teams: Vec<String>,
+    /// Calendar file kept up to date with upcoming games, for calendar apps
+    /// to subscribe to
+    #[serde(default)]
+    pub calendar: Option<String>,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
     pub position: Position,
 }
 
@@ -120,6 +1021,25 @@ pub struct RssConfig {
     pub feeds: Vec<String>,
     #[serde(default = "default_max_items")]
     pub max_items: usize,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Initial order of the list; `S` cycles through the others
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
     pub position: Position,
 }
 
@@ -135,8 +1055,14 @@ fn default_max_items() -> usize {
 pub struct GithubConfig {
     #[serde(default = "default_github_title")]
     pub title: String,
+    #[serde(default)]
     pub token: String,
+    #[serde(default)]
     pub username: String,
+    /// More accounts merged into this widget, each item tagged with its
+    /// account; used alongside `token` and `username` or instead of them
+    #[serde(default)]
+    pub accounts: Vec<GithubAccount>,
     #[serde(default = "default_show_notifications")]
     pub show_notifications: bool,
     #[serde(default = "default_show_pull_requests")]
@@ -149,9 +1075,54 @@ pub struct GithubConfig {
     pub max_pull_requests: usize,
     #[serde(default = "default_max_commits")]
     pub max_commits: usize,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
     pub position: Position,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GithubAccount {
+    /// Tag shown on the account's items; the username if unset
+    #[serde(default)]
+    pub name: Option<String>,
+    pub token: String,
+    pub username: String,
+}
+
+impl GithubAccount {
+    pub fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.username)
+    }
+}
+
+impl GithubConfig {
+    /// `token` and `username`, if set, followed by `accounts`
+    pub fn all_accounts(&self) -> Vec<GithubAccount> {
+        let own = (!self.token.is_empty()).then(|| GithubAccount {
+            name: None,
+            token: self.token.clone(),
+            username: self.username.clone(),
+        });
+        own.into_iter()
+            .chain(self.accounts.iter().cloned())
+            .collect()
+    }
+}
+
 fn default_github_title() -> String {
     "GitHub Dashboard".to_string()
 }
@@ -191,6 +1162,25 @@ pub struct YoutubeConfig {
     pub search_query: Option<String>,
     #[serde(default = "default_max_videos")]
     pub max_videos: usize,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Initial order of the list; `S` cycles through the others
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
     pub position: Position,
 }
 
@@ -202,29 +1192,346 @@ fn default_max_videos() -> usize {
     15
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptConfig {
+    #[serde(default = "default_script_title")]
+    pub title: String,
+    /// Lua file name, relative to ~/.feedtui/plugins/ unless absolute
+    pub script: String,
+    /// Passed to the script's `fetch` function as a table
+    #[serde(default)]
+    pub args: toml::Table,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    pub position: Position,
+}
+
+fn default_script_title() -> String {
+    "Plugin".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WasmConfig {
+    #[serde(default = "default_script_title")]
+    pub title: String,
+    /// WASI module file name, relative to ~/.feedtui/plugins/ unless absolute
+    pub module: String,
+    /// Passed to the module as JSON on stdin
+    #[serde(default)]
+    pub args: toml::Table,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrapeConfig {
+    #[serde(default = "default_scrape_title")]
+    pub title: String,
+    pub url: String,
+    /// CSS selector matching one element per item
+    pub item_selector: String,
+    /// Selector for the title within an item (defaults to the item's text)
+    #[serde(default)]
+    pub title_selector: Option<String>,
+    /// Selector for the link within an item (defaults to the first `a[href]`)
+    #[serde(default)]
+    pub link_selector: Option<String>,
+    #[serde(default)]
+    pub description_selector: Option<String>,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    pub position: Position,
+}
+
+fn default_scrape_title() -> String {
+    "Scraper".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphqlConfig {
+    #[serde(default = "default_graphql_title")]
+    pub title: String,
+    pub endpoint: String,
+    pub query: String,
+    #[serde(default)]
+    pub variables: toml::Table,
+    /// Sent as the `Authorization` header, e.g. "Bearer <token>"
+    #[serde(default)]
+    pub auth_header: Option<String>,
+    /// Dotted path to the array of items, e.g. "data.viewer.repositories.nodes"
+    pub items_path: String,
+    /// Dotted paths within each item
+    pub title_field: String,
+    #[serde(default)]
+    pub url_field: Option<String>,
+    #[serde(default)]
+    pub description_field: Option<String>,
+    #[serde(default)]
+    pub meta_field: Option<String>,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    pub position: Position,
+}
+
+fn default_graphql_title() -> String {
+    "GraphQL".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    #[serde(default = "default_webhook_title")]
+    pub title: String,
+    #[serde(default = "default_webhook_bind")]
+    pub bind: String,
+    #[serde(default = "default_webhook_port")]
+    pub port: u16,
+    #[serde(default = "default_max_events")]
+    pub max_events: usize,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    pub position: Position,
+}
+
+fn default_webhook_title() -> String {
+    "Webhooks".to_string()
+}
+
+fn default_webhook_bind() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_webhook_port() -> u16 {
+    9876
+}
+
+fn default_max_events() -> usize {
+    50
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SseConfig {
+    #[serde(default = "default_sse_title")]
+    pub title: String,
+    pub url: String,
+    /// Item title, with `{event}`, `{id}`, `{data}` or `{data.field}` placeholders
+    #[serde(default = "default_sse_title_template")]
+    pub title_template: String,
+    /// Optional link for each item, using the same placeholders
+    #[serde(default)]
+    pub url_template: Option<String>,
+    #[serde(default = "default_max_events")]
+    pub max_events: usize,
+    /// Overrides `general.timeout_secs` for this widget
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides `general.max_retained_items` for this widget
+    #[serde(default)]
+    pub max_retained_items: Option<usize>,
+    /// Stable name for the widget, used to route its feed; defaults to the
+    /// type and its count, such as `rss-2`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Only items passing all of these are shown
+    #[serde(default)]
+    pub filters: Vec<FilterConfig>,
+    /// Overrides `general.opener` for this widget
+    #[serde(default)]
+    pub opener: Option<String>,
+    pub position: Position,
+}
+
+fn default_sse_title() -> String {
+    "Live".to_string()
+}
+
+fn default_sse_title_template() -> String {
+    "{data}".to_string()
+}
+
 impl Config {
+    /// ~/.feedtui/config.toml (cross-platform)
+    pub fn default_path() -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".feedtui")
+            .join("config.toml")
+    }
+
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
-        let config: Config = toml::from_str(&content)?;
+        let mut config: Config = toml::from_str(&content)?;
+        config.assign_widget_ids();
+        for widget in &config.widgets {
+            for filter in widget.filters() {
+                Filter::new(filter).with_context(|| format!("widget {}", widget.id()))?;
+            }
+            if let WidgetConfig::Github(cfg) = widget {
+                if cfg.all_accounts().is_empty() {
+                    anyhow::bail!(
+                        "widget {} needs a token and username, or accounts",
+                        widget.id()
+                    );
+                }
+            }
+        }
         Ok(config)
     }
+
+    /// Give every widget a unique id: its own if set, otherwise its type and
+    /// how many of that type came before. A repeated id gets a suffix.
+    pub fn assign_widget_ids(&mut self) {
+        let mut counts: HashMap<&'static str, usize> = HashMap::new();
+        let mut taken: HashSet<String> = self
+            .widgets
+            .iter()
+            .map(|widget| widget.id().to_string())
+            .filter(|id| !id.is_empty())
+            .collect();
+        let mut seen = HashSet::new();
+        for widget in &mut self.widgets {
+            let kind = widget.kind();
+            let count = counts.entry(kind).or_default();
+            *count += 1;
+            let wanted = match widget.id_mut().take() {
+                Some(id) if seen.insert(id.clone()) => {
+                    *widget.id_mut() = Some(id);
+                    continue;
+                }
+                Some(id) => {
+                    tracing::warn!("Widget id {:?} is used twice", id);
+                    id
+                }
+                None => format!("{}-{}", kind, count),
+            };
+            let mut id = wanted.clone();
+            let mut n = 1;
+            while taken.contains(&id) {
+                n += 1;
+                id = format!("{}-{}", wanted, n);
+            }
+            taken.insert(id.clone());
+            seen.insert(id.clone());
+            *widget.id_mut() = Some(id);
+        }
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
-        Self {
+        let mut config = Self {
             general: GeneralConfig::default(),
+            api: ApiConfig::default(),
+            reader: ReaderConfig::default(),
+            speech: SpeechConfig::default(),
+            translate: TranslateConfig::default(),
+            sync: SyncConfig::default(),
+            effects: EffectsConfig::default(),
+            quiet_hours: QuietHoursConfig::default(),
+            briefing: BriefingConfig::default(),
+            smtp: None,
+            telegram: None,
+            alert_webhooks: Vec::new(),
+            rate_limits: HashMap::new(),
             widgets: vec![
                 WidgetConfig::Creature(CreatureConfig {
                     title: "Tui".to_string(),
                     show_on_startup: true,
-                    position: Position { row: 0, col: 0 },
+                    favorite_teams: Vec::new(),
+                    keywords: Vec::new(),
+                    big_move_percent: default_big_move_percent(),
+                    id: None,
+                    position: Position {
+                        row: 0,
+                        col: 0,
+                        page: 0,
+                        priority: 0,
+                    },
                 }),
                 WidgetConfig::Hackernews(HackernewsConfig {
                     title: "Hacker News".to_string(),
                     story_count: 10,
                     story_type: "top".to_string(),
-                    position: Position { row: 0, col: 1 },
+                    sort: SortOrder::default(),
+                    timeout_secs: None,
+                    max_retained_items: None,
+                    id: None,
+                    filters: Vec::new(),
+                    opener: None,
+                    position: Position {
+                        row: 0,
+                        col: 1,
+                        page: 0,
+                        priority: 0,
+                    },
                 }),
                 WidgetConfig::Stocks(StocksConfig {
                     title: "Stocks".to_string(),
@@ -234,7 +1541,17 @@ impl Default for Config {
                         "MSFT".to_string(),
                         "NVDA".to_string(),
                     ],
-                    position: Position { row: 1, col: 0 },
+                    timeout_secs: None,
+                    max_retained_items: None,
+                    id: None,
+                    filters: Vec::new(),
+                    opener: None,
+                    position: Position {
+                        row: 1,
+                        col: 0,
+                        page: 0,
+                        priority: 0,
+                    },
                 }),
                 WidgetConfig::Rss(RssConfig {
                     title: "Tech News".to_string(),
@@ -242,14 +1559,87 @@ impl Default for Config {
                         "https://feeds.arstechnica.com/arstechnica/technology-lab".to_string()
                     ],
                     max_items: 10,
-                    position: Position { row: 1, col: 1 },
+                    sort: SortOrder::default(),
+                    timeout_secs: None,
+                    max_retained_items: None,
+                    id: None,
+                    filters: Vec::new(),
+                    opener: None,
+                    position: Position {
+                        row: 1,
+                        col: 1,
+                        page: 0,
+                        priority: 0,
+                    },
                 }),
                 WidgetConfig::Sports(SportsConfig {
                     title: "Sports".to_string(),
                     leagues: vec!["nba".to_string(), "nfl".to_string()],
-                    position: Position { row: 2, col: 0 },
+                    favorite_teams: Vec::new(),
+                    calendar: None,
+                    timeout_secs: None,
+                    max_retained_items: None,
+                    id: None,
+                    filters: Vec::new(),
+                    opener: None,
+                    position: Position {
+                        row: 2,
+                        col: 0,
+                        page: 0,
+                        priority: 0,
+                    },
                 }),
             ],
-        }
+        };
+        config.assign_widget_ids();
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widget_ids_are_unique_and_independent_of_position() {
+        let mut config: Config = toml::from_str(
+            r#"
+[general]
+
+[[widgets]]
+type = "rss"
+feeds = []
+position = { row = 1, col = 1 }
+
+[[widgets]]
+type = "rss"
+id = "news"
+feeds = []
+position = { row = 0, col = 0 }
+
+[[widgets]]
+type = "rss"
+feeds = []
+position = { row = 0, col = 0 }
+
+[[widgets]]
+type = "hackernews"
+id = "news"
+position = { row = 0, col = 1 }
+"#,
+        )
+        .unwrap();
+        config.assign_widget_ids();
+        let ids: Vec<&str> = config.widgets.iter().map(WidgetConfig::id).collect();
+        assert_eq!(ids, ["rss-1", "news", "rss-3", "news-2"]);
+    }
+
+    #[test]
+    fn sort_orders_fall_back_and_cycle_within_offered() {
+        let offered = [SortOrder::Date, SortOrder::Source, SortOrder::Title];
+        assert_eq!(SortOrder::Score.within(&offered), SortOrder::Date);
+        assert_eq!(SortOrder::Source.within(&offered), SortOrder::Source);
+        assert_eq!(SortOrder::Title.next_within(&offered), SortOrder::Date);
+        assert_eq!(SortOrder::Feed.next_within(&offered), SortOrder::Source);
     }
 }
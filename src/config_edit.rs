@@ -0,0 +1,294 @@
+use crate::config::{Config, WidgetConfig};
+use crate::feeds::rss::DiscoveredFeed;
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::path::Path;
+use toml_edit::{Array, ArrayOfTables, DocumentMut, InlineTable, Item, Table, Value};
+
+/// Columns filled before a new widget starts another row
+const COLUMNS: usize = 3;
+
+/// A widget for `feedtui widget add`
+#[derive(Debug, Default)]
+pub struct NewWidget {
+    pub kind: String,
+    pub title: Option<String>,
+    pub id: Option<String>,
+    /// Row and column; the first free cell on `page` if unset
+    pub position: Option<(usize, usize)>,
+    pub page: usize,
+    pub feeds: Vec<String>,
+    pub symbols: Vec<String>,
+    pub leagues: Vec<String>,
+    /// `key=value` settings; values are read as TOML, or as a string if they
+    /// aren't valid TOML
+    pub settings: Vec<String>,
+}
+
+/// Parse `row,col` for `--position`
+pub fn parse_position(value: &str) -> Result<(usize, usize), String> {
+    let (row, col) = value
+        .split_once(',')
+        .ok_or_else(|| "expected row,col".to_string())?;
+    let parse = |n: &str| n.trim().parse::<usize>().map_err(|e| e.to_string());
+    Ok((parse(row)?, parse(col)?))
+}
+
+/// An rss widget for the `pick`th (1-based) of the feeds discovered on a
+/// site, or the first, titled after the feed unless `title` is given
+pub fn rss_widget(
+    found: &[DiscoveredFeed],
+    pick: Option<usize>,
+    title: Option<String>,
+    position: Option<(usize, usize)>,
+    page: usize,
+) -> Result<NewWidget> {
+    let index = pick.unwrap_or(1);
+    let feed = index
+        .checked_sub(1)
+        .and_then(|i| found.get(i))
+        .with_context(|| format!("no feed {}; the site has {}", index, found.len()))?;
+    Ok(NewWidget {
+        kind: "rss".to_string(),
+        title: title.or_else(|| feed.title.clone()),
+        position,
+        page,
+        feeds: vec![feed.url.clone()],
+        ..NewWidget::default()
+    })
+}
+
+/// Append a widget to the config at `path`, creating the file if needed.
+/// Comments and formatting elsewhere in the file are kept. Returns the new
+/// widget's id.
+pub fn add_widget(path: &Path, widget: &NewWidget) -> Result<String> {
+    let content = if path.exists() {
+        std::fs::read_to_string(path)?
+    } else {
+        String::new()
+    };
+    let (content, id) = add_to(&content, widget)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, content)?;
+    Ok(id)
+}
+
+/// Remove the widget with `id` from the config at `path`
+pub fn remove_widget(path: &Path, id: &str) -> Result<()> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read {}", path.display()))?;
+    std::fs::write(path, remove_from(&content, id)?)?;
+    Ok(())
+}
+
+/// Print each configured widget's id, type, position and title
+pub fn list_widgets(config: &Config) {
+    println!("{:<16} {:<12} {:<10} TITLE", "ID", "TYPE", "POSITION");
+    for widget in &config.widgets {
+        let position = widget.position();
+        let mut cell = format!("{},{}", position.row, position.col);
+        if position.page > 0 {
+            cell.push_str(&format!(" p{}", position.page));
+        }
+        println!(
+            "{:<16} {:<12} {:<10} {}",
+            widget.id(),
+            widget.kind(),
+            cell,
+            widget.title()
+        );
+    }
+}
+
+fn add_to(content: &str, widget: &NewWidget) -> Result<(String, String)> {
+    let mut document: DocumentMut = content.parse()?;
+    let config = parse_config(content)?;
+
+    if let Some(id) = &widget.id {
+        if config.widgets.iter().any(|w| w.id() == id) {
+            anyhow::bail!("a widget with id {:?} already exists", id);
+        }
+    }
+
+    let (row, col) = widget
+        .position
+        .unwrap_or_else(|| free_cell(&config, widget.page));
+    let table = widget_table(widget, row, col)?;
+
+    // Catch unknown types and missing fields before touching the file
+    toml::from_str::<WidgetConfig>(&table.to_string())
+        .with_context(|| format!("invalid {} widget", widget.kind))?;
+
+    let widgets = document
+        .entry("widgets")
+        .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+        .context("`widgets` must be written as [[widgets]] tables")?;
+    widgets.push(table);
+
+    let content = document.to_string();
+    let id = parse_config(&content)?
+        .widgets
+        .last()
+        .map(|w| w.id().to_string())
+        .unwrap_or_default();
+    Ok((content, id))
+}
+
+fn remove_from(content: &str, id: &str) -> Result<String> {
+    let mut document: DocumentMut = content.parse()?;
+    let config = parse_config(content)?;
+    let index = config
+        .widgets
+        .iter()
+        .position(|w| w.id() == id)
+        .ok_or_else(|| anyhow::anyhow!("no widget with id {:?}", id))?;
+
+    document
+        .get_mut("widgets")
+        .and_then(Item::as_array_of_tables_mut)
+        .context("`widgets` must be written as [[widgets]] tables")?
+        .remove(index);
+    Ok(document.to_string())
+}
+
+/// The config as the dashboard sees it, ids included
+fn parse_config(content: &str) -> Result<Config> {
+    let mut config: Config = toml::from_str(content)?;
+    config.assign_widget_ids();
+    Ok(config)
+}
+
+/// First cell on `page`, in reading order, that no widget is using
+fn free_cell(config: &Config, page: usize) -> (usize, usize) {
+    let taken: HashSet<(usize, usize)> = config
+        .widgets
+        .iter()
+        .map(|w| w.position())
+        .filter(|p| p.page == page)
+        .map(|p| (p.row, p.col))
+        .collect();
+    (0..)
+        .map(|i| (i / COLUMNS, i % COLUMNS))
+        .find(|cell| !taken.contains(cell))
+        .unwrap_or_default()
+}
+
+fn widget_table(widget: &NewWidget, row: usize, col: usize) -> Result<Table> {
+    let mut table = Table::new();
+    table.insert("type", toml_edit::value(widget.kind.as_str()));
+    if let Some(title) = &widget.title {
+        table.insert("title", toml_edit::value(title.as_str()));
+    }
+    if let Some(id) = &widget.id {
+        table.insert("id", toml_edit::value(id.as_str()));
+    }
+    for (key, values) in [
+        ("feeds", &widget.feeds),
+        ("symbols", &widget.symbols),
+        ("leagues", &widget.leagues),
+    ] {
+        if !values.is_empty() {
+            let array: Array = values.iter().map(String::as_str).collect();
+            table.insert(key, toml_edit::value(array));
+        }
+    }
+    for setting in &widget.settings {
+        let (key, raw) = setting
+            .split_once('=')
+            .with_context(|| format!("expected key=value, got {:?}", setting))?;
+        let value = raw
+            .trim()
+            .parse::<Value>()
+            .unwrap_or_else(|_| Value::from(raw.trim()));
+        table.insert(key.trim(), toml_edit::value(value));
+    }
+
+    let mut position = InlineTable::new();
+    position.insert("row", Value::from(row as i64));
+    position.insert("col", Value::from(col as i64));
+    if widget.page > 0 {
+        position.insert("page", Value::from(widget.page as i64));
+    }
+    table.insert("position", toml_edit::value(position));
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"# my dashboard
+[general]
+refresh_interval_secs = 30
+
+[[widgets]]
+type = "hackernews"
+title = "HN"  # the orange site
+position = { row = 0, col = 0 }
+"#;
+
+    #[test]
+    fn site_resolves_to_the_discovered_feed() {
+        let found = [
+            DiscoveredFeed {
+                url: "https://blog.example.com/feed.xml".to_string(),
+                title: Some("Example Blog".to_string()),
+            },
+            DiscoveredFeed {
+                url: "https://blog.example.com/comments.xml".to_string(),
+                title: None,
+            },
+        ];
+        let (added, _) = add_to(CONFIG, &rss_widget(&found, None, None, None, 0).unwrap()).unwrap();
+        match &parse_config(&added).unwrap().widgets[1] {
+            WidgetConfig::Rss(cfg) => {
+                assert_eq!(cfg.feeds, ["https://blog.example.com/feed.xml"]);
+                assert_eq!(cfg.title, "Example Blog");
+            }
+            other => panic!("expected an rss widget, got {:?}", other),
+        }
+
+        let widget = rss_widget(&found, Some(2), Some("Comments".to_string()), None, 0).unwrap();
+        assert_eq!(widget.feeds, ["https://blog.example.com/comments.xml"]);
+        assert_eq!(widget.title.as_deref(), Some("Comments"));
+        assert!(rss_widget(&found, Some(3), None, None, 0).is_err());
+    }
+
+    #[test]
+    fn adds_and_removes_widgets_keeping_comments() {
+        let widget = NewWidget {
+            kind: "rss".to_string(),
+            feeds: vec!["https://example.com/feed.xml".to_string()],
+            settings: vec!["max_items=5".to_string(), "sort=title".to_string()],
+            ..NewWidget::default()
+        };
+        let (added, id) = add_to(CONFIG, &widget).unwrap();
+        assert_eq!(id, "rss-1");
+        assert!(added.starts_with(CONFIG));
+
+        let config = parse_config(&added).unwrap();
+        match &config.widgets[1] {
+            WidgetConfig::Rss(cfg) => {
+                assert_eq!(cfg.feeds, ["https://example.com/feed.xml"]);
+                assert_eq!(cfg.max_items, 5);
+                assert_eq!((cfg.position.row, cfg.position.col), (0, 1));
+            }
+            other => panic!("expected rss, got {}", other.kind()),
+        }
+
+        assert_eq!(remove_from(&added, "rss-1").unwrap(), CONFIG);
+        assert!(remove_from(CONFIG, "rss-1").is_err());
+    }
+
+    #[test]
+    fn rejects_widgets_missing_required_fields() {
+        let widget = NewWidget {
+            kind: "youtube".to_string(),
+            ..NewWidget::default()
+        };
+        assert!(add_to(CONFIG, &widget).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::feeds::{FeedData, FeedFetcher, Progress};
+use crate::logging;
+use anyhow::{anyhow, Result};
+use chrono::Local;
+use crossterm::{
+    event::{DisableBracketedPaste, DisableMouseCapture},
+    execute,
+    style::Stylize,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+use futures::FutureExt;
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::Cell;
+use std::io;
+use std::panic::{AssertUnwindSafe, PanicHookInfo};
+use std::path::PathBuf;
+
+const ISSUES_URL: &str = "https://github.com/muk2/feedtui/issues";
+/// Log lines included in the on-screen summary
+const SUMMARY_LOG_LINES: usize = 8;
+/// Log lines included in the crash report file
+const REPORT_LOG_LINES: usize = 100;
+
+thread_local! {
+    static UI_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Mark the current thread as the one drawing the TUI. Panics there are
+/// fatal; panics anywhere else (fetch tasks) are only logged.
+pub fn mark_ui_thread() {
+    UI_THREAD.with(|ui| ui.set(true));
+}
+
+/// Replace the default panic output with a terminal restore, a short
+/// summary, and a crash report file
+pub fn install_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let message = panic_message(info.payload());
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}", l.file(), l.line()))
+            .unwrap_or_else(|| "unknown location".to_string());
+
+        if !UI_THREAD.with(|ui| ui.get()) {
+            tracing::error!("panic in background task at {}: {}", location, message);
+            return;
+        }
+
+        restore_terminal();
+        tracing::error!("panic at {}: {}", location, message);
+        let report = write_report(info, &message, &location);
+        print_summary(&message, &location, report.as_ref().ok());
+    }));
+}
+
+/// Run a fetch, turning a panic into an error for the widget to display
+pub async fn fetch_guarded(fetcher: &dyn FeedFetcher, progress: &Progress) -> Result<FeedData> {
+    match AssertUnwindSafe(fetcher.fetch_with_progress(progress))
+        .catch_unwind()
+        .await
+    {
+        Ok(result) => result,
+        Err(panic) => Err(anyhow!("fetcher panicked: {}", panic_message(&*panic))),
+    }
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    );
+}
+
+fn write_report(info: &PanicHookInfo, message: &str, location: &str) -> io::Result<PathBuf> {
+    let dir = dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".feedtui");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "crash-{}.txt",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let mut report = format!(
+        "feedtui {} crash report\n\ntime: {}\nos: {} {}\npanic: {}\nlocation: {}\n\n{}\n\nbacktrace:\n{}\n",
+        env!("CARGO_PKG_VERSION"),
+        Local::now().to_rfc3339(),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        message,
+        location,
+        info,
+        Backtrace::force_capture()
+    );
+    report.push_str("\nrecent log:\n");
+    for line in logging::buffer()
+        .map(|b| b.tail(REPORT_LOG_LINES))
+        .unwrap_or_default()
+    {
+        report.push_str(&format!(
+            "{} {:>5} {}: {}\n",
+            line.time.format("%H:%M:%S"),
+            line.level,
+            line.target,
+            line.message
+        ));
+    }
+
+    std::fs::write(&path, report)?;
+    Ok(path)
+}
+
+fn print_summary(message: &str, location: &str, report: Option<&PathBuf>) {
+    eprintln!();
+    eprintln!("{}", "✗ feedtui crashed".red().bold());
+    eprintln!("  {}", message);
+    eprintln!("  {}", format!("at {}", location).dark_grey());
+
+    let recent = logging::buffer()
+        .map(|b| b.tail(SUMMARY_LOG_LINES))
+        .unwrap_or_default();
+    if !recent.is_empty() {
+        eprintln!();
+        eprintln!("{}", "Recent log:".bold());
+        for line in recent {
+            eprintln!(
+                "  {} {:>5} {}",
+                line.time.format("%H:%M:%S").to_string().dark_grey(),
+                line.level,
+                line.message
+            );
+        }
+    }
+
+    eprintln!();
+    match report {
+        Some(path) => eprintln!(
+            "Crash report written to {}",
+            path.display().to_string().cyan()
+        ),
+        None => eprintln!("Could not write a crash report"),
+    }
+    eprintln!(
+        "Please include it when reporting the problem at {}",
+        ISSUES_URL
+    );
+}
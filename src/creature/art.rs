@@ -1,4 +1,9 @@
-use super::{CreatureMood, CreatureSpecies};
+use super::packs;
+use super::{get_all_items, CreatureAppearance, CreatureMood, CreatureSpecies, ItemKind};
+use ratatui::{
+    style::{Color, Style},
+    text::Line,
+};
 
 /// Get ASCII art for a creature based on species, mood, and outfit
 pub fn get_creature_art(
@@ -7,14 +12,50 @@ pub fn get_creature_art(
     outfit: Option<&str>,
     frame: usize,
 ) -> Vec<String> {
-    // Get base art for species
-    let base_art = get_species_art(species, mood, frame);
+    // User art packs take precedence over the built-in art
+    let pack = packs::find(species.name());
+    let base_art = match pack {
+        Some(pack) => pack.art(mood, frame, mood_to_face(mood)),
+        None => get_species_art(species, mood, frame),
+    };
 
     // Apply outfit modifications if applicable
-    if let Some(outfit_id) = outfit {
-        apply_outfit(outfit_id, base_art)
-    } else {
-        base_art
+    match (outfit, pack) {
+        (Some(outfit_id), Some(pack)) if pack.outfits.contains_key(outfit_id) => {
+            pack.outfits[outfit_id].wrap(base_art)
+        }
+        (Some(outfit_id), _) => apply_outfit(outfit_id, base_art),
+        (None, _) => base_art,
+    }
+}
+
+/// Color the art in the primary color, with the equipped hat above it and
+/// the accessory below it in the secondary color
+pub fn dress(art: Vec<String>, appearance: &CreatureAppearance) -> Vec<Line<'static>> {
+    let items = get_all_items();
+    let item_art = |id: &Option<String>| id.as_ref().and_then(|id| items.get(id)).map(|i| &i.kind);
+    let body = Style::default().fg(appearance.primary_color.to_ratatui_color());
+    let trim = Style::default().fg(appearance.secondary_color.to_ratatui_color());
+
+    let mut lines: Vec<Line> = art
+        .into_iter()
+        .map(|line| Line::styled(line, body))
+        .collect();
+    if let Some(ItemKind::Hat(hat)) = item_art(&appearance.hat) {
+        lines.insert(0, Line::styled(hat.clone(), trim));
+    }
+    if let Some(ItemKind::Accessory(accessory)) = item_art(&appearance.accessory) {
+        lines.push(Line::styled(accessory.clone(), trim));
+    }
+    lines
+}
+
+/// Color of the equipped background, if any
+pub fn background_color(appearance: &CreatureAppearance) -> Option<Color> {
+    let id = appearance.background.as_ref()?;
+    match get_all_items().remove(id)?.kind {
+        ItemKind::Background(color) => Some(color.to_ratatui_color()),
+        _ => None,
     }
 }
 
@@ -30,6 +71,8 @@ fn get_species_art(species: &CreatureSpecies, mood: &CreatureMood, frame: usize)
         CreatureSpecies::Robot => get_robot_art(mood, frame),
         CreatureSpecies::Spirit => get_spirit_art(mood, frame),
         CreatureSpecies::Octopus => get_octopus_art(mood, frame),
+        // Its pack is gone, so fall back to the simplest shape
+        CreatureSpecies::Custom(_) => get_blob_art(mood, frame),
     }
 }
 
@@ -255,6 +298,7 @@ fn mood_to_face(mood: &CreatureMood) -> &'static str {
         CreatureMood::Proud => "^v^",
         CreatureMood::Lonely => ";_;",
         CreatureMood::Curious => "?.?",
+        CreatureMood::Worried => ">_<",
     }
 }
 
@@ -314,6 +358,7 @@ pub fn get_greeting(mood: &CreatureMood, name: &str) -> String {
         CreatureMood::Proud => format!("{}: Look how much we've grown!", name),
         CreatureMood::Lonely => format!("{}: I missed you! Where were you?", name),
         CreatureMood::Curious => format!("{}: What shall we discover today?", name),
+        CreatureMood::Worried => format!("{}: Oh no, oh no...", name),
     }
 }
 
@@ -1,10 +1,37 @@
 pub mod art;
+pub mod packs;
 pub mod persistence;
+pub mod reactions;
+pub mod sync;
 
-use chrono::{DateTime, Utc};
+use crate::stats::{Totals, ACHIEVEMENTS};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Seconds for energy to drop by one point (about 4 an hour)
+const ENERGY_DECAY_SECS: u64 = 900;
+/// Seconds for happiness to drop by one point (3 an hour)
+const HAPPINESS_DECAY_SECS: u64 = 1200;
+/// A multiple of both decay periods, so the leftover seconds stay small
+const DECAY_CYCLE_SECS: u64 = 3600;
+/// Time away beyond this doesn't wear the creature down any further
+const MAX_AWAY_DECAY_SECS: u64 = 48 * 3600;
+/// Energy playing uses up, and so needs
+const PLAY_ENERGY: u8 = 10;
+/// Happiness from petting, playing or emoting in the widget
+const INTERACTION_HAPPINESS: u8 = 2;
+/// Levels needed per creature already in the roster to hatch another
+const HATCH_LEVEL_STEP: u32 = 5;
+/// Days of streak between earned streak freezes
+const FREEZE_EVERY_DAYS: u32 = 7;
+/// Extra XP per day of streak, up to `MAX_STREAK_BONUS`
+const STREAK_BONUS_PER_DAY: f64 = 0.05;
+const MAX_STREAK_BONUS: f64 = 0.5;
+const STREAK_FREEZE: &str = "streak_freeze";
+/// Chance of finding an item each time an article is opened
+const DROP_CHANCE: f64 = 0.08;
+
 /// The main Tui creature that accompanies the user
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Creature {
@@ -25,6 +52,22 @@ pub struct Creature {
     pub last_seen: DateTime<Utc>,
     pub total_sessions: u64,
     pub total_time_seconds: u64,
+    /// Consecutive days the creature has been visited
+    #[serde(default)]
+    pub streak: u32,
+    #[serde(default)]
+    pub best_streak: u32,
+    #[serde(default)]
+    pub last_active_day: Option<NaiveDate>,
+    /// Item ids found while browsing, oldest first; treats can repeat
+    #[serde(default)]
+    pub inventory: Vec<String>,
+    /// Ids of achievements earned from usage stats
+    #[serde(default)]
+    pub achievements: Vec<String>,
+    /// XP earned but not yet worth a whole point
+    #[serde(skip)]
+    xp_fraction: f64,
 }
 
 impl Default for Creature {
@@ -47,6 +90,12 @@ impl Default for Creature {
             last_seen: Utc::now(),
             total_sessions: 0,
             total_time_seconds: 0,
+            streak: 0,
+            best_streak: 0,
+            last_active_day: None,
+            inventory: Vec::new(),
+            achievements: Vec::new(),
+            xp_fraction: 0.0,
         }
     }
 }
@@ -66,6 +115,12 @@ impl Creature {
         (100.0 * (1.5_f64).powi(level as i32 - 1)) as u64
     }
 
+    /// Level a creature must reach before another can join a roster of
+    /// `roster_size`
+    pub fn hatch_level(roster_size: usize) -> u32 {
+        HATCH_LEVEL_STEP * roster_size as u32
+    }
+
     /// Get XP needed to reach the next level
     pub fn xp_to_next_level(&self) -> u64 {
         Self::xp_for_level(self.level + 1).saturating_sub(self.experience)
@@ -171,29 +226,229 @@ impl Creature {
         }
     }
 
-    /// Record a session start
+    /// Record a session start, wearing the creature down for the time away
     pub fn start_session(&mut self) {
+        let seconds_away = (Utc::now() - self.last_seen).num_seconds().max(0) as u64;
         self.total_sessions += 1;
         self.last_seen = Utc::now();
 
-        // Mood based on absence
-        let hours_away = (Utc::now() - self.last_seen).num_hours();
-        self.mood = if hours_away > 168 {
-            // Week+
-            CreatureMood::Lonely
-        } else if hours_away > 24 {
-            CreatureMood::Sleepy
-        } else {
-            CreatureMood::Happy
+        self.stats.decay(seconds_away.min(MAX_AWAY_DECAY_SECS));
+        self.update_mood();
+        self.record_day(Local::now().date_naive());
+    }
+
+    /// Count `today` towards the streak. Missed days are covered by streak
+    /// freezes while they last; otherwise the streak starts over.
+    pub fn record_day(&mut self, today: NaiveDate) {
+        let missed = match self.last_active_day {
+            Some(day) if day >= today => return,
+            Some(day) => (today - day).num_days() - 1,
+            None => 0,
         };
+        let freezes = self
+            .inventory
+            .iter()
+            .filter(|id| *id == STREAK_FREEZE)
+            .count() as i64;
+
+        if self.streak > 0 && missed <= freezes {
+            for _ in 0..missed {
+                if let Some(pos) = self.inventory.iter().position(|id| id == STREAK_FREEZE) {
+                    self.inventory.remove(pos);
+                }
+            }
+            self.streak += 1;
+        } else {
+            self.streak = 1;
+        }
+        self.last_active_day = Some(today);
+        self.best_streak = self.best_streak.max(self.streak);
+
+        if self.streak.is_multiple_of(FREEZE_EVERY_DAYS) {
+            self.inventory.push(STREAK_FREEZE.to_string());
+        }
+    }
+
+    /// Reading an article raises knowledge by one point
+    pub fn learn(&mut self) {
+        self.stats.knowledge = (self.stats.knowledge + 1).min(100);
+    }
+
+    /// Record any achievements `totals` newly earns, returning their names
+    pub fn earn_achievements(&mut self, totals: &Totals) -> Vec<&'static str> {
+        let mut earned = Vec::new();
+        for achievement in &ACHIEVEMENTS {
+            if achievement.is_earned(totals)
+                && !self.achievements.iter().any(|id| id == achievement.id)
+            {
+                self.achievements.push(achievement.id.to_string());
+                earned.push(achievement.name);
+            }
+        }
+        earned
+    }
+
+    /// XP multiplier for the current streak: 5% more per day after the
+    /// first, up to 50%
+    pub fn streak_multiplier(&self) -> f64 {
+        1.0 + (self.streak.saturating_sub(1) as f64 * STREAK_BONUS_PER_DAY).min(MAX_STREAK_BONUS)
+    }
+
+    /// Streak freezes held, each covering one missed day
+    pub fn streak_freezes(&self) -> usize {
+        self.inventory
+            .iter()
+            .filter(|id| *id == STREAK_FREEZE)
+            .count()
     }
 
     /// Update session time and grant XP
     pub fn tick_session(&mut self, seconds: u64) -> u64 {
         self.total_time_seconds += seconds;
-        // 1 XP per 10 seconds of usage
-        let xp_gained = seconds / 10;
-        xp_gained
+        self.last_seen = Utc::now();
+        self.stats.decay(seconds);
+        self.update_mood();
+        // Sessions running past midnight count for the new day too
+        self.record_day(Local::now().date_naive());
+
+        // 1 XP per 10 seconds of usage, more or less depending on care, the
+        // streak and skills
+        self.xp_fraction += seconds as f64 / 10.0
+            * self.care_multiplier()
+            * self.streak_multiplier()
+            * self.skill_xp_multiplier();
+        let xp_gained = self.xp_fraction.floor();
+        self.xp_fraction -= xp_gained;
+        xp_gained as u64
+    }
+
+    /// XP multiplier for how well the creature is looked after: halved
+    /// when it is tired or sad, and a bonus when it is doing well
+    pub fn care_multiplier(&self) -> f64 {
+        let lowest = self.stats.energy.min(self.stats.happiness);
+        if lowest < 20 {
+            0.5
+        } else if lowest >= 80 {
+            1.25
+        } else {
+            1.0
+        }
+    }
+
+    /// Check if a care action can be afforded and done right now
+    pub fn can_care(&self, action: CareAction) -> bool {
+        self.points >= action.cost()
+            && !(action == CareAction::Play && self.stats.energy < PLAY_ENERGY)
+    }
+
+    /// Feed, play with, or rest the creature, paying for it with points
+    pub fn care(&mut self, action: CareAction) -> bool {
+        if !self.can_care(action) {
+            return false;
+        }
+        self.points -= action.cost();
+
+        let stats = &mut self.stats;
+        match action {
+            CareAction::Feed => {
+                stats.energy = stats.energy.saturating_add(30).min(100);
+                stats.happiness = stats.happiness.saturating_add(5).min(100);
+            }
+            CareAction::Play => {
+                stats.happiness = stats.happiness.saturating_add(30).min(100);
+                stats.energy -= PLAY_ENERGY;
+            }
+            CareAction::Rest => stats.energy = stats.energy.saturating_add(50).min(100),
+        }
+        self.update_mood();
+        true
+    }
+
+    /// A free, small happiness boost from interacting in the widget
+    pub fn cheer_up(&mut self) {
+        self.stats.happiness = self
+            .stats
+            .happiness
+            .saturating_add(INTERACTION_HAPPINESS)
+            .min(100);
+        self.update_mood();
+    }
+
+    /// Maybe find an item, given a random `roll` in `[0, 1)`. Returns the
+    /// item found; wearables already owned are never found again.
+    pub fn roll_drop(&mut self, roll: f64) -> Option<Item> {
+        if roll >= DROP_CHANCE {
+            return None;
+        }
+        let mut candidates: Vec<Item> = get_all_items()
+            .into_values()
+            .filter(|item| item.kind != ItemKind::StreakFreeze)
+            .filter(|item| item.kind.is_treat() || !self.inventory.contains(&item.id))
+            .collect();
+        candidates.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let idx = (roll / DROP_CHANCE * candidates.len() as f64) as usize;
+        let item = candidates.get(idx)?.clone();
+        self.inventory.push(item.id.clone());
+        Some(item)
+    }
+
+    /// Inventory items with how many of each are held, in the order they
+    /// were first found
+    pub fn inventory_counts(&self) -> Vec<(String, usize)> {
+        let mut counts: Vec<(String, usize)> = Vec::new();
+        for id in &self.inventory {
+            match counts.iter_mut().find(|(seen, _)| seen == id) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((id.clone(), 1)),
+            }
+        }
+        counts
+    }
+
+    /// Put on or take off a wearable item, or eat a treat
+    pub fn use_item(&mut self, item_id: &str) -> bool {
+        let Some(pos) = self.inventory.iter().position(|id| id == item_id) else {
+            return false;
+        };
+        let Some(item) = get_all_items().remove(item_id) else {
+            return false;
+        };
+
+        let toggle = |slot: &mut Option<String>| {
+            *slot = if slot.as_deref() == Some(item_id) {
+                None
+            } else {
+                Some(item_id.to_string())
+            };
+        };
+        match item.kind {
+            ItemKind::Hat(_) => toggle(&mut self.appearance.hat),
+            ItemKind::Accessory(_) => toggle(&mut self.appearance.accessory),
+            ItemKind::Background(_) => toggle(&mut self.appearance.background),
+            ItemKind::Treat { energy, happiness } => {
+                self.inventory.remove(pos);
+                self.stats.energy = self.stats.energy.saturating_add(energy).min(100);
+                self.stats.happiness = self.stats.happiness.saturating_add(happiness).min(100);
+                self.update_mood();
+            }
+            // Used up automatically when a day is missed
+            ItemKind::StreakFreeze => return false,
+        }
+        true
+    }
+
+    /// Set the mood from how tired and happy the creature is
+    fn update_mood(&mut self) {
+        self.mood = if self.stats.energy < 20 {
+            CreatureMood::Sleepy
+        } else if self.stats.happiness < 25 {
+            CreatureMood::Lonely
+        } else if self.stats.energy >= 90 && self.stats.happiness >= 90 {
+            CreatureMood::Excited
+        } else {
+            CreatureMood::Happy
+        };
     }
 
     /// Check if a skill can be purchased
@@ -211,6 +466,9 @@ impl Creature {
         if self.can_purchase_skill(skill) {
             self.points -= skill.cost;
             self.unlocked_skills.push(skill.id.clone());
+            if !self.active_skills.contains(&skill.id) {
+                self.active_skills.push(skill.id.clone());
+            }
             true
         } else {
             false
@@ -227,6 +485,32 @@ impl Creature {
         }
     }
 
+    /// Effects of the skills that are unlocked and switched on
+    pub fn skill_effects(&self) -> Vec<SkillEffect> {
+        let skills = get_skill_tree();
+        self.active_skills
+            .iter()
+            .filter(|id| self.unlocked_skills.contains(id))
+            .filter_map(|id| skills.get(id))
+            .flat_map(|skill| skill.effects.clone())
+            .collect()
+    }
+
+    pub fn has_skill_effect(&self, effect: &SkillEffect) -> bool {
+        self.skill_effects().contains(effect)
+    }
+
+    /// The biggest XP boost among active skills
+    pub fn skill_xp_multiplier(&self) -> f64 {
+        self.skill_effects()
+            .iter()
+            .filter_map(|effect| match effect {
+                SkillEffect::XpBoost(multiplier) => Some(*multiplier as f64),
+                _ => None,
+            })
+            .fold(1.0, f64::max)
+    }
+
     /// Toggle a skill active/inactive
     pub fn toggle_skill(&mut self, skill_id: &str) -> bool {
         if !self.unlocked_skills.contains(&skill_id.to_string()) {
@@ -255,6 +539,8 @@ pub enum CreatureSpecies {
     Robot,   // Friendly robot
     Spirit,  // Mystical spirit
     Octopus, // Multi-tasking octopus
+    /// A species from an art pack in ~/.feedtui/art
+    Custom(String),
 }
 
 impl Default for CreatureSpecies {
@@ -264,8 +550,9 @@ impl Default for CreatureSpecies {
 }
 
 impl CreatureSpecies {
+    /// Built-in species, then those added by art packs
     pub fn all() -> Vec<CreatureSpecies> {
-        vec![
+        let mut species = vec![
             CreatureSpecies::Blob,
             CreatureSpecies::Bird,
             CreatureSpecies::Cat,
@@ -276,7 +563,16 @@ impl CreatureSpecies {
             CreatureSpecies::Robot,
             CreatureSpecies::Spirit,
             CreatureSpecies::Octopus,
-        ]
+        ];
+        for pack in packs::packs() {
+            if !species
+                .iter()
+                .any(|s| s.name().eq_ignore_ascii_case(&pack.species))
+            {
+                species.push(CreatureSpecies::Custom(pack.species.clone()));
+            }
+        }
+        species
     }
 
     pub fn name(&self) -> &str {
@@ -291,10 +587,14 @@ impl CreatureSpecies {
             CreatureSpecies::Robot => "Robot",
             CreatureSpecies::Spirit => "Spirit",
             CreatureSpecies::Octopus => "Octopus",
+            CreatureSpecies::Custom(name) => name,
         }
     }
 
     pub fn description(&self) -> &str {
+        if let Some(description) = packs::find(self.name()).and_then(|p| p.description.as_deref()) {
+            return description;
+        }
         match self {
             CreatureSpecies::Blob => "A friendly blob that bounces with joy",
             CreatureSpecies::Bird => "A chirpy companion that loves news",
@@ -306,6 +606,7 @@ impl CreatureSpecies {
             CreatureSpecies::Robot => "A helpful bot that never sleeps",
             CreatureSpecies::Spirit => "A mystical spirit from the terminal realm",
             CreatureSpecies::Octopus => "Multi-tasking master of many feeds",
+            CreatureSpecies::Custom(_) => "A creature from an art pack",
         }
     }
 }
@@ -317,6 +618,9 @@ pub struct CreatureStats {
     pub energy: u8,    // 0-100
     pub knowledge: u8, // 0-100
     pub charisma: u8,  // 0-100
+    /// Seconds towards the next point of decay
+    #[serde(default)]
+    pub decay_seconds: u64,
 }
 
 impl Default for CreatureStats {
@@ -326,6 +630,49 @@ impl Default for CreatureStats {
             energy: 100,
             knowledge: 10,
             charisma: 10,
+            decay_seconds: 0,
+        }
+    }
+}
+
+impl CreatureStats {
+    /// Lose energy and happiness for `seconds` passing
+    fn decay(&mut self, seconds: u64) {
+        let before = self.decay_seconds;
+        let after = before + seconds;
+        let lost = |period: u64| (after / period - before / period).min(100) as u8;
+        self.energy = self.energy.saturating_sub(lost(ENERGY_DECAY_SECS));
+        self.happiness = self.happiness.saturating_sub(lost(HAPPINESS_DECAY_SECS));
+        self.decay_seconds = after % DECAY_CYCLE_SECS;
+    }
+}
+
+/// Ways to look after the creature, each costing points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CareAction {
+    Feed,
+    Play,
+    Rest,
+}
+
+impl CareAction {
+    pub fn all() -> Vec<CareAction> {
+        vec![CareAction::Feed, CareAction::Play, CareAction::Rest]
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            CareAction::Feed => "Feed",
+            CareAction::Play => "Play",
+            CareAction::Rest => "Rest",
+        }
+    }
+
+    pub fn cost(&self) -> u32 {
+        match self {
+            CareAction::Feed => 2,
+            CareAction::Play => 3,
+            CareAction::Rest => 1,
         }
     }
 }
@@ -409,6 +756,7 @@ pub enum CreatureMood {
     Proud,
     Lonely,
     Curious,
+    Worried,
 }
 
 impl CreatureMood {
@@ -421,6 +769,7 @@ impl CreatureMood {
             CreatureMood::Proud => "^_^",
             CreatureMood::Lonely => ":'(",
             CreatureMood::Curious => "?.?",
+            CreatureMood::Worried => ":S",
         }
     }
 }
@@ -445,7 +794,7 @@ pub enum SkillCategory {
     Social,   // Affects emotes/interactions
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SkillEffect {
     XpBoost(f32),               // Multiplier for XP gain
     RefreshBoost,               // Faster feed refresh
@@ -477,6 +826,45 @@ pub struct Outfit {
     pub art_modifier: String,
 }
 
+/// A collectible found while browsing
+#[derive(Debug, Clone)]
+pub struct Item {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub kind: ItemKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemKind {
+    /// Art drawn above the creature
+    Hat(String),
+    /// Art drawn below the creature
+    Accessory(String),
+    /// Color behind the creature
+    Background(CreatureColor),
+    /// Eaten for energy and happiness
+    Treat { energy: u8, happiness: u8 },
+    /// Keeps the streak going through a missed day
+    StreakFreeze,
+}
+
+impl ItemKind {
+    pub fn is_treat(&self) -> bool {
+        matches!(self, ItemKind::Treat { .. })
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            ItemKind::Hat(_) => "Hat",
+            ItemKind::Accessory(_) => "Accessory",
+            ItemKind::Background(_) => "Background",
+            ItemKind::Treat { .. } => "Treat",
+            ItemKind::StreakFreeze => "Streak",
+        }
+    }
+}
+
 /// An emote the creature can perform
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Emote {
@@ -713,6 +1101,102 @@ pub fn get_all_outfits() -> HashMap<String, Outfit> {
     outfits
 }
 
+/// Get all items that can be found while browsing
+pub fn get_all_items() -> HashMap<String, Item> {
+    let items = [
+        (
+            "top_hat",
+            "Top Hat",
+            "Very distinguished",
+            ItemKind::Hat("  _|_|_".to_string()),
+        ),
+        (
+            "party_hat",
+            "Party Hat",
+            "Every day is a celebration",
+            ItemKind::Hat("   /*\\".to_string()),
+        ),
+        (
+            "crown",
+            "Crown",
+            "Royalty of the terminal",
+            ItemKind::Hat("  \\^^^/".to_string()),
+        ),
+        (
+            "bow_tie",
+            "Bow Tie",
+            "Bow ties are cool",
+            ItemKind::Accessory("   >o<".to_string()),
+        ),
+        (
+            "scarf",
+            "Scarf",
+            "Cozy for long reading sessions",
+            ItemKind::Accessory("  ~~~~~~".to_string()),
+        ),
+        (
+            "night_sky",
+            "Night Sky",
+            "A deep blue backdrop",
+            ItemKind::Background(CreatureColor::Blue),
+        ),
+        (
+            "sunset",
+            "Sunset",
+            "A warm orange glow",
+            ItemKind::Background(CreatureColor::Orange),
+        ),
+        (
+            "cookie",
+            "Cookie",
+            "A snack for energy and cheer",
+            ItemKind::Treat {
+                energy: 15,
+                happiness: 10,
+            },
+        ),
+        (
+            "berries",
+            "Berries",
+            "Restores plenty of energy",
+            ItemKind::Treat {
+                energy: 25,
+                happiness: 0,
+            },
+        ),
+        (
+            "candy",
+            "Candy",
+            "Pure happiness",
+            ItemKind::Treat {
+                energy: 0,
+                happiness: 20,
+            },
+        ),
+        (
+            STREAK_FREEZE,
+            "Streak Freeze",
+            "Earned every 7 days of streak; saves it when you miss a day",
+            ItemKind::StreakFreeze,
+        ),
+    ];
+
+    items
+        .into_iter()
+        .map(|(id, name, description, kind)| {
+            (
+                id.to_string(),
+                Item {
+                    id: id.to_string(),
+                    name: name.to_string(),
+                    description: description.to_string(),
+                    kind,
+                },
+            )
+        })
+        .collect()
+}
+
 /// Get all available emotes
 pub fn get_all_emotes() -> HashMap<String, Emote> {
     let mut emotes = HashMap::new();
@@ -774,3 +1258,112 @@ pub fn get_all_emotes() -> HashMap<String, Emote> {
 
     emotes
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_decay_across_ticks() {
+        let mut stats = CreatureStats::default();
+        for _ in 0..(ENERGY_DECAY_SECS / 10) {
+            stats.decay(10);
+        }
+        assert_eq!(stats.energy, 99);
+        assert_eq!(stats.happiness, 80);
+
+        stats.decay(HAPPINESS_DECAY_SECS);
+        assert_eq!(stats.energy, 98);
+        assert_eq!(stats.happiness, 79);
+    }
+
+    #[test]
+    fn care_costs_points_and_changes_mood() {
+        let mut creature = Creature::default();
+        creature.stats.energy = 10;
+        creature.update_mood();
+        assert_eq!(creature.mood, CreatureMood::Sleepy);
+        assert!(!creature.care(CareAction::Feed));
+
+        creature.points = 5;
+        assert!(creature.care(CareAction::Feed));
+        assert_eq!(creature.points, 3);
+        assert_eq!(creature.stats.energy, 40);
+        assert_eq!(creature.mood, CreatureMood::Happy);
+    }
+
+    #[test]
+    fn drops_skip_owned_wearables_and_treats_are_eaten() {
+        let mut creature = Creature::default();
+        assert!(creature.roll_drop(DROP_CHANCE).is_none());
+
+        let first = creature.roll_drop(0.0).unwrap();
+        assert_eq!(first.id, "berries");
+        assert_eq!(creature.roll_drop(0.0).unwrap().id, "berries");
+        assert_eq!(
+            creature.inventory_counts(),
+            vec![("berries".to_string(), 2)]
+        );
+
+        creature.inventory.push("crown".to_string());
+        assert!(creature.use_item("crown"));
+        assert_eq!(creature.appearance.hat.as_deref(), Some("crown"));
+        assert!(creature.use_item("crown"));
+        assert_eq!(creature.appearance.hat, None);
+
+        creature.stats.energy = 50;
+        assert!(creature.use_item("berries"));
+        assert_eq!(creature.stats.energy, 75);
+        assert_eq!(creature.inventory.len(), 2);
+        assert!(!creature.use_item("sunset"));
+    }
+
+    #[test]
+    fn streaks_grow_daily_and_freezes_cover_missed_days() {
+        let day = |d: u32| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        let mut creature = Creature::default();
+        for d in 1..=7 {
+            creature.record_day(day(d));
+            creature.record_day(day(d));
+        }
+        assert_eq!(creature.streak, 7);
+        assert_eq!(creature.streak_freezes(), 1);
+        assert!((creature.streak_multiplier() - 1.3).abs() < 1e-9);
+
+        // One missed day is covered, two are not
+        creature.record_day(day(9));
+        assert_eq!(creature.streak, 8);
+        assert_eq!(creature.streak_freezes(), 0);
+        creature.record_day(day(12));
+        assert_eq!(creature.streak, 1);
+        assert_eq!(creature.best_streak, 8);
+    }
+
+    #[test]
+    fn only_active_skills_take_effect() {
+        let mut creature = Creature {
+            points: 100,
+            ..Creature::default()
+        };
+        let skills = get_skill_tree();
+        assert!(creature.purchase_skill(&skills["xp_boost_1"]));
+        assert!(creature.purchase_skill(&skills["xp_boost_2"]));
+        assert!((creature.skill_xp_multiplier() - 1.25).abs() < 1e-6);
+
+        creature.toggle_skill("xp_boost_2");
+        assert!((creature.skill_xp_multiplier() - 1.1).abs() < 1e-6);
+
+        creature.unlocked_skills.push("stock_alert".to_string());
+        assert!(!creature.has_skill_effect(&SkillEffect::StockAlert));
+        creature.toggle_skill("stock_alert");
+        assert!(creature.has_skill_effect(&SkillEffect::StockAlert));
+    }
+
+    #[test]
+    fn neglect_slows_xp() {
+        let mut creature = Creature::default();
+        creature.stats.happiness = 10;
+        assert_eq!(creature.tick_session(10), 0);
+        assert_eq!(creature.tick_session(10), 1);
+    }
+}
@@ -0,0 +1,163 @@
+use super::CreatureMood;
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Art for one species, loaded from a TOML or JSON file in the art dir
+#[derive(Debug, Deserialize)]
+pub struct ArtPack {
+    /// Species name; a built-in species' name replaces its art
+    pub species: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Animation frames, with `{face}` standing in for the mood's face
+    pub frames: Vec<Vec<String>>,
+    /// Frames for particular moods, by lowercase mood name
+    #[serde(default)]
+    pub moods: HashMap<String, Vec<Vec<String>>>,
+    /// Faces by lowercase mood name, instead of the built-in ones
+    #[serde(default)]
+    pub faces: HashMap<String, String>,
+    /// Lines around the art for each outfit id, instead of the built-in ones
+    #[serde(default)]
+    pub outfits: HashMap<String, Overlay>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Overlay {
+    #[serde(default)]
+    pub above: Vec<String>,
+    #[serde(default)]
+    pub below: Vec<String>,
+}
+
+impl ArtPack {
+    /// One frame of the art for `mood`, using `default_face` when the pack
+    /// has no face of its own for it
+    pub fn art(&self, mood: &CreatureMood, frame: usize, default_face: &str) -> Vec<String> {
+        let key = mood_key(mood);
+        let frames = self
+            .moods
+            .get(&key)
+            .filter(|frames| !frames.is_empty())
+            .unwrap_or(&self.frames);
+        let face = self.faces.get(&key).map_or(default_face, String::as_str);
+        frames[frame % frames.len()]
+            .iter()
+            .map(|line| line.replace("{face}", face))
+            .collect()
+    }
+}
+
+impl Overlay {
+    pub fn wrap(&self, art: Vec<String>) -> Vec<String> {
+        let mut lines = self.above.clone();
+        lines.extend(art);
+        lines.extend(self.below.iter().cloned());
+        lines
+    }
+}
+
+fn mood_key(mood: &CreatureMood) -> String {
+    format!("{:?}", mood).to_lowercase()
+}
+
+/// Get the default directory for user art packs
+pub fn default_art_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".feedtui")
+        .join("art")
+}
+
+/// Packs from the default art dir, read once on first use
+pub fn packs() -> &'static [ArtPack] {
+    static PACKS: OnceLock<Vec<ArtPack>> = OnceLock::new();
+    PACKS.get_or_init(|| load_packs(&default_art_dir()))
+}
+
+/// The pack for a species, by case-insensitive name
+pub fn find(species: &str) -> Option<&'static ArtPack> {
+    packs()
+        .iter()
+        .find(|pack| pack.species.eq_ignore_ascii_case(species))
+}
+
+/// Every readable pack in `dir`, in file name order. Broken packs are
+/// skipped with a warning.
+pub fn load_packs(dir: &Path) -> Vec<ArtPack> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == "toml" || ext == "json")
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .filter_map(|path| match load_pack(path) {
+            Ok(pack) => Some(pack),
+            Err(e) => {
+                tracing::warn!("Skipping art pack {}: {:#}", path.display(), e);
+                None
+            }
+        })
+        .collect()
+}
+
+fn load_pack(path: &Path) -> Result<ArtPack> {
+    let content = std::fs::read_to_string(path)?;
+    let pack: ArtPack = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_str(&content)?
+    } else {
+        toml::from_str(&content)?
+    };
+    anyhow::ensure!(
+        !pack.frames.is_empty(),
+        "An art pack needs at least one frame"
+    );
+    Ok(pack)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn loads_packs_and_fills_in_faces() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("slime.toml"),
+            r#"
+species = "Slime"
+frames = [[" ({face}) "], [" [{face}] "]]
+
+[moods]
+sleepy = [[" zZ{face} "]]
+
+[faces]
+happy = "owo"
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("empty.json"),
+            r#"{"species": "Nope", "frames": []}"#,
+        )
+        .unwrap();
+
+        let packs = load_packs(dir.path());
+        assert_eq!(packs.len(), 1);
+        let pack = &packs[0];
+        assert_eq!(pack.art(&CreatureMood::Happy, 1, "^_^"), vec![" [owo] "]);
+        assert_eq!(pack.art(&CreatureMood::Sleepy, 1, "-_-"), vec![" zZ-_- "]);
+    }
+}
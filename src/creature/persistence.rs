@@ -1,15 +1,95 @@
 use super::Creature;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
-const CREATURE_FILE: &str = "tui.json";
+/// Save file from before creatures had a roster, next to the roster dir
+const LEGACY_CREATURE_FILE: &str = "tui.json";
+const ROSTER_DIR: &str = "creatures";
+/// Names the active creature's save file within the roster dir
+const ACTIVE_FILE: &str = "active";
 
-/// Get the default path for creature save file
-pub fn default_creature_path() -> PathBuf {
+/// Get the default directory holding one save file per creature
+pub fn default_roster_dir() -> PathBuf {
     dirs::home_dir()
         .unwrap_or_else(|| PathBuf::from("."))
         .join(".feedtui")
-        .join(CREATURE_FILE)
+        .join(ROSTER_DIR)
+}
+
+/// Path of the active creature's save file, which may not exist yet. A
+/// save from before the roster is moved into it first.
+pub fn active_creature_path(dir: &Path) -> Result<PathBuf> {
+    if let Ok(name) = std::fs::read_to_string(dir.join(ACTIVE_FILE)) {
+        let path = dir.join(name.trim());
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    let roster = list_creatures(dir)?;
+    if let Some((path, _)) = roster.into_iter().next() {
+        return Ok(path);
+    }
+
+    let path = dir.join(LEGACY_CREATURE_FILE);
+    if let Some(legacy) = dir.parent().map(|p| p.join(LEGACY_CREATURE_FILE)) {
+        if legacy.exists() {
+            std::fs::create_dir_all(dir)?;
+            std::fs::rename(legacy, &path)?;
+        }
+    }
+    Ok(path)
+}
+
+/// Remember which creature to load next time
+pub fn set_active_creature(path: &Path) -> Result<()> {
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name()) else {
+        anyhow::bail!("Not a creature save file: {}", path.display());
+    };
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(dir.join(ACTIVE_FILE), name.to_string_lossy().as_bytes())?;
+    Ok(())
+}
+
+/// Every creature in the roster with its save file, oldest first.
+/// Unreadable files are skipped.
+pub fn list_creatures(dir: &Path) -> Result<Vec<(PathBuf, Creature)>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut creatures = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            match load_creature(&path) {
+                Ok(Some(creature)) => creatures.push((path, creature)),
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Skipping creature {}: {}", path.display(), e),
+            }
+        }
+    }
+    creatures.sort_by_key(|(_, creature)| creature.created_at);
+    Ok(creatures)
+}
+
+/// An unused save file path for a creature called `name`
+pub fn new_creature_path(dir: &Path, name: &str) -> PathBuf {
+    let slug: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let slug = slug.trim_matches('-');
+    let slug = if slug.is_empty() { "creature" } else { slug };
+
+    let mut path = dir.join(format!("{}.json", slug));
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("{}-{}.json", slug, n));
+        n += 1;
+    }
+    path
 }
 
 /// Save creature state to file
@@ -35,6 +115,17 @@ pub fn load_creature(path: &Path) -> Result<Option<Creature>> {
     Ok(Some(creature))
 }
 
+/// Add a creature exported from another machine to the roster, returning
+/// its new save file
+pub fn import_creature(file: &Path, dir: &Path) -> Result<PathBuf> {
+    let content = std::fs::read_to_string(file)?;
+    let creature: Creature = serde_json::from_str(&content)
+        .with_context(|| format!("{} is not a feedtui creature", file.display()))?;
+    let path = new_creature_path(dir, &creature.name);
+    save_creature(&creature, &path)?;
+    Ok(path)
+}
+
 /// Load creature or create new one if none exists
 pub fn load_or_create_creature(path: &Path) -> Result<Creature> {
     match load_creature(path)? {
@@ -69,6 +160,29 @@ mod tests {
         assert_eq!(loaded.species, CreatureSpecies::Cat);
     }
 
+    #[test]
+    fn test_roster_adopts_legacy_save() {
+        let home = tempdir().unwrap();
+        let legacy = home.path().join(LEGACY_CREATURE_FILE);
+        save_creature(&Creature::default(), &legacy).unwrap();
+
+        let dir = home.path().join(ROSTER_DIR);
+        let active = active_creature_path(&dir).unwrap();
+        assert_eq!(active, dir.join(LEGACY_CREATURE_FILE));
+        assert!(active.exists() && !legacy.exists());
+
+        let second = new_creature_path(&dir, "Tui");
+        assert_eq!(second, dir.join("tui-2.json"));
+        save_creature(
+            &Creature::new("Tui".to_string(), CreatureSpecies::Fox),
+            &second,
+        )
+        .unwrap();
+        set_active_creature(&second).unwrap();
+        assert_eq!(active_creature_path(&dir).unwrap(), second);
+        assert_eq!(list_creatures(&dir).unwrap().len(), 2);
+    }
+
     #[test]
     fn test_load_nonexistent() {
         let dir = tempdir().unwrap();
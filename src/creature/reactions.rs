@@ -0,0 +1,261 @@
+use super::CreatureMood;
+use crate::config::CreatureConfig;
+use crate::feeds::FeedData;
+use std::collections::{HashMap, HashSet};
+
+/// Something in the feeds the creature noticed
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reaction {
+    /// Every tracked stock is up
+    Dance,
+    /// A stock dropped by at least `big_move_percent`
+    Worried { symbol: String, change_percent: f64 },
+    /// A favorite team's score went up
+    Excited { team: String },
+    /// A headline mentions a keyword
+    Curious { keyword: String, title: String },
+}
+
+impl Reaction {
+    /// Mood shown while reacting
+    pub fn mood(&self) -> CreatureMood {
+        match self {
+            Reaction::Dance | Reaction::Excited { .. } => CreatureMood::Excited,
+            Reaction::Worried { .. } => CreatureMood::Worried,
+            Reaction::Curious { .. } => CreatureMood::Curious,
+        }
+    }
+
+    pub fn message(&self, name: &str) -> String {
+        match self {
+            Reaction::Dance => format!("{}: All green! \\o/ *dances*", name),
+            Reaction::Worried {
+                symbol,
+                change_percent,
+            } => format!("{}: Uh oh, {} is {:.1}%...", name, symbol, change_percent),
+            Reaction::Excited { team } => format!("{}: {} scored!!", name, team),
+            Reaction::Curious { keyword, title } => {
+                format!("{}: Ooh, \"{}\": {}", name, keyword, title)
+            }
+        }
+    }
+}
+
+/// Whether tracked stocks were last seen all up, or with a big drop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Market {
+    Green,
+    Drop,
+    Mixed,
+}
+
+/// Remembers what the creature has seen so each event gets one reaction
+#[derive(Debug, Default)]
+pub struct FeedWatcher {
+    favorite_teams: Vec<String>,
+    keywords: Vec<String>,
+    big_move_percent: f64,
+    market: Option<Market>,
+    /// Last score of each favorite team, by team name
+    scores: HashMap<String, u32>,
+    /// Headlines already reacted to
+    seen_titles: HashSet<String>,
+    /// Highest-scored Hacker News story last seen
+    top_story: Option<String>,
+}
+
+impl FeedWatcher {
+    pub fn new(config: &CreatureConfig) -> Self {
+        Self {
+            favorite_teams: lowercase(&config.favorite_teams),
+            keywords: lowercase(&config.keywords),
+            big_move_percent: config.big_move_percent,
+            ..Self::default()
+        }
+    }
+
+    /// The reaction to fresh data from a widget, if it calls for one
+    pub fn observe(&mut self, data: &FeedData) -> Option<Reaction> {
+        if let FeedData::HackerNews(stories) = data {
+            if let Some(top) = stories.iter().max_by_key(|story| story.score) {
+                self.top_story = Some(top.title.clone());
+            }
+        }
+
+        match data {
+            FeedData::Stocks(quotes) if !quotes.is_empty() => {
+                let worst = quotes
+                    .iter()
+                    .min_by(|a, b| a.change_percent.total_cmp(&b.change_percent))?;
+                let market = if worst.change_percent <= -self.big_move_percent {
+                    Market::Drop
+                } else if quotes.iter().all(|q| q.change > 0.0) {
+                    Market::Green
+                } else {
+                    Market::Mixed
+                };
+                if self.market.replace(market) == Some(market) {
+                    return None;
+                }
+                match market {
+                    Market::Drop => Some(Reaction::Worried {
+                        symbol: worst.symbol.clone(),
+                        change_percent: worst.change_percent,
+                    }),
+                    Market::Green => Some(Reaction::Dance),
+                    Market::Mixed => None,
+                }
+            }
+            FeedData::Sports(events) => {
+                let mut reaction = None;
+                for event in events {
+                    let sides = [
+                        (&event.home_team, event.home_score),
+                        (&event.away_team, event.away_score),
+                    ];
+                    for (team, score) in sides {
+                        let Some(score) = score else { continue };
+                        if !self.is_favorite(team) {
+                            continue;
+                        }
+                        let previous = self.scores.insert(team.clone(), score);
+                        if previous.is_some_and(|previous| score > previous) {
+                            reaction = Some(Reaction::Excited { team: team.clone() });
+                        }
+                    }
+                }
+                reaction
+            }
+            FeedData::Stocks(_)
+            | FeedData::Wallet(_)
+            | FeedData::Odds(_)
+            | FeedData::Loading
+            | FeedData::Progress { .. }
+            | FeedData::Error(_) => None,
+            _ if self.keywords.is_empty() => None,
+            _ => {
+                let mut reaction = None;
+                for title in data.item_titles() {
+                    let lower = title.to_lowercase();
+                    let Some(keyword) = self.keywords.iter().find(|k| lower.contains(k.as_str()))
+                    else {
+                        continue;
+                    };
+                    if reaction.is_none() && !self.seen_titles.contains(&title) {
+                        reaction = Some(Reaction::Curious {
+                            keyword: keyword.clone(),
+                            title: title.clone(),
+                        });
+                    }
+                    self.seen_titles.insert(title);
+                }
+                reaction
+            }
+        }
+    }
+
+    pub fn top_story(&self) -> Option<&str> {
+        self.top_story.as_deref()
+    }
+
+    fn is_favorite(&self, team: &str) -> bool {
+        let team = team.to_lowercase();
+        self.favorite_teams
+            .iter()
+            .any(|favorite| team.contains(favorite.as_str()))
+    }
+}
+
+fn lowercase(words: &[String]) -> Vec<String> {
+    words.iter().map(|w| w.to_lowercase()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Position;
+    use crate::feeds::{HnStory, SportsEvent, StockQuote};
+
+    fn watcher() -> FeedWatcher {
+        FeedWatcher::new(&CreatureConfig {
+            title: "Tui".to_string(),
+            show_on_startup: false,
+            favorite_teams: vec!["Celtics".to_string()],
+            keywords: vec!["Rust".to_string()],
+            big_move_percent: 3.0,
+            id: None,
+            position: Position {
+                row: 0,
+                col: 0,
+                page: 0,
+                priority: 0,
+            },
+        })
+    }
+
+    fn quote(symbol: &str, change_percent: f64) -> StockQuote {
+        StockQuote {
+            symbol: symbol.to_string(),
+            price: 100.0,
+            change: change_percent,
+            change_percent,
+            name: symbol.to_string(),
+        }
+    }
+
+    #[test]
+    fn reacts_to_market_changes_once() {
+        let mut watcher = watcher();
+        let green = FeedData::Stocks(vec![quote("AAPL", 1.0), quote("MSFT", 0.5)]);
+        assert_eq!(watcher.observe(&green), Some(Reaction::Dance));
+        assert_eq!(watcher.observe(&green), None);
+
+        let drop = FeedData::Stocks(vec![quote("AAPL", 1.0), quote("MSFT", -4.0)]);
+        assert!(matches!(
+            watcher.observe(&drop),
+            Some(Reaction::Worried { symbol, .. }) if symbol == "MSFT"
+        ));
+    }
+
+    #[test]
+    fn reacts_when_a_favorite_team_scores() {
+        let mut watcher = watcher();
+        let game = |home: u32| {
+            FeedData::Sports(vec![SportsEvent {
+                league: "nba".to_string(),
+                home_team: "Boston Celtics".to_string(),
+                away_team: "Miami Heat".to_string(),
+                home_score: Some(home),
+                away_score: Some(10),
+                status: "Q1".to_string(),
+                start_time: None,
+                home_win_probability: None,
+            }])
+        };
+        assert_eq!(watcher.observe(&game(8)), None);
+        assert_eq!(
+            watcher.observe(&game(11)),
+            Some(Reaction::Excited {
+                team: "Boston Celtics".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn reacts_to_each_matching_headline_once() {
+        let mut watcher = watcher();
+        let stories = FeedData::HackerNews(vec![HnStory {
+            id: 1,
+            title: "Announcing Rust 2.0".to_string(),
+            url: None,
+            score: 1,
+            by: "a".to_string(),
+            descendants: 0,
+        }]);
+        assert!(matches!(
+            watcher.observe(&stories),
+            Some(Reaction::Curious { keyword, .. }) if keyword == "rust"
+        ));
+        assert_eq!(watcher.observe(&stories), None);
+    }
+}
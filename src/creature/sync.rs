@@ -0,0 +1,176 @@
+use super::persistence::{list_creatures, save_creature};
+use super::Creature;
+use crate::config::SyncConfig;
+use crate::feeds::http;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Every creature in the roster, by save file name
+const BUNDLE_FILE: &str = "creatures.json";
+
+type Bundle = BTreeMap<String, Creature>;
+
+/// Where the roster is synced to
+enum Target {
+    /// A directory, committed and pushed when it is a git checkout
+    Dir(PathBuf),
+    /// A URL taking GET and PUT, such as a WebDAV folder or an S3 bucket
+    Http(String),
+}
+
+impl Target {
+    fn parse(target: &str) -> Target {
+        if target.starts_with("http://") || target.starts_with("https://") {
+            return Target::Http(format!("{}/{}", target.trim_end_matches('/'), BUNDLE_FILE));
+        }
+        let path = match target.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+            None => PathBuf::from(target),
+        };
+        Target::Dir(path)
+    }
+}
+
+/// How many creatures came from the synced copy, and how many were sent
+/// from this machine
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SyncSummary {
+    pub pulled: usize,
+    pub pushed: usize,
+}
+
+/// Merge the roster with the synced copy, keeping whichever side of each
+/// creature was seen last, then write the result to both
+pub async fn sync_roster(config: &SyncConfig, dir: &Path) -> Result<SyncSummary> {
+    let target = config
+        .target
+        .as_deref()
+        .map(Target::parse)
+        .context("Set `target` under [sync] to a directory or URL")?;
+
+    let remote = match &target {
+        Target::Dir(path) => {
+            if path.join(".git").exists() {
+                git(path, &["pull", "--ff-only"])?;
+            }
+            match std::fs::read_to_string(path.join(BUNDLE_FILE)) {
+                Ok(json) => serde_json::from_str(&json)?,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Bundle::new(),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Target::Http(url) => {
+            let response = http_client(config, http::client(http::timeout(None)).get(url))
+                .send()
+                .await?;
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                Bundle::new()
+            } else {
+                response.error_for_status()?.json().await?
+            }
+        }
+    };
+
+    let mut local = Bundle::new();
+    for (path, creature) in list_creatures(dir)? {
+        if let Some(name) = path.file_name() {
+            local.insert(name.to_string_lossy().into_owned(), creature);
+        }
+    }
+    let (merged, summary) = merge(local, remote);
+
+    for (name, creature) in &merged {
+        save_creature(creature, &dir.join(name))?;
+    }
+    let json = serde_json::to_string_pretty(&merged)?;
+    match &target {
+        Target::Dir(path) => {
+            std::fs::create_dir_all(path)?;
+            std::fs::write(path.join(BUNDLE_FILE), json)?;
+            if path.join(".git").exists() {
+                git(path, &["add", BUNDLE_FILE])?;
+                // Nothing staged means the remote already has this roster
+                if git(path, &["diff", "--cached", "--quiet"]).is_err() {
+                    git(path, &["commit", "-m", "Sync feedtui creatures"])?;
+                    git(path, &["push"])?;
+                }
+            }
+        }
+        Target::Http(url) => {
+            http_client(config, http::client(http::timeout(None)).put(url))
+                .header("Content-Type", "application/json")
+                .body(json)
+                .send()
+                .await?
+                .error_for_status()?;
+        }
+    }
+    Ok(summary)
+}
+
+/// Combine both sides, the more recently seen copy of each creature winning
+fn merge(local: Bundle, remote: Bundle) -> (Bundle, SyncSummary) {
+    let mut summary = SyncSummary::default();
+    let mut merged = local;
+    for (name, theirs) in remote {
+        match merged.get(&name) {
+            Some(ours) if ours.last_seen >= theirs.last_seen => {}
+            _ => {
+                summary.pulled += 1;
+                merged.insert(name, theirs);
+            }
+        }
+    }
+    summary.pushed = merged.len() - summary.pulled;
+    (merged, summary)
+}
+
+fn http_client(config: &SyncConfig, request: http::Request) -> http::Request {
+    match &config.username {
+        Some(username) => request.basic_auth(username, config.password.as_deref()),
+        None => request,
+    }
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<()> {
+    let status = Command::new("git").arg("-C").arg(dir).args(args).status()?;
+    if !status.success() {
+        anyhow::bail!("git {} failed in {}", args.join(" "), dir.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn newer_creature_wins_the_merge() {
+        let older = Creature::default();
+        let mut newer = older.clone();
+        newer.last_seen = older.last_seen + Duration::hours(1);
+        newer.points = 42;
+
+        let local = Bundle::from([
+            ("tui.json".to_string(), older.clone()),
+            ("fox.json".to_string(), older.clone()),
+        ]);
+        let remote = Bundle::from([
+            ("tui.json".to_string(), newer),
+            ("owl.json".to_string(), older),
+        ]);
+        let (merged, summary) = merge(local, remote);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged["tui.json"].points, 42);
+        assert_eq!(
+            summary,
+            SyncSummary {
+                pulled: 2,
+                pushed: 1
+            }
+        );
+    }
+}
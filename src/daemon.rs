@@ -0,0 +1,212 @@
+use crate::api::{self, ApiState, ApiWidget};
+use crate::config::{AlertKind, Config, WidgetConfig};
+use crate::creature::Creature;
+use crate::feeds::cache::FeedCache;
+use crate::feeds::filter::{self, Filter};
+use crate::feeds::retry::RetryPolicy;
+use crate::feeds::{http, FeedData, FeedFetcher, Progress};
+use crate::ipc::{self, ControlCommand};
+use crate::notify::Notifier;
+use crate::quiet::QuietHours;
+use crate::ui::widgets::create_widget;
+use anyhow::{Context, Result};
+use chrono::Local;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Notify};
+use tokio::task::JoinHandle;
+
+/// Fetches are written out at most this often
+const SAVE_EVERY: Duration = Duration::from_secs(5);
+
+/// A widget the daemon keeps fresh
+struct Feed {
+    id: String,
+    title: String,
+    filters: Vec<Filter>,
+    refresh: Arc<Notify>,
+}
+
+/// Entry point for `feedtui daemon`: keep every widget's data fresh in the
+/// feed cache with no terminal attached, answering the control socket and
+/// the HTTP API until told to quit or interrupted
+pub async fn run_daemon(config: &Config) -> Result<()> {
+    http::configure(config);
+    let retry = RetryPolicy::from_config(&config.general);
+    let cache_path = FeedCache::default_path();
+    let mut cache = FeedCache::load(&cache_path).unwrap_or_else(|e| {
+        tracing::warn!("Could not load the feed cache: {}", e);
+        FeedCache::default()
+    });
+
+    let creature = Creature::default();
+    let (data_tx, mut data_rx) = mpsc::unbounded_channel::<(usize, FeedData)>();
+    let mut feeds = Vec::new();
+    let mut api_widgets = Vec::new();
+    let mut tasks: Vec<JoinHandle<()>> = Vec::new();
+
+    // The creature and digest widgets have no external data to fetch
+    for cfg in config
+        .widgets
+        .iter()
+        .filter(|cfg| !matches!(cfg, WidgetConfig::Creature(_) | WidgetConfig::Digest(_)))
+    {
+        let widget = create_widget(cfg, &creature);
+        let fetcher = widget.create_fetcher();
+        let interval = fetcher
+            .refresh_interval()
+            .unwrap_or(Duration::from_secs(config.general.refresh_interval_secs));
+        let feed = Feed {
+            id: widget.id(),
+            title: widget.title().to_string(),
+            filters: Filter::compile_all(cfg.filters()),
+            refresh: Arc::new(Notify::new()),
+        };
+
+        let mut data = cache
+            .get(&feed.id)
+            .map_or(FeedData::Loading, |cached| cached.data.clone());
+        filter::apply(&feed.filters, &mut data);
+        api_widgets.push(ApiWidget {
+            id: feed.id.clone(),
+            kind: cfg.kind().to_string(),
+            title: feed.title.clone(),
+            updated_at: cache.get(&feed.id).map(|cached| cached.fetched_at),
+            data,
+        });
+
+        tasks.push(tokio::spawn(poll(
+            feeds.len(),
+            feed.id.clone(),
+            fetcher,
+            retry,
+            interval,
+            feed.refresh.clone(),
+            data_tx.clone(),
+        )));
+        feeds.push(feed);
+    }
+
+    let api_state = config.api.enabled.then(|| ApiState::new(api_widgets));
+    if let Some(state) = api_state.clone() {
+        api::spawn_server(state, &config.api.bind, config.api.port).await?;
+    }
+    let (control_tx, mut control_rx) = mpsc::unbounded_channel();
+    let control_socket = ipc::spawn_server(control_tx)
+        .await
+        .context("could not open the control socket")?;
+
+    let notifier = Notifier::new(config);
+    let quiet_hours = QuietHours::new(&config.quiet_hours);
+    println!(
+        "feedtui daemon: keeping {} widgets fresh in {} (Ctrl-C or `feedtui ctl quit` to stop)",
+        feeds.len(),
+        cache_path.display()
+    );
+
+    let mut save_tick = tokio::time::interval(SAVE_EVERY);
+    let mut unsaved = false;
+    loop {
+        tokio::select! {
+            Some((idx, data)) = data_rx.recv() => {
+                let feed = &feeds[idx];
+                unsaved |= cache.insert(&feed.id, &data);
+                if let Some(ref state) = api_state {
+                    let mut data = data;
+                    filter::apply(&feed.filters, &mut data);
+                    state.update(&feed.id, &data);
+                }
+            }
+            Some(request) = control_rx.recv() => {
+                let (result, quit) = match request.command {
+                    ControlCommand::Refresh(None) => {
+                        feeds.iter().for_each(|feed| feed.refresh.notify_one());
+                        (Ok(()), false)
+                    }
+                    ControlCommand::Refresh(Some(name)) => match find_feed(&feeds, &name) {
+                        Some(feed) => {
+                            feed.refresh.notify_one();
+                            (Ok(()), false)
+                        }
+                        None => (Err(format!("no widget named {:?}", name)), false),
+                    },
+                    ControlCommand::Focus(_) => {
+                        (Err("the daemon has no dashboard to focus".to_string()), false)
+                    }
+                    ControlCommand::Notify(message) => {
+                        if !quiet_hours.contains(Local::now().naive_local()) {
+                            notifier.send(AlertKind::Notify, &message);
+                        }
+                        (Ok(()), false)
+                    }
+                    ControlCommand::Quit => (Ok(()), true),
+                };
+                let _ = request.reply.send(result);
+                if quit {
+                    break;
+                }
+            }
+            _ = save_tick.tick() => {
+                if unsaved {
+                    if let Err(e) = cache.save(&cache_path) {
+                        tracing::warn!("Could not save the feed cache: {}", e);
+                    }
+                    unsaved = false;
+                }
+            }
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    for task in tasks {
+        task.abort();
+    }
+    drop(control_socket);
+    cache.save(&cache_path)?;
+    Ok(())
+}
+
+/// Fetch every `interval`, or straight away when `refresh` is signalled
+async fn poll(
+    idx: usize,
+    widget_id: String,
+    fetcher: Box<dyn FeedFetcher>,
+    retry: RetryPolicy,
+    interval: Duration,
+    refresh: Arc<Notify>,
+    tx: mpsc::UnboundedSender<(usize, FeedData)>,
+) {
+    loop {
+        let data = match retry.fetch(fetcher.as_ref(), &Progress::none()).await {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::warn!(widget = %widget_id, "fetch failed: {:#}", e);
+                FeedData::Error(http::error_message(&e))
+            }
+        };
+        if tx.send((idx, data)).is_err() {
+            return;
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(retry.jittered(interval)) => {}
+            _ = refresh.notified() => {}
+        }
+    }
+}
+
+/// A widget by id, title, or 1-based position, as `feedtui ctl` names them
+fn find_feed<'a>(feeds: &'a [Feed], name: &str) -> Option<&'a Feed> {
+    feeds
+        .iter()
+        .find(|feed| feed.id == name)
+        .or_else(|| {
+            feeds
+                .iter()
+                .find(|feed| feed.title.eq_ignore_ascii_case(name))
+        })
+        .or_else(|| {
+            name.parse::<usize>()
+                .ok()
+                .and_then(|n| feeds.get(n.checked_sub(1)?))
+        })
+}
@@ -0,0 +1,220 @@
+use crate::feeds::FeedData;
+use serde::Serialize;
+
+/// Rank of an ordinary headline, before any keyword bonus
+const HEADLINE: f64 = 0.5;
+/// Bonus for mentioning one of the digest's keywords
+const KEYWORD_BONUS: f64 = 2.0;
+/// Rank of a game involving a favorite team
+const FAVORITE_GAME: f64 = 2.0;
+/// Rank of an unread GitHub notification
+const UNREAD: f64 = 1.5;
+/// Hacker News points worth one headline's rank
+const POINTS_PER_RANK: f64 = 100.0;
+
+/// One item picked for the digest, with why it was picked
+#[derive(Debug, Clone, Serialize)]
+pub struct DigestItem {
+    pub title: String,
+    pub url: Option<String>,
+    /// Title of the widget it came from
+    pub source: String,
+    pub reason: String,
+    pub rank: f64,
+}
+
+/// Decides how interesting each item of a feed is
+#[derive(Debug, Clone)]
+pub struct Ranker {
+    keywords: Vec<String>,
+    favorite_teams: Vec<String>,
+    big_move_percent: f64,
+}
+
+impl Ranker {
+    pub fn new(keywords: &[String], favorite_teams: &[String], big_move_percent: f64) -> Self {
+        Self {
+            keywords: keywords.iter().map(|k| k.to_lowercase()).collect(),
+            favorite_teams: favorite_teams.iter().map(|t| t.to_lowercase()).collect(),
+            big_move_percent,
+        }
+    }
+
+    /// Items of `data` worth a place in the digest, ranked
+    pub fn rank(&self, source: &str, data: &FeedData) -> Vec<DigestItem> {
+        let item = |title: &str, url: Option<String>, reason: String, rank: f64| {
+            let (rank, reason) = match self.keyword_in(title) {
+                Some(keyword) => (rank + KEYWORD_BONUS, format!("mentions {}", keyword)),
+                None => (rank, reason),
+            };
+            DigestItem {
+                title: title.to_string(),
+                url,
+                source: source.to_string(),
+                reason,
+                rank,
+            }
+        };
+
+        match data {
+            FeedData::HackerNews(stories) => stories
+                .iter()
+                .map(|s| {
+                    let url = s
+                        .url
+                        .clone()
+                        .or_else(|| Some(format!("https://news.ycombinator.com/item?id={}", s.id)));
+                    let rank = f64::from(s.score) / POINTS_PER_RANK;
+                    item(&s.title, url, format!("{} points", s.score), rank)
+                })
+                .collect(),
+            FeedData::Stocks(quotes) => quotes
+                .iter()
+                .filter(|q| q.change_percent.abs() >= self.big_move_percent)
+                .map(|q| {
+                    item(
+                        &format!("{} {}", q.symbol, q.name),
+                        Some(format!("https://finance.yahoo.com/quote/{}", q.symbol)),
+                        format!("{:+.2}% today", q.change_percent),
+                        q.change_percent.abs() / self.big_move_percent,
+                    )
+                })
+                .collect(),
+            FeedData::Rss(items) => items
+                .iter()
+                .map(|i| item(&i.title, i.link.clone(), String::new(), HEADLINE))
+                .collect(),
+            FeedData::Sports(events) => events
+                .iter()
+                .filter(|e| self.is_favorite(&e.home_team) || self.is_favorite(&e.away_team))
+                .map(|e| {
+                    let title = match (e.away_score, e.home_score) {
+                        (Some(a), Some(h)) => {
+                            format!("{} {} - {} {}", e.away_team, a, h, e.home_team)
+                        }
+                        _ => format!("{} vs {}", e.away_team, e.home_team),
+                    };
+                    item(&title, None, e.status.clone(), FAVORITE_GAME)
+                })
+                .collect(),
+            FeedData::Github(dashboard) => dashboard
+                .notifications
+                .iter()
+                .filter(|n| n.unread)
+                .map(|n| {
+                    item(
+                        &n.title,
+                        Some(n.url.clone()),
+                        format!("unread, {}", n.repository),
+                        UNREAD,
+                    )
+                })
+                .collect(),
+            FeedData::Youtube(videos) => videos
+                .iter()
+                .map(|v| {
+                    item(
+                        &v.title,
+                        Some(format!("https://www.youtube.com/watch?v={}", v.id)),
+                        v.channel.clone(),
+                        HEADLINE,
+                    )
+                })
+                .collect(),
+            FeedData::Script(lines) => lines
+                .iter()
+                .map(|l| item(&l.text, l.url.clone(), String::new(), HEADLINE))
+                .collect(),
+            FeedData::Items(items) => items
+                .iter()
+                .map(|i| {
+                    let reason = i.meta.clone().unwrap_or_default();
+                    item(&i.title, i.url.clone(), reason, HEADLINE)
+                })
+                .collect(),
+            FeedData::Wallet(_)
+            | FeedData::Odds(_)
+            | FeedData::Loading
+            | FeedData::Progress { .. }
+            | FeedData::Error(_) => Vec::new(),
+        }
+    }
+
+    fn keyword_in(&self, title: &str) -> Option<&str> {
+        let title = title.to_lowercase();
+        self.keywords
+            .iter()
+            .find(|k| title.contains(k.as_str()))
+            .map(|k| k.as_str())
+    }
+
+    fn is_favorite(&self, team: &str) -> bool {
+        let team = team.to_lowercase();
+        self.favorite_teams
+            .iter()
+            .any(|f| team.contains(f.as_str()))
+    }
+}
+
+/// The `limit` highest-ranked items; ties keep their sources' order
+pub fn top(items: impl IntoIterator<Item = DigestItem>, limit: usize) -> Vec<DigestItem> {
+    let mut items: Vec<DigestItem> = items.into_iter().collect();
+    items.sort_by(|a, b| b.rank.total_cmp(&a.rank));
+    items.truncate(limit);
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::{HnStory, RssItem, StockQuote};
+
+    #[test]
+    fn ranks_across_sources() {
+        let ranker = Ranker::new(&["Rust".to_string()], &[], 3.0);
+        let hn = FeedData::HackerNews(vec![HnStory {
+            id: 1,
+            title: "Show HN: a thing".to_string(),
+            url: None,
+            score: 420,
+            by: "pg".to_string(),
+            descendants: 0,
+        }]);
+        let rss = FeedData::Rss(
+            ["Rust 2.0 released", "Weather today"]
+                .iter()
+                .map(|title| RssItem {
+                    title: title.to_string(),
+                    link: None,
+                    published: None,
+                    published_at: None,
+                    source: "news".to_string(),
+                    description: None,
+                    discussion: None,
+                })
+                .collect(),
+        );
+        let stocks = FeedData::Stocks(
+            [("AAPL", 1.0), ("NVDA", -6.0)]
+                .iter()
+                .map(|&(symbol, change_percent)| StockQuote {
+                    symbol: symbol.to_string(),
+                    price: 100.0,
+                    change: change_percent,
+                    change_percent,
+                    name: symbol.to_string(),
+                })
+                .collect(),
+        );
+
+        let items = [("HN", hn), ("News", rss), ("Stocks", stocks)]
+            .iter()
+            .flat_map(|(source, data)| ranker.rank(source, data))
+            .collect::<Vec<_>>();
+        let titles: Vec<String> = top(items, 3).into_iter().map(|i| i.title).collect();
+        assert_eq!(
+            titles,
+            vec!["Show HN: a thing", "Rust 2.0 released", "NVDA NVDA"]
+        );
+    }
+}
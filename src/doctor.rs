@@ -0,0 +1,447 @@
+use crate::config::{Config, WidgetConfig};
+use crate::feeds::sports::SportsFetcher;
+use anyhow::Result;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::time::Duration;
+
+const HN_CHECK_URL: &str = "https://hacker-news.firebaseio.com/v0/topstories.json";
+const GITHUB_USER_URL: &str = "https://api.github.com/user";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Status {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Status::Pass => "✓",
+            Status::Warn => "!",
+            Status::Fail => "✗",
+        }
+    }
+}
+
+struct Check {
+    status: Status,
+    label: String,
+    hint: Option<String>,
+}
+
+#[derive(Default)]
+struct Report {
+    checks: Vec<Check>,
+    /// Collect checks without printing them
+    quiet: bool,
+}
+
+impl Report {
+    fn pass(&mut self, label: impl Into<String>) {
+        self.push(Status::Pass, label.into(), None);
+    }
+
+    fn warn(&mut self, label: impl Into<String>, hint: impl Into<String>) {
+        self.push(Status::Warn, label.into(), Some(hint.into()));
+    }
+
+    fn fail(&mut self, label: impl Into<String>, hint: impl Into<String>) {
+        self.push(Status::Fail, label.into(), Some(hint.into()));
+    }
+
+    fn push(&mut self, status: Status, label: String, hint: Option<String>) {
+        let check = Check {
+            status,
+            label,
+            hint,
+        };
+        if !self.quiet {
+            print_check(&check);
+        }
+        self.checks.push(check);
+    }
+
+    fn count(&self, status: Status) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+}
+
+fn print_check(check: &Check) {
+    println!("  {} {}", check.status.symbol(), check.label);
+    if let Some(ref hint) = check.hint {
+        println!("      → {}", hint);
+    }
+}
+
+/// Entry point for `feedtui doctor`
+pub async fn run_doctor(config_path: &Path) -> Result<()> {
+    let mut report = Report::default();
+
+    println!("=== feedtui doctor ===\n");
+
+    println!("Terminal");
+    check_terminal(&mut report);
+
+    println!("\nConfiguration");
+    let config = check_config(&mut report, config_path);
+
+    println!("\nConnectivity");
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .user_agent("feedtui-doctor")
+        .build()?;
+    check_connectivity(&mut report, &client, &config).await;
+
+    let failures = report.count(Status::Fail);
+    println!(
+        "\n{} passed, {} warnings, {} failed",
+        report.count(Status::Pass),
+        report.count(Status::Warn),
+        failures
+    );
+
+    if failures > 0 {
+        anyhow::bail!("{} check(s) failed", failures);
+    }
+    Ok(())
+}
+
+/// Keys the setup wizard checks before saving them
+#[derive(Debug, Clone, Copy)]
+pub enum ApiKey {
+    Github,
+    Youtube,
+}
+
+/// Check one key the way `feedtui doctor` does, without printing; the
+/// problem found, if any
+pub async fn validate_key(kind: ApiKey, key: &str) -> Result<(), String> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .user_agent("feedtui-doctor")
+        .build()
+        .map_err(|e| e.to_string())?;
+    let mut report = Report {
+        quiet: true,
+        ..Report::default()
+    };
+    match kind {
+        ApiKey::Github => check_github(&mut report, &client, key).await,
+        ApiKey::Youtube => check_youtube(&mut report, &client, key).await,
+    }
+    match report.checks.into_iter().find(|c| c.status == Status::Fail) {
+        Some(check) => Err(check.label),
+        None => Ok(()),
+    }
+}
+
+fn check_terminal(report: &mut Report) {
+    if std::io::stdout().is_terminal() {
+        report.pass("stdout is a terminal");
+    } else {
+        report.warn(
+            "stdout is not a terminal",
+            "the dashboard needs an interactive terminal; `feedtui fetch` works without one",
+        );
+    }
+
+    match crossterm::terminal::size() {
+        Ok((cols, rows)) if cols >= 80 && rows >= 24 => {
+            report.pass(format!("terminal size {}x{}", cols, rows))
+        }
+        Ok((cols, rows)) => report.warn(
+            format!("terminal size {}x{} is small", cols, rows),
+            "widgets are easier to read at 80x24 or larger",
+        ),
+        Err(e) => report.warn(
+            format!("could not query terminal size: {}", e),
+            "run doctor from the terminal you use for feedtui",
+        ),
+    }
+
+    let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+    if colorterm.contains("truecolor") || colorterm.contains("24bit") {
+        report.pass("truecolor supported");
+    } else {
+        report.warn(
+            "truecolor not advertised (COLORTERM unset)",
+            "some creature colors fall back to the nearest 256-color value",
+        );
+    }
+
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+    if locale.to_uppercase().contains("UTF-8") || locale.to_uppercase().contains("UTF8") {
+        report.pass(format!("UTF-8 locale ({})", locale));
+    } else {
+        report.warn(
+            format!("locale {:?} is not UTF-8", locale),
+            "set LANG=en_US.UTF-8 (or similar) so borders and icons render correctly",
+        );
+    }
+}
+
+fn check_config(report: &mut Report, path: &Path) -> Config {
+    if !path.exists() {
+        report.warn(
+            format!("no config at {}", path.display()),
+            "using the default dashboard; run `feedtui init` to create one",
+        );
+        return Config::default();
+    }
+
+    let config = match Config::load(path) {
+        Ok(config) => {
+            report.pass(format!(
+                "{} parses ({} widgets)",
+                path.display(),
+                config.widgets.len()
+            ));
+            config
+        }
+        Err(e) => {
+            report.fail(
+                format!("{} is invalid: {}", path.display(), e),
+                "fix the error above or regenerate with `feedtui init --force`",
+            );
+            return Config::default();
+        }
+    };
+
+    if config.general.refresh_interval_secs < 10 {
+        report.warn(
+            format!(
+                "refresh_interval_secs = {} is very short",
+                config.general.refresh_interval_secs
+            ),
+            "APIs may rate-limit you; 30-60 seconds is a good default",
+        );
+    }
+
+    let mut positions = Vec::new();
+    for widget in &config.widgets {
+        let pos = (widget.position().row, widget.position().col);
+        if positions.contains(&pos) {
+            report.warn(
+                format!("two widgets share position row={}, col={}", pos.0, pos.1),
+                "only one of them will be visible; give each widget its own position",
+            );
+        }
+        positions.push(pos);
+
+        if let WidgetConfig::Sports(cfg) = widget {
+            for league in &cfg.leagues {
+                if SportsFetcher::league_to_espn_endpoint(league).is_none() {
+                    report.fail(
+                        format!("unknown sports league {:?}", league),
+                        "supported: nba, nfl, mlb, nhl, mls, epl, ncaaf, ncaab",
+                    );
+                }
+            }
+        }
+    }
+
+    config
+}
+
+async fn check_connectivity(report: &mut Report, client: &reqwest::Client, config: &Config) {
+    let mut checked_hn = false;
+    let mut checked_espn = false;
+    let mut checked_yahoo = false;
+
+    for widget in &config.widgets {
+        match widget {
+            WidgetConfig::Hackernews(_) if !checked_hn => {
+                checked_hn = true;
+                check_url(report, client, "Hacker News API", HN_CHECK_URL).await;
+            }
+            WidgetConfig::Sports(cfg) if !checked_espn => {
+                checked_espn = true;
+                if let Some(endpoint) = cfg
+                    .leagues
+                    .iter()
+                    .find_map(|l| SportsFetcher::league_to_espn_endpoint(l))
+                {
+                    let url = format!(
+                        "https://site.api.espn.com/apis/site/v2/sports/{}/scoreboard",
+                        endpoint
+                    );
+                    check_url(report, client, "ESPN scoreboard API", &url).await;
+                }
+            }
+            WidgetConfig::Stocks(cfg) if !checked_yahoo => {
+                checked_yahoo = true;
+                if let Some(symbol) = cfg.symbols.first() {
+                    let url = format!(
+                        "https://query1.finance.yahoo.com/v8/finance/chart/{}?interval=1d&range=1d",
+                        symbol
+                    );
+                    check_url(report, client, "Yahoo Finance API", &url).await;
+                }
+            }
+            WidgetConfig::Rss(cfg) => {
+                for feed in &cfg.feeds {
+                    check_url(report, client, &format!("RSS feed {}", feed), feed).await;
+                }
+            }
+            WidgetConfig::Github(cfg) => {
+                for account in cfg.all_accounts() {
+                    check_github(report, client, &account.token).await;
+                }
+            }
+            WidgetConfig::Youtube(cfg) => check_youtube(report, client, &cfg.api_key).await,
+            WidgetConfig::Odds(cfg) => check_odds(report, client, &cfg.api_key).await,
+            _ => {}
+        }
+    }
+}
+
+async fn check_url(report: &mut Report, client: &reqwest::Client, label: &str, url: &str) {
+    match client.get(url).send().await {
+        Ok(response) if response.status().is_success() => {
+            report.pass(format!("{} reachable", label))
+        }
+        Ok(response) => report.fail(
+            format!("{} returned {}", label, response.status()),
+            format!("check that {} is still valid", url),
+        ),
+        Err(e) => report.fail(
+            format!("{} unreachable: {}", label, e),
+            "check your network connection, proxy, or firewall",
+        ),
+    }
+}
+
+fn is_placeholder(secret: &str) -> bool {
+    secret.trim().is_empty() || secret.starts_with("${") || secret.starts_with("your")
+}
+
+async fn check_github(report: &mut Report, client: &reqwest::Client, token: &str) {
+    if is_placeholder(token) {
+        report.fail(
+            format!("GitHub token is not set ({:?})", token),
+            "paste a personal access token with `notifications` and `repo` scope into config.toml",
+        );
+        return;
+    }
+
+    let response = client
+        .get(GITHUB_USER_URL)
+        .header("Authorization", format!("token {}", token))
+        .header("Accept", "application/vnd.github.v3+json")
+        .send()
+        .await;
+
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            report.fail(
+                format!("GitHub API unreachable: {}", e),
+                "check your network connection, proxy, or firewall",
+            );
+            return;
+        }
+    };
+
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        report.fail(
+            "GitHub token rejected (401)",
+            "the token is invalid or expired; create a new one at https://github.com/settings/tokens",
+        );
+        return;
+    }
+    if !status.is_success() {
+        report.fail(
+            format!("GitHub API returned {}", status),
+            "see https://www.githubstatus.com for outages",
+        );
+        return;
+    }
+    report.pass("GitHub token valid");
+
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+    };
+    if let (Some(remaining), Some(limit)) =
+        (header("x-ratelimit-remaining"), header("x-ratelimit-limit"))
+    {
+        if remaining * 10 < limit {
+            report.warn(
+                format!(
+                    "GitHub rate limit nearly exhausted ({}/{} left)",
+                    remaining, limit
+                ),
+                "increase refresh_interval_secs or wait for the hourly reset",
+            );
+        } else {
+            report.pass(format!(
+                "GitHub rate limit headroom {}/{}",
+                remaining, limit
+            ));
+        }
+    }
+}
+
+async fn check_youtube(report: &mut Report, client: &reqwest::Client, api_key: &str) {
+    if is_placeholder(api_key) {
+        report.fail(
+            format!("YouTube API key is not set ({:?})", api_key),
+            "create a YouTube Data API v3 key at https://console.cloud.google.com/apis/credentials",
+        );
+        return;
+    }
+
+    let url = format!(
+        "https://www.googleapis.com/youtube/v3/videos?part=id&id=dQw4w9WgXcQ&key={}",
+        api_key
+    );
+    match client.get(&url).send().await {
+        Ok(response) if response.status().is_success() => report.pass("YouTube API key valid"),
+        Ok(response) if response.status() == reqwest::StatusCode::FORBIDDEN => report.fail(
+            "YouTube API quota exceeded or API not enabled (403)",
+            "enable the YouTube Data API v3 for your project or wait for the daily quota reset",
+        ),
+        Ok(response) => report.fail(
+            format!("YouTube API key rejected ({})", response.status()),
+            "double-check the key in config.toml",
+        ),
+        Err(e) => report.fail(
+            format!("YouTube API unreachable: {}", e),
+            "check your network connection, proxy, or firewall",
+        ),
+    }
+}
+
+async fn check_odds(report: &mut Report, client: &reqwest::Client, api_key: &str) {
+    if is_placeholder(api_key) {
+        report.fail(
+            format!("Odds API key is not set ({:?})", api_key),
+            "get a free key at https://the-odds-api.com",
+        );
+        return;
+    }
+
+    // Listing sports doesn't count against the request quota
+    let url = format!("https://api.the-odds-api.com/v4/sports?apiKey={}", api_key);
+    match client.get(&url).send().await {
+        Ok(response) if response.status().is_success() => report.pass("Odds API key valid"),
+        Ok(response) => report.fail(
+            format!("Odds API key rejected ({})", response.status()),
+            "double-check the key in config.toml",
+        ),
+        Err(e) => report.fail(
+            format!("Odds API unreachable: {}", e.without_url()),
+            "check your network connection, proxy, or firewall",
+        ),
+    }
+}
@@ -1,12 +1,18 @@
 use anyhow::Result;
-use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent};
-use std::time::Duration;
+use crossterm::event::{
+    self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers, MouseEvent,
+};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
 #[derive(Debug, Clone)]
 pub enum Event {
     Key(KeyEvent),
     Mouse(MouseEvent),
+    /// Text pasted while bracketed paste is enabled
+    Paste(String),
     Tick,
     Resize(u16, u16),
 }
@@ -14,33 +20,43 @@ pub enum Event {
 pub struct EventHandler {
     rx: mpsc::UnboundedReceiver<Event>,
     _tx: mpsc::UnboundedSender<Event>,
+    tick_rate_ms: Arc<AtomicU64>,
+    /// Asks the reader to stop taking input from the terminal
+    paused: Arc<AtomicBool>,
+    /// Set by the reader once it has stopped
+    idle: Arc<AtomicBool>,
 }
 
 impl EventHandler {
     pub fn new(tick_rate: Duration) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
         let event_tx = tx.clone();
+        let tick_rate_ms = Arc::new(AtomicU64::new(tick_rate.as_millis() as u64));
+        let poll_rate_ms = tick_rate_ms.clone();
+        let paused = Arc::new(AtomicBool::new(false));
+        let idle = Arc::new(AtomicBool::new(false));
+        let (is_paused, is_idle) = (paused.clone(), idle.clone());
 
         tokio::spawn(async move {
             loop {
+                if is_paused.load(Ordering::Acquire) {
+                    is_idle.store(true, Ordering::Release);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    continue;
+                }
+                is_idle.store(false, Ordering::Release);
+
+                let tick_rate = Duration::from_millis(poll_rate_ms.load(Ordering::Relaxed));
                 if event::poll(tick_rate).unwrap_or(false) {
-                    match event::read() {
-                        Ok(CrosstermEvent::Key(key)) => {
-                            if event_tx.send(Event::Key(key)).is_err() {
-                                break;
-                            }
-                        }
-                        Ok(CrosstermEvent::Mouse(mouse)) => {
-                            if event_tx.send(Event::Mouse(mouse)).is_err() {
-                                break;
-                            }
-                        }
-                        Ok(CrosstermEvent::Resize(w, h)) => {
-                            if event_tx.send(Event::Resize(w, h)).is_err() {
-                                break;
-                            }
-                        }
-                        _ => {}
+                    let event = match event::read() {
+                        Ok(CrosstermEvent::Key(key)) => Event::Key(key),
+                        Ok(CrosstermEvent::Mouse(mouse)) => Event::Mouse(mouse),
+                        Ok(CrosstermEvent::Paste(text)) => Event::Paste(text),
+                        Ok(CrosstermEvent::Resize(w, h)) => Event::Resize(w, h),
+                        _ => continue,
+                    };
+                    if event_tx.send(event).is_err() {
+                        break;
                     }
                 } else if event_tx.send(Event::Tick).is_err() {
                     break;
@@ -48,7 +64,33 @@ impl EventHandler {
             }
         });
 
-        Self { rx, _tx: tx }
+        Self {
+            rx,
+            _tx: tx,
+            tick_rate_ms,
+            paused,
+            idle,
+        }
+    }
+
+    /// Stop reading the terminal so another program can use it; returns
+    /// once no read is in progress
+    pub async fn pause(&self) {
+        self.paused.store(true, Ordering::Release);
+        while !self.idle.load(Ordering::Acquire) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    pub fn resume(&self) {
+        self.idle.store(false, Ordering::Release);
+        self.paused.store(false, Ordering::Release);
+    }
+
+    /// Change how often `Tick` fires; takes effect after the current tick
+    pub fn set_tick_rate(&self, tick_rate: Duration) {
+        self.tick_rate_ms
+            .store(tick_rate.as_millis() as u64, Ordering::Relaxed);
     }
 
     pub async fn next(&mut self) -> Result<Event> {
@@ -58,3 +100,101 @@ impl EventHandler {
             .ok_or_else(|| anyhow::anyhow!("Event channel closed"))
     }
 }
+
+/// What a key press means once two-key sequences are taken into account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyInput {
+    /// The first key of a sequence; wait for the next one
+    Pending,
+    Chord(char, KeyCode),
+    Key(KeyEvent),
+}
+
+/// Tracks two-key sequences such as `g g` or `space r`
+pub struct Chords {
+    pending: Option<(char, Instant)>,
+    timeout: Duration,
+}
+
+impl Chords {
+    /// Keys that start a sequence instead of acting on their own
+    const LEADERS: [char; 2] = ['g', ' '];
+
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            pending: None,
+            timeout,
+        }
+    }
+
+    pub fn feed(&mut self, key: KeyEvent) -> KeyInput {
+        self.expire();
+        if let Some((leader, _)) = self.pending.take() {
+            return KeyInput::Chord(leader, key.code);
+        }
+
+        match key.code {
+            KeyCode::Char(c)
+                if Self::LEADERS.contains(&c)
+                    && !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.pending = Some((c, Instant::now()));
+                KeyInput::Pending
+            }
+            _ => KeyInput::Key(key),
+        }
+    }
+
+    /// The leader key waiting for its second key, if any
+    pub fn pending(&self) -> Option<char> {
+        self.pending.map(|(leader, _)| leader)
+    }
+
+    /// Drop a sequence that was not finished in time; returns whether one was
+    pub fn expire(&mut self) -> bool {
+        match self.pending {
+            Some((_, started)) if started.elapsed() > self.timeout => {
+                self.pending = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn leader_then_key_makes_a_chord() {
+        let mut chords = Chords::new(Duration::from_secs(1));
+        assert_eq!(chords.feed(key('g')), KeyInput::Pending);
+        assert_eq!(chords.pending(), Some('g'));
+        assert_eq!(
+            chords.feed(key('t')),
+            KeyInput::Chord('g', KeyCode::Char('t'))
+        );
+        assert_eq!(chords.pending(), None);
+    }
+
+    #[test]
+    fn other_keys_pass_through() {
+        let mut chords = Chords::new(Duration::from_secs(1));
+        assert_eq!(chords.feed(key('j')), KeyInput::Key(key('j')));
+    }
+
+    #[test]
+    fn unfinished_sequences_expire() {
+        let mut chords = Chords::new(Duration::ZERO);
+        assert_eq!(chords.feed(key('g')), KeyInput::Pending);
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(chords.feed(key('j')), KeyInput::Key(key('j')));
+    }
+}
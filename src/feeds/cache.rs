@@ -0,0 +1,102 @@
+use super::FeedData;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Each widget's last successful fetch, before filters, kept between runs
+/// and shared with `feedtui daemon`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FeedCache {
+    /// By widget id
+    widgets: HashMap<String, CachedFeed>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedFeed {
+    pub fetched_at: DateTime<Utc>,
+    pub data: FeedData,
+}
+
+impl FeedCache {
+    /// ~/.feedtui/cache.json
+    pub fn default_path() -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".feedtui")
+            .join("cache.json")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Write through a temporary file so the dashboard never reads half of
+    /// what the daemon is writing
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp = path.with_extension("json.tmp");
+        std::fs::write(&temp, serde_json::to_string(self)?)?;
+        std::fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&CachedFeed> {
+        self.widgets.get(id)
+    }
+
+    /// Keep `data` for the widget unless it is an error or not yet data;
+    /// returns whether it was kept
+    pub fn insert(&mut self, id: &str, data: &FeedData) -> bool {
+        if matches!(
+            data,
+            FeedData::Loading | FeedData::Progress { .. } | FeedData::Error(_)
+        ) {
+            return false;
+        }
+        self.widgets.insert(
+            id.to_string(),
+            CachedFeed {
+                fetched_at: Utc::now(),
+                data: data.clone(),
+            },
+        );
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::StockQuote;
+
+    #[test]
+    fn keeps_only_real_data_and_reads_it_back() {
+        let mut cache = FeedCache::default();
+        let quote = StockQuote {
+            symbol: "AAPL".to_string(),
+            price: 190.5,
+            change: 1.25,
+            change_percent: 0.66,
+            name: "Apple".to_string(),
+        };
+        assert!(cache.insert("stocks-1", &FeedData::Stocks(vec![quote])));
+        assert!(!cache.insert("stocks-1", &FeedData::Error("offline".to_string())));
+        assert!(!cache.insert("rss-1", &FeedData::Loading));
+
+        let json = serde_json::to_string(&cache).unwrap();
+        let cache: FeedCache = serde_json::from_str(&json).unwrap();
+        assert!(cache.get("rss-1").is_none());
+        match &cache.get("stocks-1").unwrap().data {
+            FeedData::Stocks(quotes) => assert_eq!(quotes[0].symbol, "AAPL"),
+            other => panic!("expected stocks, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::FeedMessage;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+/// Updates waiting for the UI, at most one per widget
+type Pending = Arc<Mutex<HashMap<String, FeedMessage>>>;
+
+/// Create a channel that holds only the latest update for each widget.
+///
+/// Memory stays bounded by the number of widgets no matter how far behind
+/// the UI falls, and an update that is replaced before the UI reads it is
+/// dropped rather than rendered.
+pub fn channel() -> (FeedSender, FeedReceiver) {
+    let pending = Pending::default();
+    // Capacity 1: a queued wake-up already covers every later update
+    let (wake_tx, wake_rx) = mpsc::channel(1);
+    (
+        FeedSender {
+            pending: pending.clone(),
+            wake: wake_tx,
+        },
+        FeedReceiver {
+            pending,
+            wake: wake_rx,
+        },
+    )
+}
+
+#[derive(Clone)]
+pub struct FeedSender {
+    pending: Pending,
+    wake: mpsc::Sender<()>,
+}
+
+impl FeedSender {
+    /// Queue an update, replacing any unread one for the same widget.
+    /// Fails only once the receiver is gone.
+    pub fn send(&self, msg: FeedMessage) -> Result<(), FeedMessage> {
+        if self.wake.is_closed() {
+            return Err(msg);
+        }
+        lock(&self.pending).insert(msg.widget_id.clone(), msg);
+        let _ = self.wake.try_send(());
+        Ok(())
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.wake.is_closed()
+    }
+}
+
+pub struct FeedReceiver {
+    pending: Pending,
+    wake: mpsc::Receiver<()>,
+}
+
+impl FeedReceiver {
+    /// Next pending update, or `None` once every sender is gone
+    pub async fn recv(&mut self) -> Option<FeedMessage> {
+        loop {
+            if let Some(msg) = self.take_one() {
+                return Some(msg);
+            }
+            self.wake.recv().await?;
+        }
+    }
+
+    fn take_one(&self) -> Option<FeedMessage> {
+        let mut pending = lock(&self.pending);
+        let id = pending.keys().next()?.clone();
+        pending.remove(&id)
+    }
+}
+
+fn lock(pending: &Pending) -> std::sync::MutexGuard<'_, HashMap<String, FeedMessage>> {
+    pending.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::FeedData;
+
+    fn message(widget_id: &str, error: &str) -> FeedMessage {
+        FeedMessage {
+            widget_id: widget_id.to_string(),
+            data: FeedData::Error(error.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_only_the_latest_update_per_widget() {
+        let (tx, mut rx) = channel();
+        tx.send(message("a", "first")).unwrap();
+        tx.send(message("b", "only")).unwrap();
+        tx.send(message("a", "second")).unwrap();
+        drop(tx);
+
+        let mut received = Vec::new();
+        while let Some(msg) = rx.recv().await {
+            if let FeedData::Error(text) = msg.data {
+                received.push((msg.widget_id, text));
+            }
+        }
+        received.sort();
+
+        assert_eq!(
+            received,
+            vec![
+                ("a".to_string(), "second".to_string()),
+                ("b".to_string(), "only".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn send_fails_after_receiver_is_dropped() {
+        let (tx, rx) = channel();
+        drop(rx);
+        assert!(tx.send(message("a", "late")).is_err());
+        assert!(tx.is_closed());
+    }
+}
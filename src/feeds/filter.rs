@@ -0,0 +1,358 @@
+use crate::config::FilterConfig;
+use crate::feeds::{
+    FeedData, GameOdds, GenericItem, GithubCommit, GithubNotification, GithubPullRequest, HnStory,
+    RssItem, ScriptLine, SportsEvent, StockQuote, WalletHolding, YoutubeVideo,
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use regex::Regex;
+
+/// A field of a feed item, as seen by filters
+#[derive(Debug, Clone, Copy)]
+pub enum Value<'a> {
+    Text(&'a str),
+    Number(f64),
+    Time(DateTime<Utc>),
+    Flag(bool),
+}
+
+/// Items that filters can look into by field name
+pub trait Fields {
+    fn field(&self, name: &str) -> Option<Value<'_>>;
+}
+
+/// A compiled `[[widgets.filters]]` entry
+#[derive(Debug, Clone)]
+pub struct Filter {
+    field: String,
+    matches: Option<Regex>,
+    min: Option<f64>,
+    max_age: Option<chrono::Duration>,
+    is: Option<bool>,
+}
+
+impl Filter {
+    pub fn new(config: &FilterConfig) -> Result<Self> {
+        let matches = config
+            .matches
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .with_context(|| format!("invalid `matches` for field {:?}", config.field))?;
+        let max_age = config
+            .max_age_hours
+            .map(|hours| chrono::Duration::seconds((hours * 3600.0) as i64));
+        Ok(Self {
+            field: config.field.clone(),
+            matches,
+            min: config.min,
+            max_age,
+            is: config.is,
+        })
+    }
+
+    /// Compile a widget's filters, skipping (and logging) any that are invalid
+    pub fn compile_all(configs: &[FilterConfig]) -> Vec<Filter> {
+        configs
+            .iter()
+            .filter_map(|config| {
+                Filter::new(config)
+                    .map_err(|e| tracing::warn!("Ignoring filter: {:#}", e))
+                    .ok()
+            })
+            .collect()
+    }
+
+    /// Whether the item passes. Items without the field pass, so a filter on
+    /// e.g. `unread` leaves GitHub's pull requests and commits alone.
+    fn keeps(&self, item: &impl Fields, now: DateTime<Utc>) -> bool {
+        let Some(value) = item.field(&self.field) else {
+            return true;
+        };
+        if let Some(ref re) = self.matches {
+            let matched = match value {
+                Value::Text(text) => re.is_match(text),
+                Value::Number(n) => re.is_match(&n.to_string()),
+                Value::Time(t) => re.is_match(&t.to_rfc3339()),
+                Value::Flag(b) => re.is_match(&b.to_string()),
+            };
+            if !matched {
+                return false;
+            }
+        }
+        if let Some(min) = self.min {
+            if !matches!(value, Value::Number(n) if n >= min) {
+                return false;
+            }
+        }
+        if let Some(max_age) = self.max_age {
+            if !matches!(value, Value::Time(t) if now - t <= max_age) {
+                return false;
+            }
+        }
+        if let Some(is) = self.is {
+            if !matches!(value, Value::Flag(b) if b == is) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn retain<T: Fields>(items: &mut Vec<T>, filters: &[Filter], now: DateTime<Utc>) {
+    items.retain(|item| filters.iter().all(|f| f.keeps(item, now)));
+}
+
+/// Drop the items that don't pass every filter
+pub fn apply(filters: &[Filter], data: &mut FeedData) {
+    if filters.is_empty() {
+        return;
+    }
+    let now = Utc::now();
+    match data {
+        FeedData::HackerNews(stories) => retain(stories, filters, now),
+        FeedData::Stocks(quotes) => retain(quotes, filters, now),
+        FeedData::Rss(items) => retain(items, filters, now),
+        FeedData::Sports(events) => retain(events, filters, now),
+        FeedData::Github(dashboard) => {
+            retain(&mut dashboard.notifications, filters, now);
+            retain(&mut dashboard.pull_requests, filters, now);
+            retain(&mut dashboard.commits, filters, now);
+        }
+        FeedData::Youtube(videos) => retain(videos, filters, now),
+        FeedData::Script(lines) => retain(lines, filters, now),
+        FeedData::Items(items) => retain(items, filters, now),
+        FeedData::Wallet(holdings) => retain(holdings, filters, now),
+        FeedData::Odds(games) => retain(games, filters, now),
+        FeedData::Loading | FeedData::Progress { .. } | FeedData::Error(_) => {}
+    }
+}
+
+fn text(value: &str) -> Option<Value<'_>> {
+    Some(Value::Text(value))
+}
+
+fn number(value: impl Into<f64>) -> Option<Value<'static>> {
+    Some(Value::Number(value.into()))
+}
+
+/// GitHub timestamps are RFC 3339
+fn time(value: &str) -> Option<Value<'static>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| Value::Time(t.with_timezone(&Utc)))
+}
+
+impl Fields for HnStory {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "title" => text(&self.title),
+            "url" => self.url.as_deref().and_then(text),
+            "by" | "author" => text(&self.by),
+            "score" => number(self.score),
+            "comments" => number(self.descendants),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for StockQuote {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "symbol" => text(&self.symbol),
+            "name" | "title" => text(&self.name),
+            "price" => number(self.price),
+            "change" => number(self.change),
+            "change_percent" => number(self.change_percent),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for RssItem {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "title" => text(&self.title),
+            "url" | "link" => self.link.as_deref().and_then(text),
+            "source" => text(&self.source),
+            "description" => self.description.as_deref().and_then(text),
+            "published" => self.published_at.map(Value::Time),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for WalletHolding {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "asset" => text(&self.asset),
+            "address" => text(&self.address),
+            "label" | "title" => self.label.as_deref().and_then(text),
+            "balance" => self.balance.and_then(number),
+            "value" => self.value().and_then(number),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for GameOdds {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "league" => text(&self.league),
+            "home_team" => text(&self.home_team),
+            "away_team" => text(&self.away_team),
+            "bookmaker" => text(&self.bookmaker),
+            "starts" => self.commence_time.map(Value::Time),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for SportsEvent {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "league" => text(&self.league),
+            "home_team" => text(&self.home_team),
+            "away_team" => text(&self.away_team),
+            "status" => text(&self.status),
+            "win_probability" => self.home_win_probability.and_then(number),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for GithubNotification {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "title" => text(&self.title),
+            "repository" => text(&self.repository),
+            "account" => self.account.as_deref().and_then(text),
+            "type" => text(&self.notification_type),
+            "reason" => text(&self.reason),
+            "unread" => Some(Value::Flag(self.unread)),
+            "updated" => time(&self.updated_at),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for GithubPullRequest {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "title" => text(&self.title),
+            "repository" => text(&self.repository),
+            "account" => self.account.as_deref().and_then(text),
+            "author" => text(&self.author),
+            "state" => text(&self.state),
+            "draft" => Some(Value::Flag(self.draft)),
+            "comments" => number(self.comments),
+            "updated" => time(&self.updated_at),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for GithubCommit {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "title" | "message" => text(&self.message),
+            "repository" => text(&self.repository),
+            "account" => self.account.as_deref().and_then(text),
+            "author" => text(&self.author),
+            "branch" => text(&self.branch),
+            "updated" => time(&self.timestamp),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for YoutubeVideo {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "title" => text(&self.title),
+            "channel" | "source" => text(&self.channel),
+            "description" => text(&self.description),
+            "published" => NaiveDate::parse_from_str(&self.published, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|t| Value::Time(t.and_utc())),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for ScriptLine {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "title" | "text" => text(&self.text),
+            "url" => self.url.as_deref().and_then(text),
+            _ => None,
+        }
+    }
+}
+
+impl Fields for GenericItem {
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match name {
+            "title" => text(&self.title),
+            "url" => self.url.as_deref().and_then(text),
+            "description" => self.description.as_deref().and_then(text),
+            "meta" => self.meta.as_deref().and_then(text),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn story(title: &str, score: u32) -> HnStory {
+        HnStory {
+            id: 1,
+            title: title.to_string(),
+            url: None,
+            score,
+            by: "pg".to_string(),
+            descendants: 0,
+        }
+    }
+
+    fn filter(toml: &str) -> Filter {
+        Filter::new(&toml::from_str(toml).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn keeps_items_passing_every_filter() {
+        let filters = [
+            filter("field = \"score\"\nmin = 100"),
+            filter("field = \"title\"\nmatches = \"(?i)rust\""),
+        ];
+        let mut data = FeedData::HackerNews(vec![
+            story("Rust 2.0", 250),
+            story("Rust tips", 40),
+            story("Go news", 300),
+        ]);
+        apply(&filters, &mut data);
+        assert_eq!(data.item_titles(), vec!["Rust 2.0"]);
+    }
+
+    #[test]
+    fn max_age_and_missing_fields() {
+        let filters = [filter("field = \"published\"\nmax_age_hours = 48")];
+        let item = |title: &str, hours: i64| RssItem {
+            title: title.to_string(),
+            link: None,
+            published: None,
+            published_at: Some(Utc::now() - chrono::Duration::hours(hours)),
+            source: "feed".to_string(),
+            description: None,
+            discussion: None,
+        };
+        let mut undated = item("undated", 0);
+        undated.published_at = None;
+        let mut data = FeedData::Rss(vec![item("fresh", 3), item("stale", 72), undated]);
+        apply(&filters, &mut data);
+        assert_eq!(data.item_titles(), vec!["fresh", "undated"]);
+    }
+}
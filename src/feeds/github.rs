@@ -1,22 +1,24 @@
 use super::{
-    FeedData, FeedFetcher, GithubCommit, GithubDashboard, GithubNotification, GithubPullRequest,
+    http, FeedData, FeedFetcher, GithubCommit, GithubDashboard, GithubNotification,
+    GithubPullRequest, Progress,
 };
+use crate::config::GithubAccount;
 use anyhow::Result;
 use async_trait::async_trait;
 use serde::Deserialize;
+use std::time::Duration;
 
 const GITHUB_API_BASE: &str = "https://api.github.com";
 
 pub struct GithubFetcher {
-    token: String,
-    username: String,
+    accounts: Vec<GithubAccount>,
     show_notifications: bool,
     show_pull_requests: bool,
     show_commits: bool,
     max_notifications: usize,
     max_pull_requests: usize,
     max_commits: usize,
-    client: reqwest::Client,
+    client: http::HttpClient,
 }
 
 #[derive(Debug, Deserialize)]
@@ -117,35 +119,72 @@ struct EventCommitAuthor {
 
 impl GithubFetcher {
     pub fn new(
-        token: String,
-        username: String,
+        accounts: Vec<GithubAccount>,
         show_notifications: bool,
         show_pull_requests: bool,
         show_commits: bool,
         max_notifications: usize,
         max_pull_requests: usize,
         max_commits: usize,
+        timeout: Duration,
     ) -> Self {
         Self {
-            token,
-            username,
+            accounts,
             show_notifications,
             show_pull_requests,
             show_commits,
             max_notifications,
             max_pull_requests,
             max_commits,
-            client: reqwest::Client::new(),
+            client: http::client(timeout),
         }
     }
 
-    async fn fetch_notifications(&self) -> Result<Vec<GithubNotification>> {
+    /// Mark every notification as read on GitHub, for every account
+    pub async fn mark_all_read(&self) -> Result<()> {
+        for account in &self.accounts {
+            self.mark_account_read(account).await?;
+        }
+        Ok(())
+    }
+
+    /// The account's label when items from several accounts are merged
+    fn tag(&self, account: &GithubAccount) -> Option<String> {
+        (self.accounts.len() > 1).then(|| account.label().to_string())
+    }
+
+    async fn mark_account_read(&self, account: &GithubAccount) -> Result<()> {
+        let url = format!("{}/notifications", GITHUB_API_BASE);
+
+        let response = self
+            .client
+            .put(&url)
+            .header("Authorization", format!("token {}", account.token))
+            .header("User-Agent", "feedtui")
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(&serde_json::json!({ "read": true }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "GitHub API error (mark notifications read): {}",
+                response.status()
+            ));
+        }
+        Ok(())
+    }
+
+    async fn fetch_notifications(
+        &self,
+        account: &GithubAccount,
+    ) -> Result<Vec<GithubNotification>> {
         let url = format!("{}/notifications", GITHUB_API_BASE);
 
         let response = self
             .client
             .get(&url)
-            .header("Authorization", format!("token {}", self.token))
+            .header("Authorization", format!("token {}", account.token))
             .header("User-Agent", "feedtui")
             .header("Accept", "application/vnd.github.v3+json")
             .send()
@@ -172,22 +211,23 @@ impl GithubFetcher {
                 unread: n.unread,
                 updated_at: n.updated_at,
                 reason: n.reason,
+                account: self.tag(account),
             })
             .collect();
 
         Ok(notifications)
     }
 
-    async fn fetch_pull_requests(&self) -> Result<Vec<GithubPullRequest>> {
+    async fn fetch_pull_requests(&self, account: &GithubAccount) -> Result<Vec<GithubPullRequest>> {
         let url = format!(
             "{}/search/issues?q=involves:{}+type:pr+state:open&sort=updated&per_page={}",
-            GITHUB_API_BASE, self.username, self.max_pull_requests
+            GITHUB_API_BASE, account.username, self.max_pull_requests
         );
 
         let response = self
             .client
             .get(&url)
-            .header("Authorization", format!("token {}", self.token))
+            .header("Authorization", format!("token {}", account.token))
             .header("User-Agent", "feedtui")
             .header("Accept", "application/vnd.github.v3+json")
             .send()
@@ -252,19 +292,20 @@ impl GithubFetcher {
                 review_comments: 0,
                 additions: 0,
                 deletions: 0,
+                account: self.tag(account),
             });
         }
 
         Ok(pull_requests)
     }
 
-    async fn fetch_commits(&self) -> Result<Vec<GithubCommit>> {
-        let url = format!("{}/users/{}/events", GITHUB_API_BASE, self.username);
+    async fn fetch_commits(&self, account: &GithubAccount) -> Result<Vec<GithubCommit>> {
+        let url = format!("{}/users/{}/events", GITHUB_API_BASE, account.username);
 
         let response = self
             .client
             .get(&url)
-            .header("Authorization", format!("token {}", self.token))
+            .header("Authorization", format!("token {}", account.token))
             .header("User-Agent", "feedtui")
             .header("Accept", "application/vnd.github.v3+json")
             .send()
@@ -302,6 +343,7 @@ impl GithubFetcher {
                                 "https://github.com/{}/commit/{}",
                                 event.repo.full_name, commit.sha
                             ),
+                            account: self.tag(account),
                         });
 
                         if commits.len() >= self.max_commits {
@@ -319,30 +361,68 @@ impl GithubFetcher {
 #[async_trait]
 impl FeedFetcher for GithubFetcher {
     async fn fetch(&self) -> Result<FeedData> {
+        self.fetch_with_progress(&Progress::none()).await
+    }
+
+    async fn fetch_with_progress(&self, progress: &Progress) -> Result<FeedData> {
         let mut dashboard = GithubDashboard::default();
+        let total = [
+            self.show_notifications,
+            self.show_pull_requests,
+            self.show_commits,
+        ]
+        .into_iter()
+        .filter(|&shown| shown)
+        .count();
+        let mut done = 0;
+        progress.report(done, total);
 
         // Fetch notifications if enabled
         if self.show_notifications {
-            dashboard.notifications = self.fetch_notifications().await.unwrap_or_else(|e| {
-                eprintln!("Failed to fetch notifications: {}", e);
-                Vec::new()
-            });
+            for account in &self.accounts {
+                match self.fetch_notifications(account).await {
+                    Ok(items) => dashboard.notifications.extend(items),
+                    Err(e) => tracing::warn!("Failed to fetch notifications: {}", e),
+                }
+            }
+            dashboard
+                .notifications
+                .sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+            dashboard.notifications.truncate(self.max_notifications);
+            done += 1;
+            progress.report(done, total);
         }
 
         // Fetch pull requests if enabled
         if self.show_pull_requests {
-            dashboard.pull_requests = self.fetch_pull_requests().await.unwrap_or_else(|e| {
-                eprintln!("Failed to fetch pull requests: {}", e);
-                Vec::new()
-            });
+            for account in &self.accounts {
+                match self.fetch_pull_requests(account).await {
+                    Ok(items) => dashboard.pull_requests.extend(items),
+                    Err(e) => tracing::warn!("Failed to fetch pull requests: {}", e),
+                }
+            }
+            dashboard
+                .pull_requests
+                .sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+            dashboard.pull_requests.truncate(self.max_pull_requests);
+            done += 1;
+            progress.report(done, total);
         }
 
         // Fetch commits if enabled
         if self.show_commits {
-            dashboard.commits = self.fetch_commits().await.unwrap_or_else(|e| {
-                eprintln!("Failed to fetch commits: {}", e);
-                Vec::new()
-            });
+            for account in &self.accounts {
+                match self.fetch_commits(account).await {
+                    Ok(items) => dashboard.commits.extend(items),
+                    Err(e) => tracing::warn!("Failed to fetch commits: {}", e),
+                }
+            }
+            dashboard
+                .commits
+                .sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+            dashboard.commits.truncate(self.max_commits);
+            done += 1;
+            progress.report(done, total);
         }
 
         Ok(FeedData::Github(dashboard))
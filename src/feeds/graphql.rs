@@ -0,0 +1,123 @@
+use super::{http, FeedData, FeedFetcher, GenericItem};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::time::Duration;
+
+/// Which response fields become list items
+#[derive(Debug, Clone)]
+pub struct ItemMapping {
+    pub items_path: String,
+    pub title_field: String,
+    pub url_field: Option<String>,
+    pub description_field: Option<String>,
+    pub meta_field: Option<String>,
+}
+
+pub struct GraphqlFetcher {
+    endpoint: String,
+    query: String,
+    variables: toml::Table,
+    auth_header: Option<String>,
+    mapping: ItemMapping,
+    max_items: usize,
+    client: http::HttpClient,
+}
+
+impl GraphqlFetcher {
+    pub fn new(
+        endpoint: String,
+        query: String,
+        variables: toml::Table,
+        auth_header: Option<String>,
+        mapping: ItemMapping,
+        max_items: usize,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            endpoint,
+            query,
+            variables,
+            auth_header,
+            mapping,
+            max_items,
+            client: http::client(timeout),
+        }
+    }
+
+    fn map_items(&self, response: &Value) -> Result<Vec<GenericItem>> {
+        let nodes = lookup(response, &self.mapping.items_path)
+            .and_then(|v| v.as_array())
+            .with_context(|| format!("no array at {:?} in response", self.mapping.items_path))?;
+
+        let field = |node: &Value, path: &Option<String>| {
+            path.as_deref()
+                .and_then(|p| lookup(node, p))
+                .and_then(value_to_string)
+        };
+
+        Ok(nodes
+            .iter()
+            .filter_map(|node| {
+                Some(GenericItem {
+                    title: lookup(node, &self.mapping.title_field).and_then(value_to_string)?,
+                    url: field(node, &self.mapping.url_field),
+                    description: field(node, &self.mapping.description_field),
+                    meta: field(node, &self.mapping.meta_field),
+                })
+            })
+            .take(self.max_items)
+            .collect())
+    }
+}
+
+/// Follow a dotted path such as `data.viewer.repositories.nodes` or `edges.0.node`
+pub fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |current, segment| match segment.parse::<usize>() {
+            Ok(index) => current.get(index),
+            Err(_) => current.get(segment),
+        })
+}
+
+pub fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for GraphqlFetcher {
+    async fn fetch(&self) -> Result<FeedData> {
+        let body = serde_json::json!({
+            "query": self.query,
+            "variables": self.variables,
+        });
+
+        let mut request = self
+            .client
+            .post(&self.endpoint)
+            .header("User-Agent", "feedtui/1.0")
+            .json(&body);
+        if let Some(ref auth) = self.auth_header {
+            request = request.header("Authorization", auth);
+        }
+
+        let response: Value = request.send().await?.error_for_status()?.json().await?;
+
+        if let Some(errors) = response.get("errors").and_then(|e| e.as_array()) {
+            let messages: Vec<&str> = errors
+                .iter()
+                .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+                .collect();
+            if !messages.is_empty() {
+                anyhow::bail!("GraphQL error: {}", messages.join("; "));
+            }
+        }
+
+        Ok(FeedData::Items(self.map_items(&response)?))
+    }
+}
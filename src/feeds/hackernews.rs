@@ -1,14 +1,15 @@
-use super::{FeedData, FeedFetcher, HnStory};
+use super::{http, FeedData, FeedFetcher, HnStory};
 use anyhow::Result;
 use async_trait::async_trait;
 use serde::Deserialize;
+use std::time::Duration;
 
 const HN_API_BASE: &str = "https://hacker-news.firebaseio.com/v0";
 
 pub struct HnFetcher {
     story_type: String,
     story_count: usize,
-    client: reqwest::Client,
+    client: http::HttpClient,
 }
 
 #[derive(Debug, Deserialize)]
@@ -22,11 +23,11 @@ struct HnItem {
 }
 
 impl HnFetcher {
-    pub fn new(story_type: String, story_count: usize) -> Self {
+    pub fn new(story_type: String, story_count: usize, timeout: Duration) -> Self {
         Self {
             story_type,
             story_count,
-            client: reqwest::Client::new(),
+            client: http::client(timeout),
         }
     }
 
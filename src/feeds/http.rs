@@ -0,0 +1,159 @@
+use super::rate_limit::RateLimiter;
+use crate::config::Config;
+use reqwest::{IntoUrl, RequestBuilder, Response};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// `general.timeout_secs`, set once at startup
+static DEFAULT_TIMEOUT_SECS: AtomicU64 = AtomicU64::new(15);
+
+static LIMITER: OnceLock<RateLimiter> = OnceLock::new();
+
+/// Apply the config's network settings; call before creating any fetchers
+pub fn configure(config: &Config) {
+    DEFAULT_TIMEOUT_SECS.store(config.general.timeout_secs.max(1), Ordering::Relaxed);
+    let _ = LIMITER.set(RateLimiter::new(&config.rate_limits));
+}
+
+fn limiter() -> &'static RateLimiter {
+    LIMITER.get_or_init(|| RateLimiter::new(&HashMap::new()))
+}
+
+/// A widget's `timeout_secs`, falling back to the global default
+pub fn timeout(widget_secs: Option<u64>) -> Duration {
+    let secs = widget_secs.unwrap_or_else(|| DEFAULT_TIMEOUT_SECS.load(Ordering::Relaxed));
+    Duration::from_secs(secs.max(1))
+}
+
+/// The process-wide client, so every fetcher shares one connection pool,
+/// DNS cache, and TLS session cache
+fn shared() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .connect_timeout(timeout(None))
+            .pool_idle_timeout(Duration::from_secs(90))
+            .build()
+            .unwrap_or_default()
+    })
+}
+
+/// A handle on the shared client that applies one widget's timeout to
+/// every request it builds
+#[derive(Clone)]
+pub struct HttpClient {
+    inner: reqwest::Client,
+    timeout: Duration,
+}
+
+impl HttpClient {
+    pub fn get(&self, url: impl IntoUrl) -> Request {
+        Request(self.inner.get(url).timeout(self.timeout))
+    }
+
+    pub fn post(&self, url: impl IntoUrl) -> Request {
+        Request(self.inner.post(url).timeout(self.timeout))
+    }
+
+    pub fn put(&self, url: impl IntoUrl) -> Request {
+        Request(self.inner.put(url).timeout(self.timeout))
+    }
+}
+
+/// A request that waits for the per-host rate limiter before it is sent
+pub struct Request(RequestBuilder);
+
+impl Request {
+    pub fn header(self, key: &str, value: impl AsRef<str>) -> Self {
+        Request(self.0.header(key, value.as_ref()))
+    }
+
+    pub fn json<T: Serialize + ?Sized>(self, json: &T) -> Self {
+        Request(self.0.json(json))
+    }
+
+    pub fn body(self, body: impl Into<reqwest::Body>) -> Self {
+        Request(self.0.body(body))
+    }
+
+    pub fn basic_auth(self, username: &str, password: Option<&str>) -> Self {
+        Request(self.0.basic_auth(username, password))
+    }
+
+    pub async fn send(self) -> reqwest::Result<Response> {
+        let (client, request) = self.0.build_split();
+        let request = request?;
+        if let Some(host) = request.url().host_str() {
+            limiter().acquire(host).await;
+        }
+        client.execute(request).await
+    }
+}
+
+/// Read a response body, failing as soon as it grows past `max_bytes`
+/// instead of buffering an arbitrarily large download
+pub async fn read_limited(mut response: Response, max_bytes: usize) -> anyhow::Result<Vec<u8>> {
+    let host = response.url().host_str().unwrap_or_default().to_string();
+    let too_large = || {
+        anyhow::anyhow!(
+            "Response from {} too large (over {} KB)",
+            host,
+            max_bytes.div_ceil(1024)
+        )
+    };
+
+    if response
+        .content_length()
+        .is_some_and(|len| len > max_bytes as u64)
+    {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > max_bytes {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Client for request/response fetchers; `timeout` bounds the whole request
+pub fn client(timeout: Duration) -> HttpClient {
+    HttpClient {
+        inner: shared().clone(),
+        timeout,
+    }
+}
+
+/// Client for long-lived streams, where `timeout` bounds connecting and
+/// each read rather than the whole response. Streams hold their connection
+/// open, so they get a client of their own instead of the shared pool.
+pub fn streaming_client(timeout: Duration) -> reqwest::Client {
+    reqwest::Client::builder()
+        .connect_timeout(timeout)
+        .read_timeout(timeout)
+        .build()
+        .unwrap_or_default()
+}
+
+/// Text shown in a widget for a failed fetch. Timeouts get their own
+/// message since reqwest reports them as a generic send error.
+pub fn error_message(error: &anyhow::Error) -> String {
+    let timed_out = error
+        .chain()
+        .filter_map(|cause| cause.downcast_ref::<reqwest::Error>())
+        .find(|e| e.is_timeout());
+
+    match timed_out {
+        Some(e) => match e.url().and_then(|url| url.host_str()) {
+            Some(host) => format!("Request to {} timed out", host),
+            None => "Request timed out".to_string(),
+        },
+        None => error.to_string(),
+    }
+}
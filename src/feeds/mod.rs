@@ -1,12 +1,31 @@
+pub mod cache;
+pub mod channel;
+pub mod filter;
 pub mod github;
+pub mod graphql;
 pub mod hackernews;
+pub mod http;
+pub mod odds;
+pub mod rate_limit;
+pub mod retry;
 pub mod rss;
+pub mod scrape;
+pub mod script;
 pub mod sports;
+pub mod sse;
 pub mod stocks;
+pub mod wallet;
+pub mod wasm;
+pub mod webhook;
 pub mod youtube;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
 
 #[derive(Debug, Clone)]
 pub struct FeedMessage {
@@ -14,7 +33,8 @@ pub struct FeedMessage {
     pub data: FeedData,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
 pub enum FeedData {
     HackerNews(Vec<HnStory>),
     Stocks(Vec<StockQuote>),
@@ -22,11 +42,21 @@ pub enum FeedData {
     Sports(Vec<SportsEvent>),
     Github(GithubDashboard),
     Youtube(Vec<YoutubeVideo>),
+    #[cfg_attr(not(feature = "plugins"), allow(dead_code))]
+    Script(Vec<ScriptLine>),
+    Items(Vec<GenericItem>),
+    Wallet(Vec<WalletHolding>),
+    Odds(Vec<GameOdds>),
     Loading,
+    /// How many of a fetch's requests have finished so far
+    Progress {
+        done: usize,
+        total: usize,
+    },
     Error(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HnStory {
     pub id: u64,
     pub title: String,
@@ -36,7 +66,7 @@ pub struct HnStory {
     pub descendants: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockQuote {
     pub symbol: String,
     pub price: f64,
@@ -45,16 +75,72 @@ pub struct StockQuote {
     pub name: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RssItem {
     pub title: String,
     pub link: Option<String>,
     pub published: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
     pub source: String,
     pub description: Option<String>,
+    /// Comments page on the aggregator the item was posted to
+    pub discussion: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+/// One tracked address, valued at the current spot price
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletHolding {
+    pub asset: String,
+    pub address: String,
+    pub label: Option<String>,
+    /// `None` when the explorer couldn't be reached
+    pub balance: Option<f64>,
+    pub price: f64,
+    pub change_percent_24h: f64,
+}
+
+impl WalletHolding {
+    pub fn value(&self) -> Option<f64> {
+        self.balance.map(|balance| balance * self.price)
+    }
+}
+
+/// One bookmaker's lines on an upcoming game, in American odds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameOdds {
+    pub id: String,
+    pub league: String,
+    pub home_team: String,
+    pub away_team: String,
+    pub commence_time: Option<DateTime<Utc>>,
+    pub bookmaker: String,
+    pub moneyline: Option<Moneyline>,
+    pub spread: Option<Spread>,
+    pub total: Option<Total>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Moneyline {
+    pub home: f64,
+    pub away: f64,
+}
+
+/// `point` is the home team's handicap
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Spread {
+    pub point: f64,
+    pub home_price: f64,
+    pub away_price: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Total {
+    pub point: f64,
+    pub over_price: f64,
+    pub under_price: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SportsEvent {
     pub league: String,
     pub home_team: String,
@@ -63,9 +149,11 @@ pub struct SportsEvent {
     pub away_score: Option<u32>,
     pub status: String,
     pub start_time: Option<String>,
+    /// Chance the home team wins, 0 to 1; ESPN only gives it for live games
+    pub home_win_probability: Option<f64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubNotification {
     pub id: String,
     pub title: String,
@@ -75,9 +163,11 @@ pub struct GithubNotification {
     pub unread: bool,
     pub updated_at: String,
     pub reason: String,
+    /// Account the item came from, in a widget merging several
+    pub account: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubPullRequest {
     pub id: u64,
     pub number: u32,
@@ -93,9 +183,11 @@ pub struct GithubPullRequest {
     pub review_comments: u32,
     pub additions: u32,
     pub deletions: u32,
+    /// Account the item came from, in a widget merging several
+    pub account: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubCommit {
     pub sha: String,
     pub message: String,
@@ -106,16 +198,18 @@ pub struct GithubCommit {
     pub additions: u32,
     pub deletions: u32,
     pub url: String,
+    /// Account the item came from, in a widget merging several
+    pub account: Option<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GithubDashboard {
     pub notifications: Vec<GithubNotification>,
     pub pull_requests: Vec<GithubPullRequest>,
     pub commits: Vec<GithubCommit>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YoutubeVideo {
     pub id: String,
     pub title: String,
@@ -127,7 +221,187 @@ pub struct YoutubeVideo {
     pub duration: Option<String>,
 }
 
+/// A line produced by a plugin's `render` function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptLine {
+    pub text: String,
+    pub url: Option<String>,
+}
+
+/// A plain list entry for sources without a dedicated data type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenericItem {
+    pub title: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Short secondary line (source, timestamp, score, ...)
+    #[serde(default)]
+    pub meta: Option<String>,
+}
+
+impl FeedData {
+    /// Headline-style summary of each item, used for plain-text output
+    pub fn item_titles(&self) -> Vec<String> {
+        match self {
+            FeedData::HackerNews(stories) => stories.iter().map(|s| s.title.clone()).collect(),
+            FeedData::Stocks(quotes) => quotes
+                .iter()
+                .map(|q| format!("{} ${:.2} ({:+.2}%)", q.symbol, q.price, q.change_percent))
+                .collect(),
+            FeedData::Rss(items) => items.iter().map(|i| i.title.clone()).collect(),
+            FeedData::Sports(events) => events
+                .iter()
+                .map(|e| match (e.away_score, e.home_score) {
+                    (Some(a), Some(h)) => {
+                        format!(
+                            "{} {} - {} {} ({})",
+                            e.away_team, a, h, e.home_team, e.status
+                        )
+                    }
+                    _ => format!("{} vs {} ({})", e.away_team, e.home_team, e.status),
+                })
+                .collect(),
+            FeedData::Github(dashboard) => dashboard
+                .notifications
+                .iter()
+                .map(|n| n.title.clone())
+                .chain(dashboard.pull_requests.iter().map(|pr| pr.title.clone()))
+                .chain(dashboard.commits.iter().map(|c| c.message.clone()))
+                .collect(),
+            FeedData::Youtube(videos) => videos.iter().map(|v| v.title.clone()).collect(),
+            FeedData::Script(lines) => lines.iter().map(|l| l.text.clone()).collect(),
+            FeedData::Items(items) => items.iter().map(|i| i.title.clone()).collect(),
+            FeedData::Wallet(holdings) => holdings
+                .iter()
+                .map(|h| match h.balance {
+                    Some(balance) => format!(
+                        "{} {} {:.8} ({:.2})",
+                        h.label.as_deref().unwrap_or(&h.address),
+                        h.asset,
+                        balance,
+                        balance * h.price
+                    ),
+                    None => format!(
+                        "{} {} unavailable",
+                        h.label.as_deref().unwrap_or(&h.address),
+                        h.asset
+                    ),
+                })
+                .collect(),
+            FeedData::Odds(games) => games
+                .iter()
+                .map(|g| match g.moneyline {
+                    Some(ml) => format!(
+                        "{} {:+} @ {} {:+}",
+                        g.away_team, ml.away, g.home_team, ml.home
+                    ),
+                    None => format!("{} @ {}", g.away_team, g.home_team),
+                })
+                .collect(),
+            FeedData::Loading | FeedData::Progress { .. } => Vec::new(),
+            FeedData::Error(e) => vec![format!("Error: {}", e)],
+        }
+    }
+
+    pub fn item_count(&self) -> usize {
+        match self {
+            FeedData::HackerNews(stories) => stories.len(),
+            FeedData::Stocks(quotes) => quotes.len(),
+            FeedData::Rss(items) => items.len(),
+            FeedData::Sports(events) => events.len(),
+            FeedData::Github(dashboard) => {
+                dashboard.notifications.len()
+                    + dashboard.pull_requests.len()
+                    + dashboard.commits.len()
+            }
+            FeedData::Youtube(videos) => videos.len(),
+            FeedData::Script(lines) => lines.len(),
+            FeedData::Items(items) => items.len(),
+            FeedData::Wallet(holdings) => holdings.len(),
+            FeedData::Odds(games) => games.len(),
+            FeedData::Loading | FeedData::Progress { .. } | FeedData::Error(_) => 0,
+        }
+    }
+
+    /// Keep the first `n` items, which sources order newest first. GitHub
+    /// keeps the first `n` of each of its lists.
+    pub fn truncate(&mut self, n: usize) {
+        match self {
+            FeedData::HackerNews(stories) => stories.truncate(n),
+            FeedData::Stocks(quotes) => quotes.truncate(n),
+            FeedData::Rss(items) => items.truncate(n),
+            FeedData::Sports(events) => events.truncate(n),
+            FeedData::Github(dashboard) => {
+                dashboard.notifications.truncate(n);
+                dashboard.pull_requests.truncate(n);
+                dashboard.commits.truncate(n);
+            }
+            FeedData::Youtube(videos) => videos.truncate(n),
+            FeedData::Script(lines) => lines.truncate(n),
+            FeedData::Items(items) => items.truncate(n),
+            FeedData::Wallet(holdings) => holdings.truncate(n),
+            FeedData::Odds(games) => games.truncate(n),
+            FeedData::Loading | FeedData::Progress { .. } | FeedData::Error(_) => {}
+        }
+    }
+
+    /// Rough size of the payload in bytes, measured as its JSON encoding
+    pub fn approx_bytes(&self) -> usize {
+        serde_json::to_vec(self).map_or(0, |json| json.len())
+    }
+}
+
+/// Reports how far a fetch made of several requests has got
+#[derive(Clone)]
+pub struct Progress {
+    report: Option<Arc<dyn Fn(usize, usize) + Send + Sync>>,
+}
+
+impl Progress {
+    pub fn new(report: impl Fn(usize, usize) + Send + Sync + 'static) -> Self {
+        Self {
+            report: Some(Arc::new(report)),
+        }
+    }
+
+    /// Progress nobody is watching
+    pub fn none() -> Self {
+        Self { report: None }
+    }
+
+    pub fn report(&self, done: usize, total: usize) {
+        if let Some(report) = &self.report {
+            report(done, total);
+        }
+    }
+}
+
 #[async_trait]
 pub trait FeedFetcher: Send + Sync {
     async fn fetch(&self) -> Result<FeedData>;
+
+    /// `fetch`, reporting progress for sources that make several requests
+    async fn fetch_with_progress(&self, _progress: &Progress) -> Result<FeedData> {
+        self.fetch().await
+    }
+
+    /// Override the global refresh interval for this source
+    fn refresh_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether the app should call `subscribe` instead of polling `fetch`
+    fn supports_streaming(&self) -> bool {
+        false
+    }
+
+    /// Push updates into `tx` as they arrive (WebSocket, SSE, MQTT, ...).
+    /// Returning ends the stream; the app resubscribes after the refresh
+    /// interval and shows the error, if any.
+    async fn subscribe(&self, tx: mpsc::UnboundedSender<FeedData>) -> Result<()> {
+        let _ = tx.send(self.fetch().await?);
+        Ok(())
+    }
 }
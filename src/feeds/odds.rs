@@ -0,0 +1,251 @@
+use super::{http, FeedData, FeedFetcher, GameOdds, Moneyline, Spread, Total};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use serde::Deserialize;
+use std::time::Duration;
+
+const ODDS_API_BASE: &str = "https://api.the-odds-api.com/v4/sports";
+
+pub struct OddsFetcher {
+    api_key: String,
+    leagues: Vec<String>,
+    favorite_teams: Vec<String>,
+    regions: String,
+    bookmaker: Option<String>,
+    client: http::HttpClient,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiEvent {
+    id: String,
+    commence_time: Option<DateTime<Utc>>,
+    home_team: String,
+    away_team: String,
+    #[serde(default)]
+    bookmakers: Vec<ApiBookmaker>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiBookmaker {
+    key: String,
+    title: String,
+    #[serde(default)]
+    markets: Vec<ApiMarket>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiMarket {
+    key: String,
+    #[serde(default)]
+    outcomes: Vec<ApiOutcome>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiOutcome {
+    name: String,
+    price: f64,
+    point: Option<f64>,
+}
+
+impl OddsFetcher {
+    pub fn new(
+        api_key: String,
+        leagues: Vec<String>,
+        favorite_teams: Vec<String>,
+        regions: String,
+        bookmaker: Option<String>,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            api_key,
+            leagues,
+            favorite_teams: favorite_teams.iter().map(|t| t.to_lowercase()).collect(),
+            regions,
+            bookmaker,
+            client: http::client(timeout),
+        }
+    }
+
+    /// The Odds API's key for a league, from the short names the sports
+    /// widget uses; anything else is passed through as a sport key
+    pub fn sport_key(league: &str) -> String {
+        match league.to_lowercase().as_str() {
+            "nba" => "basketball_nba",
+            "nfl" => "americanfootball_nfl",
+            "mlb" => "baseball_mlb",
+            "nhl" => "icehockey_nhl",
+            "mls" => "soccer_usa_mls",
+            "epl" | "premier-league" => "soccer_epl",
+            "ncaaf" | "college-football" => "americanfootball_ncaaf",
+            "ncaab" | "college-basketball" => "basketball_ncaab",
+            other => return other.to_string(),
+        }
+        .to_string()
+    }
+
+    fn is_followed(&self, team: &str) -> bool {
+        let team = team.to_lowercase();
+        self.favorite_teams.is_empty() || self.favorite_teams.iter().any(|f| team.contains(f))
+    }
+
+    async fn fetch_league(&self, league: &str) -> Result<Vec<GameOdds>> {
+        let url = format!(
+            "{}/{}/odds?apiKey={}&regions={}&markets=h2h,spreads,totals&oddsFormat=american",
+            ODDS_API_BASE,
+            Self::sport_key(league),
+            self.api_key,
+            self.regions
+        );
+        // The key is part of the URL, so keep it out of error messages
+        let events: Vec<ApiEvent> = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| e.without_url())?
+            .json()
+            .await
+            .map_err(|e| e.without_url())?;
+
+        Ok(events
+            .into_iter()
+            .filter(|e| self.is_followed(&e.home_team) || self.is_followed(&e.away_team))
+            .filter_map(|event| self.game_odds(league, event))
+            .collect())
+    }
+
+    fn game_odds(&self, league: &str, event: ApiEvent) -> Option<GameOdds> {
+        let book = match self.bookmaker.as_deref() {
+            Some(key) => event
+                .bookmakers
+                .iter()
+                .find(|b| b.key.eq_ignore_ascii_case(key))?,
+            None => event.bookmakers.first()?,
+        };
+        let market = |key: &str| book.markets.iter().find(|m| m.key == key);
+        let outcome = |market: &ApiMarket, name: &str| {
+            market
+                .outcomes
+                .iter()
+                .find(|o| o.name.eq_ignore_ascii_case(name))
+                .map(|o| (o.price, o.point))
+        };
+
+        let moneyline = market("h2h").and_then(|m| {
+            Some(Moneyline {
+                home: outcome(m, &event.home_team)?.0,
+                away: outcome(m, &event.away_team)?.0,
+            })
+        });
+        let spread = market("spreads").and_then(|m| {
+            let (home_price, point) = outcome(m, &event.home_team)?;
+            Some(Spread {
+                point: point?,
+                home_price,
+                away_price: outcome(m, &event.away_team)?.0,
+            })
+        });
+        let total = market("totals").and_then(|m| {
+            let (over_price, point) = outcome(m, "Over")?;
+            Some(Total {
+                point: point?,
+                over_price,
+                under_price: outcome(m, "Under")?.0,
+            })
+        });
+
+        Some(GameOdds {
+            id: event.id,
+            league: league.to_uppercase(),
+            home_team: event.home_team,
+            away_team: event.away_team,
+            commence_time: event.commence_time,
+            bookmaker: book.title.clone(),
+            moneyline,
+            spread,
+            total,
+        })
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for OddsFetcher {
+    async fn fetch(&self) -> Result<FeedData> {
+        let results = join_all(self.leagues.iter().map(|l| self.fetch_league(l))).await;
+
+        let mut games = Vec::new();
+        let mut first_error = None;
+        for (league, result) in self.leagues.iter().zip(results) {
+            match result {
+                Ok(league_games) => games.extend(league_games),
+                Err(e) => {
+                    tracing::warn!("could not fetch odds for {}: {:#}", league, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        if games.is_empty() {
+            if let Some(e) = first_error {
+                return Err(e);
+            }
+        }
+
+        games.sort_by_key(|g| g.commence_time);
+        Ok(FeedData::Odds(games))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_lines_from_the_chosen_bookmaker() {
+        let fetcher = OddsFetcher::new(
+            String::new(),
+            vec!["nba".to_string()],
+            vec!["celtics".to_string()],
+            "us".to_string(),
+            Some("fanduel".to_string()),
+            Duration::from_secs(1),
+        );
+        let event: ApiEvent = serde_json::from_str(
+            r#"{
+                "id": "abc",
+                "commence_time": "2024-05-01T23:30:00Z",
+                "home_team": "Boston Celtics",
+                "away_team": "Miami Heat",
+                "bookmakers": [
+                    {"key": "draftkings", "title": "DraftKings", "markets": []},
+                    {"key": "fanduel", "title": "FanDuel", "markets": [
+                        {"key": "h2h", "outcomes": [
+                            {"name": "Boston Celtics", "price": -450},
+                            {"name": "Miami Heat", "price": 350}
+                        ]},
+                        {"key": "spreads", "outcomes": [
+                            {"name": "Boston Celtics", "price": -110, "point": -9.5},
+                            {"name": "Miami Heat", "price": -110, "point": 9.5}
+                        ]},
+                        {"key": "totals", "outcomes": [
+                            {"name": "Over", "price": -105, "point": 207.5},
+                            {"name": "Under", "price": -115, "point": 207.5}
+                        ]}
+                    ]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(fetcher.is_followed(&event.home_team));
+
+        let odds = fetcher.game_odds("nba", event).unwrap();
+        assert_eq!(odds.bookmaker, "FanDuel");
+        assert_eq!(odds.league, "NBA");
+        assert_eq!(odds.moneyline.unwrap().away, 350.0);
+        assert_eq!(odds.spread.unwrap().point, -9.5);
+        assert_eq!(odds.total.unwrap().under_price, -115.0);
+        assert_eq!(OddsFetcher::sport_key("EPL"), "soccer_epl");
+    }
+}
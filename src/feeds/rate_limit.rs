@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Requests per minute for APIs with quotas, used unless `[rate_limits]`
+/// in the config says otherwise
+const DEFAULT_LIMITS: &[(&str, u32)] = &[
+    ("api.github.com", 60),
+    ("site.api.espn.com", 30),
+    ("www.googleapis.com", 30),
+];
+
+/// Token bucket allowing `per_minute` requests a minute, in bursts of up
+/// to `per_minute`
+#[derive(Debug)]
+struct Bucket {
+    per_minute: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(per_minute: u32, now: Instant) -> Self {
+        let per_minute = per_minute.max(1) as f64;
+        Self {
+            per_minute,
+            tokens: per_minute,
+            updated: now,
+        }
+    }
+
+    /// Take a token, or return how long until one is available
+    fn take(&mut self, now: Instant) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_minute / 60.0).min(self.per_minute);
+        self.updated = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                (1.0 - self.tokens) * 60.0 / self.per_minute,
+            ))
+        }
+    }
+}
+
+/// Per-host request limits shared by every fetcher
+#[derive(Debug)]
+pub struct RateLimiter {
+    limits: HashMap<String, u32>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    /// `overrides` maps host names to requests per minute; 0 removes a limit
+    pub fn new(overrides: &HashMap<String, u32>) -> Self {
+        let mut limits: HashMap<String, u32> = DEFAULT_LIMITS
+            .iter()
+            .map(|(host, limit)| (host.to_string(), *limit))
+            .collect();
+        for (host, limit) in overrides {
+            limits.insert(host.to_lowercase(), *limit);
+        }
+        limits.retain(|_, limit| *limit > 0);
+
+        Self {
+            limits,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait until a request to `host` is allowed
+    pub async fn acquire(&self, host: &str) {
+        let Some(&limit) = self.limits.get(host) else {
+            return;
+        };
+
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+                let now = Instant::now();
+                buckets
+                    .entry(host.to_string())
+                    .or_insert_with(|| Bucket::new(limit, now))
+                    .take(now)
+            };
+            match wait {
+                None => return,
+                Some(wait) => {
+                    tracing::debug!("rate limited on {}, waiting {:?}", host, wait);
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_allows_a_burst_then_spaces_requests() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(2, start);
+
+        assert_eq!(bucket.take(start), None);
+        assert_eq!(bucket.take(start), None);
+        let wait = bucket.take(start).unwrap();
+        assert!((wait.as_secs_f64() - 30.0).abs() < 0.01);
+
+        assert_eq!(bucket.take(start + Duration::from_secs(30)), None);
+    }
+
+    #[test]
+    fn overrides_replace_and_remove_defaults() {
+        let overrides = HashMap::from([
+            ("api.github.com".to_string(), 0),
+            ("Example.com".to_string(), 10),
+        ]);
+        let limiter = RateLimiter::new(&overrides);
+
+        assert!(!limiter.limits.contains_key("api.github.com"));
+        assert_eq!(limiter.limits.get("example.com"), Some(&10));
+        assert_eq!(limiter.limits.get("site.api.espn.com"), Some(&30));
+    }
+}
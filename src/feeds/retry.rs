@@ -0,0 +1,100 @@
+use super::{FeedData, FeedFetcher, Progress};
+use crate::config::GeneralConfig;
+use crate::crash;
+use crate::random;
+use anyhow::Result;
+use std::time::Duration;
+
+/// Longest wait between retries, however many attempts are configured
+const MAX_DELAY: Duration = Duration::from_secs(300);
+
+/// How failed fetches are retried before an error reaches the widget
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Total tries, including the first
+    pub attempts: u32,
+    /// Delay before the first retry; doubles on each further retry, up to
+    /// five minutes
+    pub base_delay: Duration,
+    /// Random spread applied to delays and refresh intervals, as a fraction (0.1 = ±10%)
+    pub jitter: f64,
+}
+
+impl RetryPolicy {
+    pub fn from_config(general: &GeneralConfig) -> Self {
+        Self {
+            attempts: general.retry_attempts.max(1),
+            base_delay: Duration::from_millis(general.retry_base_delay_ms),
+            jitter: general.jitter.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Fetch, retrying transient network failures with exponential backoff
+    pub async fn fetch(&self, fetcher: &dyn FeedFetcher, progress: &Progress) -> Result<FeedData> {
+        let mut attempt = 1;
+        loop {
+            match crash::fetch_guarded(fetcher, progress).await {
+                Ok(data) => return Ok(data),
+                Err(e) if attempt < self.attempts && is_transient(&e) => {
+                    let delay = self.jittered(self.backoff(attempt));
+                    tracing::debug!(
+                        "attempt {}/{} failed, retrying in {:?}: {:#}",
+                        attempt,
+                        self.attempts,
+                        delay,
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Delay after the `attempt`th failure, before jitter
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.checked_pow(attempt - 1).unwrap_or(u32::MAX);
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(MAX_DELAY, |delay| delay.min(MAX_DELAY))
+    }
+
+    /// Spread a duration by up to ±jitter so widgets don't fire in lockstep
+    pub fn jittered(&self, duration: Duration) -> Duration {
+        duration.mul_f64((1.0 + self.jitter * random::signed_unit()).max(0.0))
+    }
+}
+
+/// Timeouts, connection failures, rate limiting, and server errors are worth
+/// retrying; bad credentials or malformed responses are not
+fn is_transient(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .filter_map(|cause| cause.downcast_ref::<reqwest::Error>())
+        .any(|e| {
+            e.is_timeout()
+                || e.is_connect()
+                || e.status().is_some_and(|s| {
+                    s.is_server_error() || s == reqwest::StatusCode::TOO_MANY_REQUESTS
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_a_cap() {
+        let policy = RetryPolicy {
+            attempts: u32::MAX,
+            base_delay: Duration::from_millis(500),
+            jitter: 0.0,
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(40), MAX_DELAY);
+        assert_eq!(policy.backoff(u32::MAX), MAX_DELAY);
+    }
+}
@@ -1,19 +1,52 @@
-use super::{FeedData, FeedFetcher, RssItem};
+use super::{http, FeedData, FeedFetcher, Progress, RssItem};
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use std::cmp::Reverse;
+use std::time::Duration;
+
+/// Feeds fetched at once by a single widget
+const MAX_CONCURRENT_FEEDS: usize = 8;
+/// Largest feed document accepted
+const MAX_FEED_BYTES: usize = 5 * 1024 * 1024;
+/// Sites whose feeds point at their own comment pages
+const DISCUSSION_HOSTS: [&str; 3] = ["lobste.rs", "news.ycombinator.com", "reddit.com"];
+/// `type`s of `<link rel="alternate">` that point at a feed
+const FEED_TYPES: [&str; 3] = [
+    "application/rss+xml",
+    "application/atom+xml",
+    "application/feed+json",
+];
+
+/// A feed a website advertises
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscoveredFeed {
+    pub url: String,
+    pub title: Option<String>,
+}
+
+impl DiscoveredFeed {
+    /// The feed's title with its URL, for picking one from a list
+    pub fn label(&self) -> String {
+        match &self.title {
+            Some(title) => format!("{} ({})", title, self.url),
+            None => self.url.clone(),
+        }
+    }
+}
 
 pub struct RssFetcher {
     feeds: Vec<String>,
     max_items: usize,
-    client: reqwest::Client,
+    client: http::HttpClient,
 }
 
 impl RssFetcher {
-    pub fn new(feeds: Vec<String>, max_items: usize) -> Self {
+    pub fn new(feeds: Vec<String>, max_items: usize, timeout: Duration) -> Self {
         Self {
             feeds,
             max_items,
-            client: reqwest::Client::new(),
+            client: http::client(timeout),
         }
     }
 
@@ -23,9 +56,10 @@ impl RssFetcher {
             .get(url)
             .header("User-Agent", "feedtui/1.0")
             .send()
-            .await?;
+            .await?
+            .error_for_status()?;
 
-        let body = response.bytes().await?;
+        let body = http::read_limited(response, MAX_FEED_BYTES).await?;
         let feed = feed_rs::parser::parse(&body[..])?;
 
         let source_name = feed
@@ -43,18 +77,21 @@ impl RssFetcher {
                     .summary
                     .map(|s| s.content)
                     .or_else(|| entry.content.and_then(|c| c.body));
+                let published = entry.published.or(entry.updated);
+                let links: Vec<String> = entry.links.into_iter().map(|l| l.href).collect();
+                let discussion = discussion_url(&entry.id, &links);
 
                 RssItem {
                     title: entry
                         .title
                         .map(|t| t.content)
                         .unwrap_or_else(|| "No title".to_string()),
-                    link: entry.links.first().map(|l| l.href.clone()),
-                    published: entry
-                        .published
-                        .map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
+                    link: links.into_iter().next(),
+                    published: published.map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
+                    published_at: published,
                     source: source_name.clone(),
                     description,
+                    discussion,
                 }
             })
             .collect();
@@ -66,18 +103,192 @@ impl RssFetcher {
 #[async_trait]
 impl FeedFetcher for RssFetcher {
     async fn fetch(&self) -> Result<FeedData> {
-        let mut all_items = Vec::new();
+        self.fetch_with_progress(&Progress::none()).await
+    }
 
-        for feed_url in &self.feeds {
-            match self.fetch_feed(feed_url).await {
+    async fn fetch_with_progress(&self, progress: &Progress) -> Result<FeedData> {
+        let total = self.feeds.len();
+        let mut done = 0;
+        progress.report(done, total);
+        let results: Vec<_> = stream::iter(self.feeds.iter().cloned())
+            .map(|feed_url| async move {
+                let result = self.fetch_feed(&feed_url).await;
+                (feed_url, result)
+            })
+            .buffer_unordered(MAX_CONCURRENT_FEEDS)
+            .inspect(|_| {
+                done += 1;
+                progress.report(done, total);
+            })
+            .collect()
+            .await;
+
+        let mut all_items = Vec::new();
+        let mut first_error = None;
+        for (feed_url, result) in results {
+            match result {
                 Ok(items) => all_items.extend(items),
-                Err(_) => continue,
+                Err(e) => {
+                    tracing::warn!("could not fetch feed {}: {:#}", feed_url, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        // Only an error when no feed at all could be read
+        if all_items.is_empty() {
+            if let Some(e) = first_error {
+                return Err(e);
             }
         }
 
-        // Sort by date if available, limit to max_items
+        // Newest first; undated items go last
+        all_items.sort_by_key(|item| Reverse(item.published_at));
         all_items.truncate(self.max_items);
 
         Ok(FeedData::Rss(all_items))
     }
 }
+
+/// Feeds at `url`: the URL itself if it already is one, otherwise those the
+/// page links to with `<link rel="alternate">`. A missing scheme means https
+pub async fn discover(url: &str, timeout: Duration) -> Result<Vec<DiscoveredFeed>> {
+    let url = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{}", url)
+    };
+    let base = reqwest::Url::parse(&url)?;
+    if !matches!(base.scheme(), "http" | "https") {
+        anyhow::bail!("not a web address: {}", url);
+    }
+
+    let response = http::client(timeout)
+        .get(base.as_str())
+        .header("User-Agent", "feedtui/1.0")
+        .send()
+        .await?
+        .error_for_status()?;
+    // Redirects decide what relative links are relative to
+    let base = response.url().clone();
+    let body = http::read_limited(response, MAX_FEED_BYTES).await?;
+
+    if let Ok(feed) = feed_rs::parser::parse(&body[..]) {
+        return Ok(vec![DiscoveredFeed {
+            url: base.to_string(),
+            title: feed.title.map(|t| t.content),
+        }]);
+    }
+
+    let feeds = feed_links(&String::from_utf8_lossy(&body), &base);
+    if feeds.is_empty() {
+        anyhow::bail!("no feeds found at {}", base);
+    }
+    Ok(feeds)
+}
+
+/// Feeds advertised in a page's `<link rel="alternate">` tags, in page order
+fn feed_links(html: &str, base: &reqwest::Url) -> Vec<DiscoveredFeed> {
+    let document = scraper::Html::parse_document(html);
+    let selector = scraper::Selector::parse("link[rel~=alternate][href]").expect("valid selector");
+
+    let mut feeds: Vec<DiscoveredFeed> = Vec::new();
+    for link in document.select(&selector) {
+        let link = link.value();
+        let is_feed = link.attr("type").is_some_and(|kind| {
+            FEED_TYPES
+                .iter()
+                .any(|t| kind.trim().eq_ignore_ascii_case(t))
+        });
+        let Some(url) = link
+            .attr("href")
+            .and_then(|href| base.join(href.trim()).ok())
+        else {
+            continue;
+        };
+        if !is_feed || feeds.iter().any(|f| f.url == url.as_str()) {
+            continue;
+        }
+        feeds.push(DiscoveredFeed {
+            url: url.to_string(),
+            title: link
+                .attr("title")
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string),
+        });
+    }
+    feeds
+}
+
+/// The entry's comments page, when the feed comes from an aggregator: Lobsters
+/// and hnrss use it as the guid, Reddit links to it directly
+fn discussion_url(id: &str, links: &[String]) -> Option<String> {
+    std::iter::once(id)
+        .chain(links.iter().map(String::as_str))
+        .find(|url| {
+            reqwest::Url::parse(url).is_ok_and(|url| {
+                url.host_str().is_some_and(|host| {
+                    DISCUSSION_HOSTS
+                        .iter()
+                        .any(|d| host == *d || host.ends_with(&format!(".{}", d)))
+                })
+            })
+        })
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discovers_feeds_but_not_wordpress_api_links() {
+        let head = r#"<html><head>
+            <link rel="alternate" type="application/rss+xml" title="Blog &raquo; Feed" href="/feed/">
+            <link rel="alternate" type="application/rss+xml" title="Comments" href="/comments/feed/">
+            <link rel="alternate" type="application/json" href="/wp-json/wp/v2/pages/2">
+            <link rel="alternate" type="application/json+oembed" href="/wp-json/oembed/1.0/embed">
+            <link rel="alternate" type="application/feed+json" href="feed.json">
+        </head></html>"#;
+        let base = reqwest::Url::parse("https://blog.example.com/about/").unwrap();
+        let urls: Vec<String> = feed_links(head, &base)
+            .into_iter()
+            .map(|feed| feed.url)
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                "https://blog.example.com/feed/",
+                "https://blog.example.com/comments/feed/",
+                "https://blog.example.com/about/feed.json",
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_aggregator_comment_pages() {
+        let links = |urls: &[&str]| urls.iter().map(|u| u.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            discussion_url(
+                "https://lobste.rs/s/abc123",
+                &links(&["https://example.com/post"])
+            ),
+            Some("https://lobste.rs/s/abc123".to_string())
+        );
+        assert_eq!(
+            discussion_url(
+                "t3_xyz",
+                &links(&["https://www.reddit.com/r/rust/comments/xyz/title/"])
+            ),
+            Some("https://www.reddit.com/r/rust/comments/xyz/title/".to_string())
+        );
+        assert_eq!(
+            discussion_url(
+                "https://blog.example.com/?p=1",
+                &links(&["https://blog.example.com/post"])
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use super::{http, FeedData, FeedFetcher, GenericItem};
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use scraper::{ElementRef, Html, Selector};
+use std::time::Duration;
+
+pub struct ScrapeFetcher {
+    url: String,
+    item_selector: String,
+    title_selector: Option<String>,
+    link_selector: Option<String>,
+    description_selector: Option<String>,
+    max_items: usize,
+    client: http::HttpClient,
+}
+
+impl ScrapeFetcher {
+    pub fn new(
+        url: String,
+        item_selector: String,
+        title_selector: Option<String>,
+        link_selector: Option<String>,
+        description_selector: Option<String>,
+        max_items: usize,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            url,
+            item_selector,
+            title_selector,
+            link_selector,
+            description_selector,
+            max_items,
+            client: http::client(timeout),
+        }
+    }
+
+    fn extract(&self, body: &str) -> Result<Vec<GenericItem>> {
+        let base = reqwest::Url::parse(&self.url).ok();
+        let document = Html::parse_document(body);
+
+        let item_sel = parse_selector(&self.item_selector)?;
+        let title_sel = self
+            .title_selector
+            .as_deref()
+            .map(parse_selector)
+            .transpose()?;
+        let link_sel = self
+            .link_selector
+            .as_deref()
+            .map(parse_selector)
+            .transpose()?;
+        let description_sel = self
+            .description_selector
+            .as_deref()
+            .map(parse_selector)
+            .transpose()?;
+        let anchor_sel = parse_selector("a[href]")?;
+
+        let mut items = Vec::new();
+        for element in document.select(&item_sel) {
+            if items.len() >= self.max_items {
+                break;
+            }
+
+            let title_el = match title_sel {
+                Some(ref sel) => element.select(sel).next(),
+                None => Some(element),
+            };
+            let Some(title_el) = title_el else {
+                continue;
+            };
+            let title = element_text(title_el);
+            if title.is_empty() {
+                continue;
+            }
+
+            // Without a link selector, use the title if it is a link, or the
+            // first link inside the item
+            let link_el = match link_sel {
+                Some(ref sel) => element.select(sel).next(),
+                None if title_el.value().name() == "a" => Some(title_el),
+                None => element.select(&anchor_sel).next(),
+            };
+            let url = link_el
+                .and_then(|el| el.value().attr("href"))
+                .map(|href| resolve_url(base.as_ref(), href));
+
+            let description = description_sel
+                .as_ref()
+                .and_then(|sel| element.select(sel).next())
+                .map(element_text)
+                .filter(|text| !text.is_empty());
+
+            items.push(GenericItem {
+                title,
+                url,
+                description,
+                meta: base
+                    .as_ref()
+                    .and_then(|b| b.host_str())
+                    .map(|host| host.to_string()),
+            });
+        }
+
+        Ok(items)
+    }
+}
+
+fn parse_selector(selector: &str) -> Result<Selector> {
+    Selector::parse(selector).map_err(|e| anyhow!("invalid CSS selector {:?}: {}", selector, e))
+}
+
+/// All text inside an element with whitespace collapsed
+fn element_text(element: ElementRef) -> String {
+    element
+        .text()
+        .flat_map(|t| t.split_whitespace())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn resolve_url(base: Option<&reqwest::Url>, href: &str) -> String {
+    base.and_then(|b| b.join(href).ok())
+        .map(|u| u.to_string())
+        .unwrap_or_else(|| href.to_string())
+}
+
+#[async_trait]
+impl FeedFetcher for ScrapeFetcher {
+    async fn fetch(&self) -> Result<FeedData> {
+        let body = self
+            .client
+            .get(&self.url)
+            .header("User-Agent", "feedtui/1.0")
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        let items = self.extract(&body)?;
+        if items.is_empty() {
+            anyhow::bail!("no items matched selector {:?}", self.item_selector);
+        }
+        Ok(FeedData::Items(items))
+    }
+}
@@ -0,0 +1,179 @@
+use super::{FeedData, FeedFetcher};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// ~/.feedtui/plugins
+pub fn plugins_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".feedtui")
+        .join("plugins")
+}
+
+/// Resolve a plugin file name against the plugins directory
+pub fn plugin_path(name: &str) -> PathBuf {
+    let path = PathBuf::from(name);
+    if path.is_absolute() {
+        path
+    } else {
+        plugins_dir().join(path)
+    }
+}
+
+/// Runs a Lua plugin script.
+///
+/// A plugin defines two global functions:
+///
+/// ```lua
+/// function fetch(args)   -- args is the widget's `args` table from config.toml
+///   return json_decode(http_get("https://example.com/api"))
+/// end
+///
+/// function render(data)  -- data is whatever fetch returned
+///   return { "plain line", { text = "line with a link", url = "https://..." } }
+/// end
+/// ```
+///
+/// `http_get(url)` and `json_decode(text)` are provided to every script.
+pub struct ScriptFetcher {
+    path: PathBuf,
+    #[cfg_attr(not(feature = "plugins"), allow(dead_code))]
+    args: toml::Table,
+    #[cfg_attr(not(feature = "plugins"), allow(dead_code))]
+    timeout: Duration,
+}
+
+impl ScriptFetcher {
+    pub fn new(script: &str, args: toml::Table, timeout: Duration) -> Self {
+        Self {
+            path: plugin_path(script),
+            args,
+            timeout,
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for ScriptFetcher {
+    #[cfg(feature = "plugins")]
+    async fn fetch(&self) -> Result<FeedData> {
+        let path = self.path.clone();
+        let args = self.args.clone();
+        let timeout = self.timeout;
+        let handle = tokio::runtime::Handle::current();
+
+        // The Lua state is not Send, so each run gets its own blocking thread
+        let lines =
+            tokio::task::spawn_blocking(move || lua::run_script(&path, &args, timeout, handle))
+                .await??;
+        Ok(FeedData::Script(lines))
+    }
+
+    #[cfg(not(feature = "plugins"))]
+    async fn fetch(&self) -> Result<FeedData> {
+        anyhow::bail!(
+            "cannot run {}: feedtui was built without the `plugins` feature",
+            self.path.display()
+        )
+    }
+}
+
+#[cfg(feature = "plugins")]
+mod lua {
+    use crate::feeds::{http, ScriptLine};
+    use anyhow::{anyhow, Context, Result};
+    use mlua::{Function, Lua, LuaSerdeExt, Value};
+    use std::path::Path;
+    use std::time::Duration;
+    use tokio::runtime::Handle;
+
+    fn lua_error(e: mlua::Error) -> anyhow::Error {
+        anyhow!("{}", e)
+    }
+
+    pub fn run_script(
+        path: &Path,
+        args: &toml::Table,
+        timeout: Duration,
+        handle: Handle,
+    ) -> Result<Vec<ScriptLine>> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read plugin {}", path.display()))?;
+
+        let lua = Lua::new();
+        install_helpers(&lua, timeout, handle).map_err(lua_error)?;
+        lua.load(&source)
+            .set_name(path.display().to_string())
+            .exec()
+            .map_err(lua_error)?;
+
+        let globals = lua.globals();
+        let fetch: Function = globals
+            .get("fetch")
+            .map_err(|_| anyhow!("{} does not define fetch()", path.display()))?;
+        let render: Function = globals
+            .get("render")
+            .map_err(|_| anyhow!("{} does not define render()", path.display()))?;
+
+        let args = lua.to_value(args).map_err(lua_error)?;
+        let data: Value = fetch.call(args).map_err(lua_error)?;
+        let lines: Vec<Value> = render.call(data).map_err(lua_error)?;
+
+        lines.into_iter().map(to_script_line).collect()
+    }
+
+    fn install_helpers(lua: &Lua, timeout: Duration, handle: Handle) -> mlua::Result<()> {
+        let globals = lua.globals();
+        let client = http::client(timeout);
+
+        let http_get = lua.create_function(move |_, url: String| {
+            let client = client.clone();
+            handle
+                .block_on(async move {
+                    client
+                        .get(&url)
+                        .header("User-Agent", "feedtui/1.0")
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .text()
+                        .await
+                })
+                .map_err(mlua::Error::external)
+        })?;
+        globals.set("http_get", http_get)?;
+
+        let json_decode = lua.create_function(|lua, text: String| {
+            let value: serde_json::Value =
+                serde_json::from_str(&text).map_err(mlua::Error::external)?;
+            lua.to_value(&value)
+        })?;
+        globals.set("json_decode", json_decode)?;
+
+        Ok(())
+    }
+
+    fn to_script_line(value: Value) -> Result<ScriptLine> {
+        let text = match value {
+            Value::Table(table) => {
+                return Ok(ScriptLine {
+                    text: table.get("text").map_err(lua_error)?,
+                    url: table.get("url").map_err(lua_error)?,
+                })
+            }
+            Value::String(s) => s.to_string_lossy(),
+            Value::Integer(i) => i.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::Boolean(b) => b.to_string(),
+            other => {
+                return Err(anyhow!(
+                    "render() must return strings or {{ text, url }} tables, got {}",
+                    other.type_name()
+                ))
+            }
+        };
+        Ok(ScriptLine { text, url: None })
+    }
+}
@@ -1,11 +1,12 @@
-use super::{FeedData, FeedFetcher, SportsEvent};
+use super::{http, FeedData, FeedFetcher, SportsEvent};
 use anyhow::Result;
 use async_trait::async_trait;
 use serde::Deserialize;
+use std::time::Duration;
 
 pub struct SportsFetcher {
     leagues: Vec<String>,
-    client: reqwest::Client,
+    client: http::HttpClient,
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,6 +37,24 @@ struct EspnCompetition {
     competitors: Vec<EspnCompetitor>,
     #[serde(rename = "startDate")]
     start_date: Option<String>,
+    situation: Option<EspnSituation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EspnSituation {
+    #[serde(rename = "lastPlay")]
+    last_play: Option<EspnPlay>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EspnPlay {
+    probability: Option<EspnProbability>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EspnProbability {
+    #[serde(rename = "homeWinPercentage")]
+    home_win_percentage: Option<f64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,14 +72,14 @@ struct EspnTeam {
 }
 
 impl SportsFetcher {
-    pub fn new(leagues: Vec<String>) -> Self {
+    pub fn new(leagues: Vec<String>, timeout: Duration) -> Self {
         Self {
             leagues,
-            client: reqwest::Client::new(),
+            client: http::client(timeout),
         }
     }
 
-    fn league_to_espn_endpoint(league: &str) -> Option<&'static str> {
+    pub fn league_to_espn_endpoint(league: &str) -> Option<&'static str> {
         match league.to_lowercase().as_str() {
             "nba" => Some("basketball/nba"),
             "nfl" => Some("football/nfl"),
@@ -110,6 +129,12 @@ impl SportsFetcher {
                     away_score: home.score.as_ref().and_then(|s| s.parse().ok()),
                     status: event.status.status_type.description.clone(),
                     start_time: competition.start_date.clone(),
+                    home_win_probability: competition
+                        .situation
+                        .as_ref()
+                        .and_then(|s| s.last_play.as_ref())
+                        .and_then(|p| p.probability.as_ref())
+                        .and_then(|p| p.home_win_percentage),
                 })
             })
             .collect();
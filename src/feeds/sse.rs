@@ -0,0 +1,274 @@
+use super::graphql::{lookup, value_to_string};
+use super::{http, FeedData, FeedFetcher, GenericItem};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Local;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// How long a one-shot `fetch` listens before returning what it received
+const FETCH_WINDOW: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Default)]
+struct SseEvent {
+    event: String,
+    data: String,
+    id: Option<String>,
+}
+
+/// Incremental parser for the `text/event-stream` format
+#[derive(Default)]
+struct SseParser {
+    buf: Vec<u8>,
+    event: String,
+    data: Vec<String>,
+    id: Option<String>,
+    retry: Option<Duration>,
+}
+
+impl SseParser {
+    fn push(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buf.extend_from_slice(chunk);
+        let mut events = Vec::new();
+
+        while let Some(pos) = self.buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    events.push(SseEvent {
+                        event: std::mem::take(&mut self.event),
+                        data: std::mem::take(&mut self.data).join("\n"),
+                        id: self.id.clone(),
+                    });
+                }
+                self.event.clear();
+                continue;
+            }
+            if line.starts_with(':') {
+                continue;
+            }
+
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => self.event = value.to_string(),
+                "data" => self.data.push(value.to_string()),
+                "id" => self.id = Some(value.to_string()),
+                "retry" => {
+                    // `retry: 0` would otherwise reconnect in a tight loop
+                    if let Ok(ms) = value.parse() {
+                        self.retry = Some(Duration::from_millis(ms).max(MIN_BACKOFF));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        events
+    }
+}
+
+pub struct SseFetcher {
+    url: String,
+    title_template: String,
+    url_template: Option<String>,
+    max_events: usize,
+    client: reqwest::Client,
+}
+
+impl SseFetcher {
+    pub fn new(
+        url: String,
+        title_template: String,
+        url_template: Option<String>,
+        max_events: usize,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            url,
+            title_template,
+            url_template,
+            max_events,
+            client: http::streaming_client(timeout),
+        }
+    }
+
+    async fn connect(&self, last_event_id: Option<&str>) -> Result<reqwest::Response> {
+        let mut request = self
+            .client
+            .get(&self.url)
+            .header("Accept", "text/event-stream")
+            .header("User-Agent", "feedtui/1.0");
+        if let Some(id) = last_event_id {
+            request = request.header("Last-Event-ID", id);
+        }
+        Ok(request.send().await?.error_for_status()?)
+    }
+
+    fn to_item(&self, event: &SseEvent) -> GenericItem {
+        let json = serde_json::from_str::<Value>(&event.data).ok();
+        GenericItem {
+            title: render_template(&self.title_template, event, json.as_ref()),
+            url: self
+                .url_template
+                .as_ref()
+                .map(|t| render_template(t, event, json.as_ref()))
+                .filter(|u| !u.is_empty()),
+            description: Some(event.data.clone()),
+            meta: Some(match event.event.as_str() {
+                "" => Local::now().format("%H:%M:%S").to_string(),
+                name => format!("{} · {}", Local::now().format("%H:%M:%S"), name),
+            }),
+        }
+    }
+}
+
+/// Fill `{event}`, `{id}`, `{data}`, and `{data.path.to.field}` placeholders
+fn render_template(template: &str, event: &SseEvent, json: Option<&Value>) -> String {
+    let mut out = String::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let key = &rest[start + 1..start + len];
+        let value = match key {
+            "event" => Some(event.event.clone()),
+            "id" => event.id.clone(),
+            "data" => Some(event.data.clone()),
+            _ => key
+                .strip_prefix("data.")
+                .and_then(|path| json.and_then(|j| lookup(j, path)))
+                .and_then(value_to_string),
+        };
+        out.push_str(&value.unwrap_or_default());
+        rest = &rest[start + len + 1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[async_trait]
+impl FeedFetcher for SseFetcher {
+    async fn fetch(&self) -> Result<FeedData> {
+        let mut response = self.connect(None).await?;
+        let mut parser = SseParser::default();
+        let mut items = Vec::new();
+
+        let _ = tokio::time::timeout(FETCH_WINDOW, async {
+            while let Ok(Some(chunk)) = response.chunk().await {
+                for event in parser.push(&chunk) {
+                    items.insert(0, self.to_item(&event));
+                }
+                if items.len() >= self.max_events {
+                    break;
+                }
+            }
+        })
+        .await;
+
+        items.truncate(self.max_events);
+        Ok(FeedData::Items(items))
+    }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn subscribe(&self, tx: mpsc::UnboundedSender<FeedData>) -> Result<()> {
+        let mut events: VecDeque<GenericItem> = VecDeque::new();
+        let mut last_event_id: Option<String> = None;
+        let mut min_backoff = MIN_BACKOFF;
+        let mut backoff = min_backoff;
+
+        while !tx.is_closed() {
+            match self.connect(last_event_id.as_deref()).await {
+                Ok(mut response) => {
+                    let mut parser = SseParser::default();
+                    while let Ok(Some(chunk)) = response.chunk().await {
+                        for event in parser.push(&chunk) {
+                            if event.id.is_some() {
+                                last_event_id = event.id.clone();
+                            }
+                            events.push_front(self.to_item(&event));
+                            events.truncate(self.max_events);
+                        }
+                        if let Some(retry) = parser.retry {
+                            min_backoff = retry;
+                        }
+                        backoff = min_backoff;
+                        if tx
+                            .send(FeedData::Items(events.iter().cloned().collect()))
+                            .is_err()
+                        {
+                            return Ok(());
+                        }
+                    }
+                }
+                Err(e) => {
+                    // Keep showing earlier events while reconnecting
+                    if events.is_empty() {
+                        let _ = tx.send(FeedData::Error(format!(
+                            "{} (retrying in {}s)",
+                            http::error_message(&e),
+                            backoff.as_secs()
+                        )));
+                    }
+                }
+            }
+
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parser_handles_split_chunks() {
+        let mut parser = SseParser::default();
+        assert!(parser.push(b"event: deploy\ndata: {\"msg\"").is_empty());
+
+        let events = parser.push(b": \"ok\"}\nid: 7\n\ndata: second\n\n");
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event, "deploy");
+        assert_eq!(events[0].data, "{\"msg\": \"ok\"}");
+        assert_eq!(events[0].id.as_deref(), Some("7"));
+        assert_eq!(events[1].event, "");
+        assert_eq!(events[1].data, "second");
+
+        parser.push(b"retry: 5000\n");
+        assert_eq!(parser.retry, Some(Duration::from_secs(5)));
+        parser.push(b"retry: 0\n");
+        assert_eq!(parser.retry, Some(MIN_BACKOFF));
+    }
+
+    #[test]
+    fn test_render_template() {
+        let event = SseEvent {
+            event: "deploy".to_string(),
+            data: "{\"app\": {\"name\": \"api\"}}".to_string(),
+            id: None,
+        };
+        let json = serde_json::from_str::<Value>(&event.data).ok();
+        assert_eq!(
+            render_template("{event}: {data.app.name}{missing}", &event, json.as_ref()),
+            "deploy: api"
+        );
+    }
+}
@@ -1,12 +1,14 @@
-use super::{FeedData, FeedFetcher, StockQuote};
+use super::{http, FeedData, FeedFetcher, RssItem, StockQuote};
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::DateTime;
 use futures::future::join_all;
 use serde::Deserialize;
+use std::time::Duration;
 
 pub struct StocksFetcher {
     symbols: Vec<String>,
-    client: reqwest::Client,
+    client: http::HttpClient,
 }
 
 #[derive(Debug, Deserialize)]
@@ -35,11 +37,68 @@ struct ChartMeta {
     chart_previous_close: Option<f64>,
 }
 
+#[derive(Debug, Deserialize)]
+struct YahooSearchResponse {
+    #[serde(default)]
+    news: Vec<YahooNews>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YahooNews {
+    title: String,
+    link: Option<String>,
+    publisher: Option<String>,
+    #[serde(rename = "providerPublishTime")]
+    published: Option<i64>,
+}
+
+/// Headlines to show for one symbol
+const NEWS_COUNT: usize = 15;
+
+/// Recent headlines mentioning `symbol`, newest first
+pub async fn fetch_news(symbol: &str, timeout: Duration) -> Result<Vec<RssItem>> {
+    let url = format!(
+        "https://query1.finance.yahoo.com/v1/finance/search?q={}&quotesCount=0&newsCount={}",
+        symbol, NEWS_COUNT
+    );
+    let response: YahooSearchResponse = http::client(timeout)
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let mut items: Vec<RssItem> = response
+        .news
+        .into_iter()
+        .map(|news| {
+            let published_at = news
+                .published
+                .and_then(|secs| DateTime::from_timestamp(secs, 0));
+            RssItem {
+                title: news.title,
+                link: news.link,
+                published: published_at.map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
+                published_at,
+                source: news
+                    .publisher
+                    .unwrap_or_else(|| "Yahoo Finance".to_string()),
+                description: None,
+                discussion: None,
+            }
+        })
+        .collect();
+    items.sort_by_key(|item| std::cmp::Reverse(item.published_at));
+    Ok(items)
+}
+
 impl StocksFetcher {
-    pub fn new(symbols: Vec<String>) -> Self {
+    pub fn new(symbols: Vec<String>, timeout: Duration) -> Self {
         Self {
             symbols,
-            client: reqwest::Client::new(),
+            client: http::client(timeout),
         }
     }
 
@@ -0,0 +1,190 @@
+use super::{http, FeedData, FeedFetcher, WalletHolding};
+use crate::config::{CryptoAsset, WalletAddress};
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+
+const BLOCKSTREAM_API: &str = "https://blockstream.info/api";
+const COINGECKO_PRICES: &str = "https://api.coingecko.com/api/v3/simple/price";
+
+const SATS_PER_BTC: f64 = 1e8;
+const WEI_PER_ETH: f64 = 1e18;
+
+pub struct WalletFetcher {
+    addresses: Vec<WalletAddress>,
+    currency: String,
+    eth_rpc: String,
+    client: http::HttpClient,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockstreamAddress {
+    chain_stats: ChainStats,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChainStats {
+    funded_txo_sum: u64,
+    spent_txo_sum: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    result: Option<String>,
+    error: Option<Value>,
+}
+
+impl WalletFetcher {
+    pub fn new(
+        addresses: Vec<WalletAddress>,
+        currency: String,
+        eth_rpc: String,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            addresses,
+            currency: currency.to_lowercase(),
+            eth_rpc,
+            client: http::client(timeout),
+        }
+    }
+
+    /// Spot price and 24h change of each asset, by asset
+    async fn fetch_prices(&self) -> Result<HashMap<CryptoAsset, (f64, f64)>> {
+        let url = format!(
+            "{}?ids=bitcoin,ethereum&vs_currencies={}&include_24hr_change=true",
+            COINGECKO_PRICES, self.currency
+        );
+        let prices: HashMap<String, HashMap<String, f64>> = self
+            .client
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let change_key = format!("{}_24h_change", self.currency);
+        let mut by_asset = HashMap::new();
+        for (asset, id) in [
+            (CryptoAsset::Btc, "bitcoin"),
+            (CryptoAsset::Eth, "ethereum"),
+        ] {
+            let Some(quote) = prices.get(id) else {
+                continue;
+            };
+            let price = quote
+                .get(&self.currency)
+                .copied()
+                .ok_or_else(|| anyhow!("no {} price for {}", self.currency, id))?;
+            let change = quote.get(&change_key).copied().unwrap_or(0.0);
+            by_asset.insert(asset, (price, change));
+        }
+        Ok(by_asset)
+    }
+
+    async fn fetch_balance(&self, address: &WalletAddress) -> Result<f64> {
+        match address.asset {
+            CryptoAsset::Btc => {
+                let info: BlockstreamAddress = self
+                    .client
+                    .get(format!("{}/address/{}", BLOCKSTREAM_API, address.address))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                let sats = info
+                    .chain_stats
+                    .funded_txo_sum
+                    .saturating_sub(info.chain_stats.spent_txo_sum);
+                Ok(sats as f64 / SATS_PER_BTC)
+            }
+            CryptoAsset::Eth => {
+                let body = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "eth_getBalance",
+                    "params": [address.address, "latest"],
+                });
+                let response: RpcResponse = self
+                    .client
+                    .post(&self.eth_rpc)
+                    .json(&body)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                if let Some(error) = response.error {
+                    bail!("eth_getBalance failed: {}", error);
+                }
+                let wei = response
+                    .result
+                    .ok_or_else(|| anyhow!("eth_getBalance returned nothing"))?;
+                Ok(parse_wei(&wei)? / WEI_PER_ETH)
+            }
+        }
+    }
+}
+
+/// A hex quantity such as `0x1bc16d674ec80000`
+fn parse_wei(hex: &str) -> Result<f64> {
+    let digits = hex.trim_start_matches("0x");
+    if digits.is_empty() {
+        return Ok(0.0);
+    }
+    Ok(u128::from_str_radix(digits, 16)? as f64)
+}
+
+#[async_trait]
+impl FeedFetcher for WalletFetcher {
+    async fn fetch(&self) -> Result<FeedData> {
+        let prices = self.fetch_prices().await?;
+        let balances = join_all(self.addresses.iter().map(|a| self.fetch_balance(a))).await;
+
+        let holdings = self
+            .addresses
+            .iter()
+            .zip(balances)
+            .map(|(address, balance)| {
+                let (price, change) = prices.get(&address.asset).copied().unwrap_or_default();
+                let balance = balance
+                    .map_err(|e| {
+                        tracing::warn!(
+                            "could not fetch {} balance of {}: {:#}",
+                            address.asset.symbol(),
+                            address.address,
+                            e
+                        )
+                    })
+                    .ok();
+                WalletHolding {
+                    asset: address.asset.symbol().to_string(),
+                    address: address.address.clone(),
+                    label: address.label.clone(),
+                    balance,
+                    price,
+                    change_percent_24h: change,
+                }
+            })
+            .collect();
+        Ok(FeedData::Wallet(holdings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wei_quantities_become_ether() {
+        assert_eq!(parse_wei("0x1bc16d674ec80000").unwrap() / WEI_PER_ETH, 2.0);
+        assert_eq!(parse_wei("0x0").unwrap(), 0.0);
+        assert!(parse_wei("0xzz").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use super::script::plugin_path;
+use super::{FeedData, FeedFetcher};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Runs a sandboxed WASI plugin module.
+///
+/// Plugin ABI (WASI preview1 command module):
+///
+/// - stdin: the widget's `args` table, encoded as JSON
+/// - stdout: a JSON array of items `{ "title", "url"?, "description"?, "meta"? }`
+/// - imports from the `feedtui` module, the only way out of the sandbox:
+///   - `http_get(url_ptr: i32, url_len: i32) -> i32` performs a GET and
+///     returns the body length, or -1 on failure (including URLs over 8 KB
+///     and bodies over 4 MB)
+///   - `http_body(buf_ptr: i32) -> i32` copies the last body into guest
+///     memory (the guest allocates `length` bytes first); returns 0 or -1
+///
+/// Modules get no filesystem, environment, or sockets, and are stopped once
+/// they exhaust their fuel budget.
+pub struct WasmFetcher {
+    path: PathBuf,
+    #[cfg_attr(not(feature = "wasm-plugins"), allow(dead_code))]
+    args: toml::Table,
+    #[cfg_attr(not(feature = "wasm-plugins"), allow(dead_code))]
+    timeout: Duration,
+}
+
+impl WasmFetcher {
+    pub fn new(module: &str, args: toml::Table, timeout: Duration) -> Self {
+        Self {
+            path: plugin_path(module),
+            args,
+            timeout,
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for WasmFetcher {
+    #[cfg(feature = "wasm-plugins")]
+    async fn fetch(&self) -> Result<FeedData> {
+        let path = self.path.clone();
+        let input = serde_json::to_vec(&self.args)?;
+        let timeout = self.timeout;
+        let handle = tokio::runtime::Handle::current();
+
+        let items =
+            tokio::task::spawn_blocking(move || host::run_module(&path, input, timeout, handle))
+                .await??;
+        Ok(FeedData::Items(items))
+    }
+
+    #[cfg(not(feature = "wasm-plugins"))]
+    async fn fetch(&self) -> Result<FeedData> {
+        anyhow::bail!(
+            "cannot run {}: feedtui was built without the `wasm-plugins` feature",
+            self.path.display()
+        )
+    }
+}
+
+#[cfg(feature = "wasm-plugins")]
+mod host {
+    use crate::feeds::{http, GenericItem};
+    use anyhow::{Context, Result};
+    use std::path::Path;
+    use std::time::Duration;
+    use tokio::runtime::Handle;
+    use wasmtime::{Caller, Config, Engine, Extern, Linker, Memory, Module, Store};
+    use wasmtime_wasi::pipe::{MemoryInputPipe, MemoryOutputPipe};
+    use wasmtime_wasi::preview1::{self, WasiP1Ctx};
+    use wasmtime_wasi::{I32Exit, WasiCtxBuilder};
+
+    /// Instruction budget per fetch
+    const FUEL: u64 = 5_000_000_000;
+    /// Largest stdout a module may produce
+    const MAX_OUTPUT: usize = 4 * 1024 * 1024;
+    /// Longest URL a module may pass to `http_get`
+    const MAX_URL: usize = 8 * 1024;
+    /// Largest response body `http_get` hands to a module
+    const MAX_BODY: usize = 4 * 1024 * 1024;
+
+    struct HostState {
+        wasi: WasiP1Ctx,
+        client: http::HttpClient,
+        handle: Handle,
+        body: Vec<u8>,
+    }
+
+    pub fn run_module(
+        path: &Path,
+        input: Vec<u8>,
+        timeout: Duration,
+        handle: Handle,
+    ) -> Result<Vec<GenericItem>> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config)?;
+        let module = Module::from_file(&engine, path)
+            .with_context(|| format!("could not load plugin {}", path.display()))?;
+
+        let mut linker: Linker<HostState> = Linker::new(&engine);
+        preview1::add_to_linker_sync(&mut linker, |state| &mut state.wasi)?;
+        linker.func_wrap("feedtui", "http_get", http_get)?;
+        linker.func_wrap("feedtui", "http_body", http_body)?;
+
+        let stdout = MemoryOutputPipe::new(MAX_OUTPUT);
+        let wasi = WasiCtxBuilder::new()
+            .stdin(MemoryInputPipe::new(input))
+            .stdout(stdout.clone())
+            .build_p1();
+
+        let mut store = Store::new(
+            &engine,
+            HostState {
+                wasi,
+                client: http::client(timeout),
+                handle,
+                body: Vec::new(),
+            },
+        );
+        store.set_fuel(FUEL)?;
+
+        let instance = linker.instantiate(&mut store, &module)?;
+        let start = instance.get_typed_func::<(), ()>(&mut store, "_start")?;
+        if let Err(e) = start.call(&mut store, ()) {
+            // proc_exit(0) surfaces as an error but is a normal exit
+            match e.downcast_ref::<I32Exit>() {
+                Some(exit) if exit.0 == 0 => {}
+                _ => return Err(e.context(format!("plugin {} failed", path.display()))),
+            }
+        }
+
+        let output = stdout.contents();
+        serde_json::from_slice(&output)
+            .with_context(|| format!("plugin {} did not print a JSON item list", path.display()))
+    }
+
+    fn guest_memory(caller: &mut Caller<'_, HostState>) -> Option<Memory> {
+        match caller.get_export("memory") {
+            Some(Extern::Memory(memory)) => Some(memory),
+            _ => None,
+        }
+    }
+
+    fn http_get(mut caller: Caller<'_, HostState>, url_ptr: i32, url_len: i32) -> i32 {
+        let Some(memory) = guest_memory(&mut caller) else {
+            return -1;
+        };
+        // Checked before allocating, as the length comes from the module
+        let Ok(url_len) = usize::try_from(url_len) else {
+            return -1;
+        };
+        if url_len > MAX_URL || url_len > memory.data_size(&caller) {
+            return -1;
+        }
+        let mut url = vec![0u8; url_len];
+        if memory.read(&caller, url_ptr as usize, &mut url).is_err() {
+            return -1;
+        }
+        let url = String::from_utf8_lossy(&url).into_owned();
+
+        let state = caller.data_mut();
+        let client = state.client.clone();
+        let body = state.handle.block_on(async move {
+            let response = client
+                .get(&url)
+                .header("User-Agent", "feedtui/1.0")
+                .send()
+                .await?
+                .error_for_status()?;
+            http::read_limited(response, MAX_BODY).await
+        });
+
+        match body {
+            Ok(body) => {
+                state.body = body;
+                state.body.len() as i32
+            }
+            Err(_) => -1,
+        }
+    }
+
+    fn http_body(mut caller: Caller<'_, HostState>, buf_ptr: i32) -> i32 {
+        let Some(memory) = guest_memory(&mut caller) else {
+            return -1;
+        };
+        let body = std::mem::take(&mut caller.data_mut().body);
+        match memory.write(&mut caller, buf_ptr as usize, &body) {
+            Ok(()) => 0,
+            Err(_) => -1,
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use super::{FeedData, FeedFetcher, GenericItem};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Local;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, OnceCell};
+
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Events received by a webhook widget's listener, newest first
+pub struct WebhookState {
+    addr: String,
+    max_events: usize,
+    events: Mutex<VecDeque<GenericItem>>,
+    subscribers: Mutex<Vec<mpsc::UnboundedSender<FeedData>>>,
+    listener: OnceCell<()>,
+}
+
+impl WebhookState {
+    pub fn new(bind: &str, port: u16, max_events: usize) -> Self {
+        Self {
+            addr: format!("{}:{}", bind, port),
+            max_events,
+            events: Mutex::new(VecDeque::new()),
+            subscribers: Mutex::new(Vec::new()),
+            listener: OnceCell::new(),
+        }
+    }
+
+    fn snapshot(&self) -> FeedData {
+        let events = self.events.lock().unwrap();
+        FeedData::Items(events.iter().cloned().collect())
+    }
+
+    fn push(&self, item: GenericItem) {
+        {
+            let mut events = self.events.lock().unwrap();
+            events.push_front(item);
+            events.truncate(self.max_events);
+        }
+
+        let snapshot = self.snapshot();
+        self.subscribers
+            .lock()
+            .unwrap()
+            .retain(|tx| tx.send(snapshot.clone()).is_ok());
+    }
+}
+
+pub struct WebhookFetcher {
+    state: Arc<WebhookState>,
+}
+
+impl WebhookFetcher {
+    pub fn new(state: Arc<WebhookState>) -> Self {
+        Self { state }
+    }
+
+    /// Bind the listener the first time the widget is fetched
+    async fn ensure_listening(&self) -> Result<()> {
+        self.state
+            .listener
+            .get_or_try_init(|| async {
+                let listener = TcpListener::bind(&self.state.addr)
+                    .await
+                    .with_context(|| format!("could not listen on {}", self.state.addr))?;
+                let state = self.state.clone();
+                tokio::spawn(async move {
+                    while let Ok((stream, _)) = listener.accept().await {
+                        let state = state.clone();
+                        tokio::spawn(async move {
+                            let _ = handle_connection(stream, &state).await;
+                        });
+                    }
+                });
+                Ok::<(), anyhow::Error>(())
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for WebhookFetcher {
+    async fn fetch(&self) -> Result<FeedData> {
+        self.ensure_listening().await?;
+        Ok(self.state.snapshot())
+    }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn subscribe(&self, tx: mpsc::UnboundedSender<FeedData>) -> Result<()> {
+        self.ensure_listening().await?;
+        let _ = tx.send(self.state.snapshot());
+        self.state.subscribers.lock().unwrap().push(tx.clone());
+
+        // Events are pushed by the listener; stay subscribed until the app goes away
+        tx.closed().await;
+        Ok(())
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, state: &WebhookState) -> Result<()> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        if buf.len() > MAX_HEADER_BYTES {
+            return respond(&mut stream, "431 Request Header Fields Too Large").await;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).into_owned();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or("/").to_string();
+
+    if method != "POST" {
+        return respond(&mut stream, "405 Method Not Allowed").await;
+    }
+
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    if content_length > MAX_BODY_BYTES {
+        return respond(&mut stream, "413 Payload Too Large").await;
+    }
+
+    let mut body = buf[header_end..].to_vec();
+    while body.len() < content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+    body.truncate(content_length);
+
+    match serde_json::from_slice::<Value>(&body) {
+        Ok(payload) => {
+            state.push(payload_to_item(&payload, &path));
+            respond(&mut stream, "204 No Content").await
+        }
+        Err(_) => respond(&mut stream, "400 Bad Request").await,
+    }
+}
+
+async fn respond(stream: &mut TcpStream, status: &str) -> Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        status
+    );
+    stream.write_all(response.as_bytes()).await?;
+    Ok(())
+}
+
+/// Summarize a payload, preferring common human-readable fields
+fn payload_to_item(payload: &Value, path: &str) -> GenericItem {
+    let field = |names: &[&str]| {
+        names
+            .iter()
+            .find_map(|name| payload.get(*name).and_then(|v| v.as_str()))
+            .map(|s| s.to_string())
+    };
+
+    let title =
+        field(&["title", "message", "text", "summary", "status", "event"]).unwrap_or_else(|| {
+            let compact = payload.to_string();
+            compact.chars().take(120).collect()
+        });
+
+    GenericItem {
+        title,
+        url: field(&["url", "link", "html_url"]),
+        description: serde_json::to_string_pretty(payload).ok(),
+        meta: Some(format!(
+            "{} · POST {}",
+            Local::now().format("%H:%M:%S"),
+            path
+        )),
+    }
+}
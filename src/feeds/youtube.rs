@@ -1,7 +1,8 @@
-use super::{FeedData, FeedFetcher, YoutubeVideo};
+use super::{http, FeedData, FeedFetcher, YoutubeVideo};
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use serde::Deserialize;
+use std::time::Duration;
 
 const YOUTUBE_API_BASE: &str = "https://www.googleapis.com/youtube/v3";
 
@@ -10,7 +11,7 @@ pub struct YoutubeFetcher {
     channels: Vec<String>,
     search_query: Option<String>,
     max_videos: usize,
-    client: reqwest::Client,
+    client: http::HttpClient,
 }
 
 #[derive(Debug, Deserialize)]
@@ -95,13 +96,14 @@ impl YoutubeFetcher {
         channels: Vec<String>,
         search_query: Option<String>,
         max_videos: usize,
+        timeout: Duration,
     ) -> Self {
         Self {
             api_key,
             channels,
             search_query,
             max_videos,
-            client: reqwest::Client::new(),
+            client: http::client(timeout),
         }
     }
 
@@ -259,7 +261,7 @@ impl FeedFetcher for YoutubeFetcher {
             match self.get_channel_videos(channel_id).await {
                 Ok(mut videos) => all_videos.append(&mut videos),
                 Err(e) => {
-                    eprintln!("Error fetching channel {}: {}", channel_id, e);
+                    tracing::warn!("Error fetching channel {}: {}", channel_id, e);
                     continue;
                 }
             }
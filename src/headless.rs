@@ -0,0 +1,91 @@
+use crate::config::{Config, WidgetConfig};
+use crate::creature::Creature;
+use crate::feeds::filter::{self, Filter};
+use crate::feeds::retry::RetryPolicy;
+use crate::feeds::{http, FeedData, Progress};
+use crate::ui::widgets::create_widget;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use serde::Serialize;
+
+/// Result of a single fetch pass for one widget
+#[derive(Debug, Serialize)]
+pub struct WidgetSnapshot {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub title: String,
+    pub data: FeedData,
+}
+
+/// Result of a single fetch pass over the whole dashboard
+#[derive(Debug, Serialize)]
+pub struct FetchReport {
+    pub fetched_at: DateTime<Utc>,
+    pub widgets: Vec<WidgetSnapshot>,
+}
+
+/// Run every configured fetcher once, without starting the TUI
+pub async fn fetch_all(config: &Config) -> FetchReport {
+    let creature = Creature::default();
+    let retry = RetryPolicy::from_config(&config.general);
+    http::configure(config);
+
+    let futures = config
+        .widgets
+        .iter()
+        // The creature and digest widgets have no external data to fetch
+        .filter(|cfg| !matches!(cfg, WidgetConfig::Creature(_) | WidgetConfig::Digest(_)))
+        .map(|cfg| {
+            let widget = create_widget(cfg, &creature);
+            let fetcher = widget.create_fetcher();
+            let id = widget.id();
+            let title = widget.title().to_string();
+            let kind = cfg.kind().to_string();
+            let filters = Filter::compile_all(cfg.filters());
+
+            async move {
+                let mut data = match retry.fetch(fetcher.as_ref(), &Progress::none()).await {
+                    Ok(data) => data,
+                    Err(e) => FeedData::Error(http::error_message(&e)),
+                };
+                filter::apply(&filters, &mut data);
+                WidgetSnapshot {
+                    id,
+                    kind,
+                    title,
+                    data,
+                }
+            }
+        });
+
+    FetchReport {
+        fetched_at: Utc::now(),
+        widgets: join_all(futures).await,
+    }
+}
+
+/// Entry point for `feedtui fetch`
+pub async fn run_fetch(config: &Config, json: bool) -> Result<()> {
+    let report = fetch_all(config).await;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    for widget in &report.widgets {
+        println!("== {} ({}) ==", widget.title, widget.id);
+        let titles = widget.data.item_titles();
+        if titles.is_empty() {
+            println!("  (no items)");
+        }
+        for title in titles {
+            println!("  - {}", title);
+        }
+        println!();
+    }
+
+    Ok(())
+}
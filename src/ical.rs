@@ -0,0 +1,136 @@
+use crate::feeds::SportsEvent;
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// Rough length of a game, as the scoreboard only gives start times
+const GAME_HOURS: u32 = 3;
+
+/// An iCalendar file of the games in `events` that haven't started yet,
+/// narrowed to `favorite_teams` unless that is empty, and how many games it
+/// holds
+pub fn games_calendar(
+    events: &[SportsEvent],
+    favorite_teams: &[String],
+    now: DateTime<Utc>,
+) -> (String, usize) {
+    let favorites: Vec<String> = favorite_teams.iter().map(|t| t.to_lowercase()).collect();
+    let is_favorite = |team: &str| {
+        let team = team.to_lowercase();
+        favorites.iter().any(|f| team.contains(f.as_str()))
+    };
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//feedtui//games//EN".to_string(),
+        "X-WR-CALNAME:feedtui games".to_string(),
+    ];
+    let mut count = 0;
+    for event in events {
+        let Some(start) = event.start_time.as_deref().and_then(parse_start) else {
+            continue;
+        };
+        if start <= now {
+            continue;
+        }
+        if !favorites.is_empty() && !is_favorite(&event.home_team) && !is_favorite(&event.away_team)
+        {
+            continue;
+        }
+        let summary = format!("{} @ {}", event.away_team, event.home_team);
+        let uid: String = format!("{}-{}-{}", event.league, summary, start.timestamp())
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect();
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}@feedtui", uid),
+            format!("DTSTAMP:{}", ical_time(now)),
+            format!("DTSTART:{}", ical_time(start)),
+            format!("DURATION:PT{}H", GAME_HOURS),
+            format!("SUMMARY:{}", escape_text(&summary)),
+            format!("CATEGORIES:{}", escape_text(&event.league)),
+            "END:VEVENT".to_string(),
+        ]);
+        count += 1;
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let calendar = lines
+        .iter()
+        .map(|line| fold(line))
+        .collect::<Vec<_>>()
+        .join("\r\n");
+    (calendar + "\r\n", count)
+}
+
+/// ESPN gives start times like `2024-05-01T23:30Z`, without seconds
+fn parse_start(start: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(start)
+        .map(|t| t.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(start, "%Y-%m-%dT%H:%MZ")
+                .ok()
+                .map(|t| t.and_utc())
+        })
+}
+
+fn ical_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Split lines longer than 75 bytes, continuing them with a leading space
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn game(home: &str, away: &str, start: &str) -> SportsEvent {
+        SportsEvent {
+            league: "NBA".to_string(),
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            home_score: None,
+            away_score: None,
+            status: "Scheduled".to_string(),
+            start_time: Some(start.to_string()),
+            home_win_probability: None,
+        }
+    }
+
+    #[test]
+    fn lists_upcoming_games_of_favorites() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let events = [
+            game("Boston Celtics", "Miami Heat", "2024-05-01T23:30Z"),
+            game("Boston Celtics", "New York Knicks", "2024-05-01T10:00Z"),
+            game("Denver Nuggets", "Phoenix Suns", "2024-05-02T01:00Z"),
+        ];
+        let (calendar, count) = games_calendar(&events, &["celtics".to_string()], now);
+        assert_eq!(count, 1);
+        assert!(calendar.contains("SUMMARY:Miami Heat @ Boston Celtics\r\n"));
+        assert!(calendar.contains("DTSTART:20240501T233000Z\r\n"));
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+    }
+}
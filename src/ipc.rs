@@ -0,0 +1,233 @@
+use anyhow::Result;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::sync::{mpsc, oneshot};
+
+/// A command sent over the control socket.
+///
+/// The protocol is line based: each line is one command, answered with
+/// `ok` or `error: <reason>`.
+///
+/// ```text
+/// refresh [widget]   refresh one widget, or all of them
+/// focus <widget>     select a widget
+/// notify <message>   show a message in the status bar
+/// quit               exit feedtui
+/// ```
+///
+/// Widgets are named by id (`hackernews-1`), title, or 1-based index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlCommand {
+    Refresh(Option<String>),
+    Focus(String),
+    Notify(String),
+    Quit,
+}
+
+impl ControlCommand {
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let line = line.trim();
+        let (name, arg) = match line.split_once(char::is_whitespace) {
+            Some((name, arg)) => (name, Some(arg.trim().to_string())),
+            None => (line, None),
+        };
+
+        match (name, arg) {
+            ("refresh", arg) => Ok(ControlCommand::Refresh(arg)),
+            ("focus", Some(widget)) => Ok(ControlCommand::Focus(widget)),
+            ("notify", Some(message)) => Ok(ControlCommand::Notify(message)),
+            ("quit", None) => Ok(ControlCommand::Quit),
+            ("focus", None) => Err("usage: focus <widget>".to_string()),
+            ("notify", None) => Err("usage: notify <message>".to_string()),
+            _ => Err(format!("unknown command {:?}", line)),
+        }
+    }
+}
+
+/// A command from a client, with a channel for the app's answer
+pub struct ControlRequest {
+    pub command: ControlCommand,
+    pub reply: oneshot::Sender<Result<(), String>>,
+}
+
+async fn serve_connection<S>(stream: S, tx: mpsc::UnboundedSender<ControlRequest>) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let result = match ControlCommand::parse(&line) {
+            Ok(command) => {
+                let (reply, answer) = oneshot::channel();
+                if tx.send(ControlRequest { command, reply }).is_err() {
+                    break;
+                }
+                answer
+                    .await
+                    .unwrap_or_else(|_| Err("feedtui is shutting down".to_string()))
+            }
+            Err(e) => Err(e),
+        };
+
+        let response = match result {
+            Ok(()) => "ok\n".to_string(),
+            Err(e) => format!("error: {}\n", e),
+        };
+        writer.write_all(response.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+pub use self::unix::{send_command, spawn_server};
+
+#[cfg(windows)]
+pub use self::windows::{send_command, spawn_server};
+
+#[cfg(unix)]
+mod unix {
+    use super::{serve_connection, ControlRequest};
+    use anyhow::{Context, Result};
+    use std::path::PathBuf;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{UnixListener, UnixStream};
+    use tokio::sync::mpsc;
+
+    /// ~/.feedtui/feedtui.sock
+    fn socket_path() -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".feedtui")
+            .join("feedtui.sock")
+    }
+
+    /// The socket this process is listening on, removed when dropped
+    pub struct ControlSocket {
+        path: PathBuf,
+    }
+
+    impl Drop for ControlSocket {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+
+    pub async fn spawn_server(tx: mpsc::UnboundedSender<ControlRequest>) -> Result<ControlSocket> {
+        listen(socket_path(), tx).await
+    }
+
+    async fn listen(
+        path: PathBuf,
+        tx: mpsc::UnboundedSender<ControlRequest>,
+    ) -> Result<ControlSocket> {
+        if path.exists() {
+            if UnixStream::connect(&path).await.is_ok() {
+                anyhow::bail!("another feedtui is already listening on {}", path.display());
+            }
+            // Left behind by a previous run that did not exit cleanly
+            std::fs::remove_file(&path)?;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let listener = UnixListener::bind(&path)
+            .with_context(|| format!("could not bind {}", path.display()))?;
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve_connection(stream, tx.clone()));
+            }
+        });
+        Ok(ControlSocket { path })
+    }
+
+    pub async fn send_command(line: &str) -> Result<String> {
+        let path = socket_path();
+        let stream = UnixStream::connect(&path).await.with_context(|| {
+            format!(
+                "could not connect to {} (is feedtui running?)",
+                path.display()
+            )
+        })?;
+
+        let (reader, mut writer) = stream.into_split();
+        writer.write_all(format!("{}\n", line).as_bytes()).await?;
+        let mut response = String::new();
+        BufReader::new(reader).read_line(&mut response).await?;
+        Ok(response.trim_end().to_string())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use tempfile::tempdir;
+
+        #[tokio::test]
+        async fn only_the_owner_removes_the_socket() {
+            let dir = tempdir().unwrap();
+            let path = dir.path().join("feedtui.sock");
+            let (tx, _rx) = mpsc::unbounded_channel();
+
+            let socket = listen(path.clone(), tx.clone()).await.unwrap();
+            assert!(listen(path.clone(), tx).await.is_err());
+            assert!(path.exists());
+
+            drop(socket);
+            assert!(!path.exists());
+        }
+    }
+}
+
+#[cfg(windows)]
+mod windows {
+    use super::{serve_connection, ControlRequest};
+    use anyhow::{Context, Result};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::windows::named_pipe::{ClientOptions, ServerOptions};
+    use tokio::sync::mpsc;
+
+    const PIPE_NAME: &str = r"\\.\pipe\feedtui";
+
+    /// Named pipes go away with the process that created them
+    pub struct ControlSocket;
+
+    pub async fn spawn_server(tx: mpsc::UnboundedSender<ControlRequest>) -> Result<ControlSocket> {
+        let mut server = ServerOptions::new()
+            .first_pipe_instance(true)
+            .create(PIPE_NAME)
+            .with_context(|| format!("could not create {}", PIPE_NAME))?;
+
+        tokio::spawn(async move {
+            loop {
+                if server.connect().await.is_err() {
+                    break;
+                }
+                let connected = server;
+                server = match ServerOptions::new().create(PIPE_NAME) {
+                    Ok(server) => server,
+                    Err(_) => break,
+                };
+                tokio::spawn(serve_connection(connected, tx.clone()));
+            }
+        });
+        Ok(ControlSocket)
+    }
+
+    pub async fn send_command(line: &str) -> Result<String> {
+        let pipe = ClientOptions::new()
+            .open(PIPE_NAME)
+            .with_context(|| format!("could not connect to {} (is feedtui running?)", PIPE_NAME))?;
+
+        let (reader, mut writer) = tokio::io::split(pipe);
+        writer.write_all(format!("{}\n", line).as_bytes()).await?;
+        let mut response = String::new();
+        BufReader::new(reader).read_line(&mut response).await?;
+        Ok(response.trim_end().to_string())
+    }
+}
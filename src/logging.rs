@@ -0,0 +1,204 @@
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+/// Rotate the log once it grows past this size
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+/// Number of rotated files to keep (feedtui.log.1 .. feedtui.log.N)
+const KEEP_ROTATED: usize = 3;
+/// Lines kept in memory for the in-app log viewer
+const BUFFER_LINES: usize = 500;
+
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// Recent log lines, shared with the log viewer overlay
+#[derive(Clone, Default)]
+pub struct LogBuffer(Arc<Mutex<VecDeque<LogLine>>>);
+
+impl LogBuffer {
+    fn push(&self, line: LogLine) {
+        let mut lines = self.0.lock().unwrap();
+        if lines.len() == BUFFER_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    /// The most recent `count` lines, oldest first
+    pub fn tail(&self, count: usize) -> Vec<LogLine> {
+        let lines = self.0.lock().unwrap();
+        let skip = lines.len().saturating_sub(count);
+        lines.iter().skip(skip).cloned().collect()
+    }
+}
+
+static BUFFER: OnceLock<LogBuffer> = OnceLock::new();
+
+/// The in-memory log, if `init` succeeded
+pub fn buffer() -> Option<LogBuffer> {
+    BUFFER.get().cloned()
+}
+
+/// ~/.feedtui/feedtui.log
+pub fn log_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".feedtui")
+        .join("feedtui.log")
+}
+
+/// Install the global logger. The level comes from `FEEDTUI_LOG`
+/// (error, warn, info, debug, trace) and defaults to info.
+pub fn init() -> Result<()> {
+    let max_level = std::env::var("FEEDTUI_LOG")
+        .ok()
+        .and_then(|level| level.parse::<Level>().ok())
+        .unwrap_or(Level::INFO);
+
+    let path = log_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let buffer = LogBuffer::default();
+    let logger = Logger {
+        file: Mutex::new(LogFile::open(path)?),
+        buffer: buffer.clone(),
+        max_level,
+        next_span: AtomicU64::new(1),
+    };
+    tracing::subscriber::set_global_default(logger)?;
+    let _ = BUFFER.set(buffer);
+    Ok(())
+}
+
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl LogFile {
+    fn open(path: PathBuf) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self { path, file, size })
+    }
+
+    fn write_line(&mut self, line: &str) {
+        if self.size + line.len() as u64 > MAX_LOG_BYTES {
+            let _ = self.rotate();
+        }
+        if self.file.write_all(line.as_bytes()).is_ok() {
+            self.size += line.len() as u64;
+        }
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        let rotated = |n: usize| self.path.with_extension(format!("log.{}", n));
+        for n in (1..KEEP_ROTATED).rev() {
+            let _ = std::fs::rename(rotated(n), rotated(n + 1));
+        }
+        std::fs::rename(&self.path, rotated(1))?;
+        *self = Self::open(self.path.clone())?;
+        Ok(())
+    }
+}
+
+struct Logger {
+    file: Mutex<LogFile>,
+    buffer: LogBuffer,
+    max_level: Level,
+    next_span: AtomicU64,
+}
+
+impl Subscriber for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        // Dependencies only get to log problems
+        let max_level = if metadata.target().starts_with("feedtui") {
+            self.max_level
+        } else {
+            Level::WARN
+        };
+        *metadata.level() <= max_level
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(self.next_span.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let line = LogLine {
+            time: Local::now(),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.finish(),
+        };
+
+        let text = format!(
+            "{} {:>5} {}: {}\n",
+            line.time.format("%Y-%m-%dT%H:%M:%S%.3f"),
+            line.level,
+            line.target,
+            line.message
+        );
+        self.file.lock().unwrap().write_line(&text);
+        self.buffer.push(line);
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        format!("{}{}", self.message, self.fields)
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+}
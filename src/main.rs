@@ -1,12 +1,36 @@
+mod api;
 mod app;
+mod briefing;
+mod clipboard;
 mod config;
+mod config_edit;
+mod crash;
 mod creature;
+mod daemon;
+mod digest;
+mod doctor;
 mod event;
 mod feeds;
+mod headless;
+mod ical;
+mod ipc;
+mod logging;
+mod notify;
+mod onboarding;
+mod quiet;
+mod random;
+mod shell;
+mod smtp;
+mod snapshot;
+mod speech;
+mod stats;
+mod translate;
 mod ui;
+mod wizard;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use std::io::IsTerminal;
 use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
@@ -38,17 +62,151 @@ enum Commands {
     Config,
     /// Install the binary to cargo bin directory
     Install,
+    /// Fetch all configured feeds once and print the results (no TUI)
+    Fetch {
+        /// Print results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Fetch once and write a briefing of headlines, portfolio and scores
+    Briefing {
+        /// Output file (.html for HTML, anything else for Markdown); defaults
+        /// to `output` under [briefing]
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        /// Also email it through the [smtp] server
+        #[arg(long)]
+        email: bool,
+    },
+    /// Keep feeds fresh in the background for the dashboard, `ctl` and the HTTP API
+    Daemon,
+    /// Check terminal, config, and API connectivity
+    Doctor,
+    /// Send a command to a running feedtui (refresh [widget], focus <widget>, notify <msg>, quit)
+    Ctl {
+        #[arg(required = true, trailing_var_arg = true)]
+        command: Vec<String>,
+    },
+    /// Fetch once and render one frame of the dashboard to a .txt or .html file
+    Snapshot {
+        /// Output file (.html for colored output, anything else for plain text)
+        #[arg(short, long)]
+        out: PathBuf,
+        /// Width of the rendered frame in columns
+        #[arg(long, default_value_t = 120)]
+        width: u16,
+        /// Height of the rendered frame in rows
+        #[arg(long, default_value_t = 40)]
+        height: u16,
+    },
+    /// Move the creature between machines
+    Creature {
+        #[command(subcommand)]
+        command: CreatureCommand,
+    },
+    /// Add, remove, or list the widgets in the config file
+    Widget {
+        #[command(subcommand)]
+        command: WidgetCommand,
+    },
+    /// Show time spent, items read, and achievements over recent days
+    Stats,
+    /// Work with RSS feeds
+    Rss {
+        #[command(subcommand)]
+        command: RssCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum WidgetCommand {
+    /// Append a widget, e.g. `widget add rss --feed URL --position 1,1`
+    Add {
+        /// Widget type, as in `type = "..."`
+        kind: String,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long)]
+        id: Option<String>,
+        /// Grid cell as row,col; defaults to the first free cell
+        #[arg(long, value_parser = config_edit::parse_position)]
+        position: Option<(usize, usize)>,
+        #[arg(long, default_value_t = 0)]
+        page: usize,
+        /// Feed URL for rss widgets (repeatable)
+        #[arg(long = "feed")]
+        feeds: Vec<String>,
+        /// Symbol for stocks widgets (repeatable)
+        #[arg(long = "symbol")]
+        symbols: Vec<String>,
+        /// League for sports and odds widgets (repeatable)
+        #[arg(long = "league")]
+        leagues: Vec<String>,
+        /// Any other setting as key=value, e.g. `--set max_items=20` (repeatable)
+        #[arg(long = "set")]
+        settings: Vec<String>,
+    },
+    /// Remove the widget with the given id
+    Remove { id: String },
+    /// List configured widgets with their ids
+    List,
+}
+
+#[derive(Subcommand, Debug)]
+enum RssCommand {
+    /// List the feeds a website advertises, to paste into `feeds`
+    Discover { site: String },
+    /// Find the feeds a website advertises and add an rss widget for one
+    Add {
+        /// Website or feed URL
+        site: String,
+        /// Which discovered feed to use, counting the lines of
+        /// `rss discover` from 1; defaults to the first
+        #[arg(long)]
+        pick: Option<usize>,
+        /// Defaults to the feed's own title
+        #[arg(long)]
+        title: Option<String>,
+        /// Grid cell as row,col; defaults to the first free cell
+        #[arg(long, value_parser = config_edit::parse_position)]
+        position: Option<(usize, usize)>,
+        #[arg(long, default_value_t = 0)]
+        page: usize,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum CreatureCommand {
+    /// Write the active creature's save to a file, or stdout
+    Export {
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Add an exported creature to the roster
+    Import {
+        file: PathBuf,
+        /// Make it the active creature
+        #[arg(long)]
+        activate: bool,
+    },
+    /// Merge the roster with the [sync] target, newest creature winning
+    Sync,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
 
+    // Logs go to ~/.feedtui/feedtui.log so they never corrupt the TUI
+    if let Err(e) = logging::init() {
+        eprintln!("Warning: Could not open log file: {}", e);
+    }
+
     // Handle subcommands
     if let Some(command) = args.command {
         match command {
             Commands::Init { force } => {
-                return init_config(force);
+                return init_config(force).await;
             }
             Commands::Config => {
                 return show_config_info();
@@ -56,18 +214,74 @@ async fn main() -> Result<()> {
             Commands::Install => {
                 return show_install_instructions();
             }
+            Commands::Fetch { json } => {
+                let config = load_config(args.config);
+                return headless::run_fetch(&config, json).await;
+            }
+            Commands::Briefing { out, email } => {
+                let config = load_config(args.config);
+                return briefing::run_briefing(&config, out, email).await;
+            }
+            Commands::Stats => {
+                return stats::run_stats();
+            }
+            Commands::Daemon => {
+                let config = load_config(args.config);
+                return daemon::run_daemon(&config).await;
+            }
+            Commands::Doctor => {
+                let path = args.config.unwrap_or_else(config::Config::default_path);
+                return doctor::run_doctor(&path).await;
+            }
+            Commands::Ctl { command } => {
+                let response = ipc::send_command(&command.join(" ")).await?;
+                println!("{}", response);
+                if response.starts_with("error") {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            Commands::Snapshot { out, width, height } => {
+                let config = load_config(args.config);
+                return snapshot::run_snapshot(config, &out, width, height).await;
+            }
+            Commands::Creature { command } => {
+                return run_creature_command(command, args.config).await;
+            }
+            Commands::Widget { command } => {
+                return run_widget_command(command, args.config);
+            }
+            Commands::Rss { command } => {
+                return run_rss_command(command, args.config).await;
+            }
         }
     }
 
-    // Load config from ~/.feedtui/config.toml (cross-platform)
-    let config_path = args.config.unwrap_or_else(|| {
-        dirs::home_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join(".feedtui")
-            .join("config.toml")
-    });
+    // First launch: set up a creature and a dashboard instead of falling
+    // back to the defaults
+    let config_path = args.config.unwrap_or_else(config::Config::default_path);
+    let onboarded = if !config_path.exists() && std::io::stdout().is_terminal() {
+        onboarding::run_onboarding(&config_path).await?
+    } else {
+        None
+    };
+    let mut config = onboarded.unwrap_or_else(|| load_config(Some(config_path)));
+
+    // Apply CLI overrides
+    if let Some(refresh) = args.refresh {
+        config.general.refresh_interval_secs = refresh;
+    }
+
+    // Run the app
+    let mut app = app::App::new(config);
+    app.run().await
+}
+
+/// Load config from the given path, or the default location
+fn load_config(path: Option<PathBuf>) -> config::Config {
+    let config_path = path.unwrap_or_else(config::Config::default_path);
 
-    let mut config = config::Config::load(&config_path).unwrap_or_else(|e| {
+    config::Config::load(&config_path).unwrap_or_else(|e| {
         eprintln!(
             "Warning: Could not load config from {:?}: {}",
             config_path, e
@@ -75,21 +289,122 @@ async fn main() -> Result<()> {
         eprintln!("Using default configuration...");
         eprintln!("Tip: Run 'feedtui init' to create a configuration file.\n");
         config::Config::default()
-    });
+    })
+}
 
-    // Apply CLI overrides
-    if let Some(refresh) = args.refresh {
-        config.general.refresh_interval_secs = refresh;
+fn run_widget_command(command: WidgetCommand, config: Option<PathBuf>) -> Result<()> {
+    let path = config.unwrap_or_else(config::Config::default_path);
+    match command {
+        WidgetCommand::Add {
+            kind,
+            title,
+            id,
+            position,
+            page,
+            feeds,
+            symbols,
+            leagues,
+            settings,
+        } => {
+            let widget = config_edit::NewWidget {
+                kind,
+                title,
+                id,
+                position,
+                page,
+                feeds,
+                symbols,
+                leagues,
+                settings,
+            };
+            let id = config_edit::add_widget(&path, &widget)?;
+            println!("Added widget {} to {}", id, path.display());
+        }
+        WidgetCommand::Remove { id } => {
+            config_edit::remove_widget(&path, &id)?;
+            println!("Removed widget {} from {}", id, path.display());
+        }
+        WidgetCommand::List => config_edit::list_widgets(&config::Config::load(&path)?),
     }
+    Ok(())
+}
 
-    // Run the app
-    let mut app = app::App::new(config);
-    app.run().await
+async fn run_rss_command(command: RssCommand, config: Option<PathBuf>) -> Result<()> {
+    match command {
+        RssCommand::Discover { site } => {
+            for feed in feeds::rss::discover(&site, feeds::http::timeout(None)).await? {
+                match feed.title {
+                    Some(title) => println!("{}\t{}", feed.url, title),
+                    None => println!("{}", feed.url),
+                }
+            }
+        }
+        RssCommand::Add {
+            site,
+            pick,
+            title,
+            position,
+            page,
+        } => {
+            let path = config.unwrap_or_else(config::Config::default_path);
+            let found = feeds::rss::discover(&site, feeds::http::timeout(None)).await?;
+            let widget = config_edit::rss_widget(&found, pick, title, position, page)?;
+            let id = config_edit::add_widget(&path, &widget)?;
+            println!(
+                "Added widget {} for {} to {}",
+                id,
+                widget.feeds[0],
+                path.display()
+            );
+            if found.len() > 1 && pick.is_none() {
+                println!(
+                    "{} other feeds found; see `feedtui rss discover {}` and --pick",
+                    found.len() - 1,
+                    site
+                );
+            }
+        }
+    }
+    Ok(())
 }
 
-fn init_config(force: bool) -> Result<()> {
-    use std::io::{self, Write};
+async fn run_creature_command(command: CreatureCommand, config: Option<PathBuf>) -> Result<()> {
+    use creature::persistence::{
+        active_creature_path, default_roster_dir, import_creature, set_active_creature,
+    };
+
+    let dir = default_roster_dir();
+    match command {
+        CreatureCommand::Export { out } => {
+            let json = std::fs::read_to_string(active_creature_path(&dir)?)?;
+            match out {
+                Some(out) => {
+                    std::fs::write(&out, json)?;
+                    eprintln!("Exported to {}", out.display());
+                }
+                None => println!("{}", json),
+            }
+        }
+        CreatureCommand::Import { file, activate } => {
+            let path = import_creature(&file, &dir)?;
+            if activate {
+                set_active_creature(&path)?;
+            }
+            println!("Imported as {}", path.display());
+        }
+        CreatureCommand::Sync => {
+            let config = load_config(config);
+            let summary = creature::sync::sync_roster(&config.sync, &dir).await?;
+            println!(
+                "Synced: {} creature(s) pulled, {} pushed",
+                summary.pulled, summary.pushed
+            );
+        }
+    }
+    Ok(())
+}
 
+async fn init_config(force: bool) -> Result<()> {
     let config_dir = dirs::home_dir()
         .unwrap_or_else(|| PathBuf::from("."))
         .join(".feedtui");
@@ -103,163 +418,21 @@ fn init_config(force: bool) -> Result<()> {
         return Ok(());
     }
 
-    println!("=== feedtui Configuration Wizard ===\n");
-
     // Create config directory if it doesn't exist
     std::fs::create_dir_all(&config_dir)?;
 
-    // Prompt for refresh interval
-    print!("Refresh interval in seconds [60]: ");
-    io::stdout().flush()?;
-    let mut refresh_input = String::new();
-    io::stdin().read_line(&mut refresh_input)?;
-    let refresh_interval = refresh_input.trim().parse::<u64>().unwrap_or(60);
-
-    // Prompt for theme
-    print!("Theme (dark/light) [dark]: ");
-    io::stdout().flush()?;
-    let mut theme_input = String::new();
-    io::stdin().read_line(&mut theme_input)?;
-    let theme = theme_input.trim();
-    let theme = if theme.is_empty() { "dark" } else { theme };
-
-    // Ask about widgets
-    println!("\n=== Widget Configuration ===");
-    println!("Which widgets would you like to enable?\n");
-
-    let enable_creature = prompt_yes_no("Enable Tui creature companion?", true)?;
-    let enable_hackernews = prompt_yes_no("Enable Hacker News?", true)?;
-    let enable_stocks = prompt_yes_no("Enable stock ticker?", true)?;
-    let enable_rss = prompt_yes_no("Enable RSS feeds?", true)?;
-    let enable_sports = prompt_yes_no("Enable sports scores?", false)?;
-    let enable_github = prompt_yes_no("Enable GitHub dashboard?", false)?;
-
-    // Build config content
-    let mut config_content = format!(
-        "[general]\nrefresh_interval_secs = {}\ntheme = \"{}\"\n\n",
-        refresh_interval, theme
-    );
-
-    let mut row = 0;
-    let mut col = 0;
-
-    if enable_creature {
-        config_content.push_str(&format!(
-            "[[widgets]]\ntype = \"creature\"\ntitle = \"Tui\"\nshow_on_startup = true\nposition = {{ row = {}, col = {} }}\n\n",
-            row, col
-        ));
-        col += 1;
-    }
-
-    if enable_hackernews {
-        config_content.push_str(&format!(
-            "[[widgets]]\ntype = \"hackernews\"\ntitle = \"Hacker News\"\nstory_count = 10\nstory_type = \"top\"\nposition = {{ row = {}, col = {} }}\n\n",
-            row, col
-        ));
-        col += 1;
-    }
-
-    if enable_stocks {
-        print!("\nEnter stock symbols (comma-separated) [AAPL,GOOGL,MSFT]: ");
-        io::stdout().flush()?;
-        let mut stocks_input = String::new();
-        io::stdin().read_line(&mut stocks_input)?;
-        let stocks = stocks_input.trim();
-        let stocks = if stocks.is_empty() {
-            "AAPL\", \"GOOGL\", \"MSFT"
-        } else {
-            stocks
-        };
-        let stocks_array = stocks
-            .split(',')
-            .map(|s| format!("\"{}\"", s.trim()))
-            .collect::<Vec<_>>()
-            .join(", ");
-
-        if col >= 3 {
-            row += 1;
-            col = 0;
-        }
-        config_content.push_str(&format!(
-            "[[widgets]]\ntype = \"stocks\"\ntitle = \"Portfolio\"\nsymbols = [{}]\nposition = {{ row = {}, col = {} }}\n\n",
-            stocks_array, row, col
-        ));
-        col += 1;
-    }
-
-    if enable_rss {
-        if col >= 3 {
-            row += 1;
-            col = 0;
-        }
-        config_content.push_str(&format!(
-            "[[widgets]]\ntype = \"rss\"\ntitle = \"Tech News\"\nfeeds = [\n  \"https://feeds.arstechnica.com/arstechnica/technology-lab\"\n]\nmax_items = 10\nposition = {{ row = {}, col = {} }}\n\n",
-            row, col
-        ));
-        col += 1;
-    }
-
-    if enable_sports {
-        if col >= 3 {
-            row += 1;
-            col = 0;
-        }
-        config_content.push_str(&format!(
-            "[[widgets]]\ntype = \"sports\"\ntitle = \"Sports\"\nleagues = [\"nba\", \"nfl\"]\nposition = {{ row = {}, col = {} }}\n\n",
-            row, col
-        ));
-        col += 1;
-    }
-
-    if enable_github {
-        println!("\n=== GitHub Configuration ===");
-        print!("GitHub username: ");
-        io::stdout().flush()?;
-        let mut github_user = String::new();
-        io::stdin().read_line(&mut github_user)?;
-        let github_user = github_user.trim();
-
-        if !github_user.is_empty() {
-            if col >= 3 {
-                row += 1;
-                col = 0;
-            }
-            config_content.push_str(&format!(
-                "[[widgets]]\ntype = \"github\"\ntitle = \"GitHub Dashboard\"\ntoken = \"${{GITHUB_TOKEN}}\"\nusername = \"{}\"\nshow_notifications = true\nshow_pull_requests = true\nshow_commits = true\nmax_notifications = 20\nmax_pull_requests = 10\nmax_commits = 10\nposition = {{ row = {}, col = {} }}\n\n",
-                github_user, row, col
-            ));
-        }
+    if !wizard::run_wizard(&config_path).await? {
+        println!("Setup cancelled; nothing was written.");
+        return Ok(());
     }
 
-    // Write config file
-    std::fs::write(&config_path, config_content)?;
-
-    println!("\n✓ Configuration saved to: {}", config_path.display());
+    println!("✓ Configuration saved to: {}", config_path.display());
     println!("\nYou can edit this file directly or run 'feedtui init --force' to reconfigure.");
     println!("\nRun 'feedtui' to start the dashboard!");
 
     Ok(())
 }
 
-fn prompt_yes_no(prompt: &str, default: bool) -> Result<bool> {
-    use std::io::{self, Write};
-
-    let default_str = if default { "Y/n" } else { "y/N" };
-    print!("{} [{}]: ", prompt, default_str);
-    io::stdout().flush()?;
-
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    let input = input.trim().to_lowercase();
-
-    Ok(match input.as_str() {
-        "" => default,
-        "y" | "yes" => true,
-        "n" | "no" => false,
-        _ => default,
-    })
-}
-
 fn show_config_info() -> Result<()> {
     let config_dir = dirs::home_dir()
         .unwrap_or_else(|| PathBuf::from("."))
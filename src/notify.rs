@@ -0,0 +1,118 @@
+use crate::config::{AlertKind, AlertWebhookConfig, Config, TelegramConfig, WebhookFormat};
+use crate::feeds::http;
+use anyhow::Result;
+use serde_json::Value;
+
+const TELEGRAM_API: &str = "https://api.telegram.org";
+
+/// Sends alerts off the machine, to the channels set up in the config
+#[derive(Debug, Clone, Default)]
+pub struct Notifier {
+    telegram: Option<TelegramConfig>,
+    webhooks: Vec<AlertWebhookConfig>,
+}
+
+impl Notifier {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            telegram: config.telegram.clone(),
+            webhooks: config.alert_webhooks.clone(),
+        }
+    }
+
+    /// Deliver `message` in the background; failures are only logged, as
+    /// the alert has already shown on screen
+    pub fn send(&self, kind: AlertKind, message: &str) {
+        let text = format!("feedtui: {}", message);
+        if let Some(telegram) = self.telegram.clone() {
+            let text = text.clone();
+            tokio::spawn(async move {
+                if let Err(e) = send_telegram(&telegram, &text).await {
+                    tracing::warn!("Could not send Telegram alert: {:#}", e);
+                }
+            });
+        }
+        for webhook in &self.webhooks {
+            if !webhook.alerts.is_empty() && !webhook.alerts.contains(&kind) {
+                continue;
+            }
+            let webhook = webhook.clone();
+            let text = text.clone();
+            tokio::spawn(async move {
+                if let Err(e) = send_webhook(&webhook, &text).await {
+                    tracing::warn!(
+                        "Could not post alert to {:?} webhook: {:#}",
+                        webhook.format,
+                        e
+                    );
+                }
+            });
+        }
+    }
+}
+
+async fn send_telegram(config: &TelegramConfig, text: &str) -> Result<()> {
+    let url = format!("{}/bot{}/sendMessage", TELEGRAM_API, config.bot_token);
+    let body = serde_json::json!({
+        "chat_id": config.chat_id,
+        "text": text,
+    });
+    // The token is part of the URL, so keep it out of error messages
+    http::client(http::timeout(None))
+        .post(&url)
+        .json(&body)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| e.without_url())?;
+    Ok(())
+}
+
+async fn send_webhook(config: &AlertWebhookConfig, text: &str) -> Result<()> {
+    // Webhook URLs are secrets too
+    http::client(http::timeout(None))
+        .post(&config.url)
+        .json(&webhook_payload(config, text))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| e.without_url())?;
+    Ok(())
+}
+
+fn webhook_payload(config: &AlertWebhookConfig, text: &str) -> Value {
+    match config.format {
+        WebhookFormat::Discord => serde_json::json!({ "content": text }),
+        WebhookFormat::Slack | WebhookFormat::Mattermost => {
+            let mut payload = serde_json::json!({ "text": text });
+            if let Some(ref channel) = config.channel {
+                payload["channel"] = Value::String(channel.clone());
+            }
+            payload
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payloads_match_each_service() {
+        let webhook = |format: &str| -> AlertWebhookConfig {
+            toml::from_str(&format!(
+                "url = \"https://example.com/hook\"\nformat = \"{}\"\nchannel = \"alerts\"",
+                format
+            ))
+            .unwrap()
+        };
+        assert_eq!(
+            webhook_payload(&webhook("discord"), "hi"),
+            serde_json::json!({ "content": "hi" })
+        );
+        assert_eq!(
+            webhook_payload(&webhook("mattermost"), "hi"),
+            serde_json::json!({ "text": "hi", "channel": "alerts" })
+        );
+    }
+}
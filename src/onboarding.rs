@@ -0,0 +1,479 @@
+use crate::app::App;
+use crate::config::{Config, WidgetConfig};
+use crate::creature::art::{dress, get_creature_art};
+use crate::creature::persistence::{
+    default_roster_dir, new_creature_path, save_creature, set_active_creature,
+};
+use crate::creature::{Creature, CreatureColor, CreatureMood, CreatureSpecies};
+use crate::ui::layout::{self, Placement};
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use serde::Deserialize;
+use std::path::Path;
+use std::time::Duration;
+
+/// How often the creature preview moves
+const ANIMATION_STEP: Duration = Duration::from_millis(500);
+const DEFAULT_NAME: &str = "Tui";
+
+/// Starter dashboards: name, description, and their `[[widgets]]`
+const TEMPLATES: [(&str, &str, &str); 4] = [
+    (
+        "Starter",
+        "A bit of everything: news, stocks and scores",
+        r#"
+[[widgets]]
+type = "creature"
+title = "Tui"
+show_on_startup = true
+position = { row = 0, col = 0 }
+
+[[widgets]]
+type = "hackernews"
+title = "Hacker News"
+story_count = 10
+story_type = "top"
+position = { row = 0, col = 1 }
+
+[[widgets]]
+type = "stocks"
+title = "Stocks"
+symbols = ["AAPL", "GOOGL", "MSFT", "NVDA"]
+position = { row = 1, col = 0 }
+
+[[widgets]]
+type = "rss"
+title = "Tech News"
+feeds = ["https://feeds.arstechnica.com/arstechnica/technology-lab"]
+max_items = 10
+position = { row = 1, col = 1 }
+"#,
+    ),
+    (
+        "Developer",
+        "Hacker News, Lobsters and programming blogs",
+        r#"
+[[widgets]]
+type = "creature"
+title = "Tui"
+show_on_startup = true
+position = { row = 0, col = 0 }
+
+[[widgets]]
+type = "hackernews"
+title = "Hacker News"
+story_count = 15
+story_type = "top"
+position = { row = 0, col = 1 }
+
+[[widgets]]
+type = "rss"
+title = "Lobsters"
+feeds = ["https://lobste.rs/rss"]
+max_items = 15
+position = { row = 1, col = 0 }
+
+[[widgets]]
+type = "rss"
+title = "Blogs"
+feeds = ["https://blog.rust-lang.org/feed.xml", "https://github.blog/feed/"]
+max_items = 15
+position = { row = 1, col = 1 }
+"#,
+    ),
+    (
+        "Markets",
+        "Stocks, business headlines and a daily digest",
+        r#"
+[[widgets]]
+type = "creature"
+title = "Tui"
+show_on_startup = true
+position = { row = 0, col = 0 }
+
+[[widgets]]
+type = "stocks"
+title = "Watchlist"
+symbols = ["SPY", "QQQ", "AAPL", "MSFT", "NVDA", "AMZN"]
+position = { row = 0, col = 1 }
+
+[[widgets]]
+type = "rss"
+title = "Markets"
+feeds = ["https://feeds.content.dowjones.io/public/rss/mw_topstories"]
+max_items = 15
+position = { row = 1, col = 0 }
+
+[[widgets]]
+type = "digest"
+title = "Digest"
+big_move_percent = 2.0
+position = { row = 1, col = 1 }
+"#,
+    ),
+    (
+        "Sports fan",
+        "Live scores and sports news",
+        r#"
+[[widgets]]
+type = "creature"
+title = "Tui"
+show_on_startup = true
+position = { row = 0, col = 0 }
+
+[[widgets]]
+type = "sports"
+title = "Scores"
+leagues = ["nba", "nfl", "mlb", "nhl"]
+position = { row = 0, col = 1 }
+
+[[widgets]]
+type = "rss"
+title = "Sports News"
+feeds = ["https://www.espn.com/espn/rss/news"]
+max_items = 15
+position = { row = 1, col = 0 }
+"#,
+    ),
+];
+
+#[derive(Deserialize)]
+struct Template {
+    widgets: Vec<WidgetConfig>,
+}
+
+fn template_widgets(index: usize) -> Vec<WidgetConfig> {
+    toml::from_str::<Template>(TEMPLATES[index].2)
+        .expect("built-in templates are valid")
+        .widgets
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Creature,
+    Name,
+    Layout,
+}
+
+/// First launch: hatch a creature and pick a starter dashboard
+struct Onboarding {
+    step: Step,
+    species: Vec<CreatureSpecies>,
+    colors: Vec<CreatureColor>,
+    species_idx: usize,
+    color_idx: usize,
+    name: String,
+    template_idx: usize,
+    frame: usize,
+}
+
+/// Run when there is no config yet. Writes the config and the creature's
+/// save, then returns the config to start with; `None` if skipped with Esc.
+pub async fn run_onboarding(config_path: &Path) -> Result<Option<Config>> {
+    let mut onboarding = Onboarding::new();
+    let mut terminal = App::setup_terminal()?;
+
+    let finished = loop {
+        terminal.draw(|frame| onboarding.render(frame))?;
+        if !event::poll(ANIMATION_STEP)? {
+            onboarding.frame = onboarding.frame.wrapping_add(1);
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        if let Some(finished) = onboarding.handle_key(key) {
+            break finished;
+        }
+    };
+    App::restore_terminal(&mut terminal)?;
+    if !finished {
+        return Ok(None);
+    }
+
+    let creature = onboarding.creature();
+    let mut widgets = template_widgets(onboarding.template_idx);
+    for widget in &mut widgets {
+        if let WidgetConfig::Creature(cfg) = widget {
+            cfg.title = creature.name.clone();
+        }
+    }
+    let mut config = Config {
+        widgets,
+        ..Config::default()
+    };
+    if let Some(dir) = config_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(config_path, toml::to_string_pretty(&config)?)?;
+    config.assign_widget_ids();
+
+    let roster_dir = default_roster_dir();
+    let creature_path = new_creature_path(&roster_dir, &creature.name);
+    save_creature(&creature, &creature_path)?;
+    set_active_creature(&creature_path)?;
+    Ok(Some(config))
+}
+
+impl Onboarding {
+    fn new() -> Self {
+        let colors = CreatureColor::all();
+        let color_idx = colors
+            .iter()
+            .position(|c| *c == CreatureColor::Cyan)
+            .unwrap_or(0);
+        Self {
+            step: Step::Creature,
+            species: CreatureSpecies::all(),
+            colors,
+            species_idx: 0,
+            color_idx,
+            name: String::new(),
+            template_idx: 0,
+            frame: 0,
+        }
+    }
+
+    fn creature(&self) -> Creature {
+        let name = match self.name.trim() {
+            "" => DEFAULT_NAME.to_string(),
+            name => name.to_string(),
+        };
+        let mut creature = Creature::new(name, self.species[self.species_idx].clone());
+        creature.appearance.primary_color = self.colors[self.color_idx].clone();
+        creature
+    }
+
+    /// `Some(true)` once done, `Some(false)` if skipped
+    fn handle_key(&mut self, key: KeyEvent) -> Option<bool> {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Some(false);
+        }
+        let wrap = |idx: usize, len: usize, forward: bool| {
+            if forward {
+                (idx + 1) % len
+            } else {
+                (idx + len - 1) % len
+            }
+        };
+
+        match (self.step, key.code) {
+            (Step::Creature, KeyCode::Esc) => return Some(false),
+            (Step::Name, KeyCode::Esc) => self.step = Step::Creature,
+            (Step::Layout, KeyCode::Esc) => self.step = Step::Name,
+
+            (Step::Creature, KeyCode::Up | KeyCode::Char('k')) => {
+                self.species_idx = wrap(self.species_idx, self.species.len(), false)
+            }
+            (Step::Creature, KeyCode::Down | KeyCode::Char('j')) => {
+                self.species_idx = wrap(self.species_idx, self.species.len(), true)
+            }
+            (Step::Creature, KeyCode::Left | KeyCode::Char('h')) => {
+                self.color_idx = wrap(self.color_idx, self.colors.len(), false)
+            }
+            (Step::Creature, KeyCode::Right | KeyCode::Char('l')) => {
+                self.color_idx = wrap(self.color_idx, self.colors.len(), true)
+            }
+            (Step::Creature, KeyCode::Enter) => self.step = Step::Name,
+
+            (Step::Name, KeyCode::Enter) => self.step = Step::Layout,
+            (Step::Name, KeyCode::Backspace) => {
+                self.name.pop();
+            }
+            (Step::Name, KeyCode::Char(c)) => self.name.push(c),
+
+            (Step::Layout, KeyCode::Up | KeyCode::Char('k')) => {
+                self.template_idx = wrap(self.template_idx, TEMPLATES.len(), false)
+            }
+            (Step::Layout, KeyCode::Down | KeyCode::Char('j')) => {
+                self.template_idx = wrap(self.template_idx, TEMPLATES.len(), true)
+            }
+            (Step::Layout, KeyCode::Enter) => return Some(true),
+            _ => {}
+        }
+        None
+    }
+
+    fn render(&self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split(frame.area());
+
+        let (heading, help) = match self.step {
+            Step::Creature => (
+                "Pick a companion",
+                "↑↓ species | ←→ color | Enter next | Esc skip setup",
+            ),
+            Step::Name => ("Give it a name", "Enter next | Esc back"),
+            Step::Layout => (
+                "Choose a starter dashboard",
+                "↑↓ choose | Enter start | Esc back",
+            ),
+        };
+        let header = Line::from(vec![
+            Span::styled(
+                " Welcome to feedtui! ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(heading),
+        ]);
+        frame.render_widget(Paragraph::new(header), chunks[0]);
+        frame.render_widget(
+            Paragraph::new(Line::styled(help, Style::default().fg(Color::DarkGray))),
+            chunks[2],
+        );
+
+        match self.step {
+            Step::Creature | Step::Name => self.render_creature(frame, chunks[1]),
+            Step::Layout => self.render_templates(frame, chunks[1]),
+        }
+    }
+
+    fn render_creature(&self, frame: &mut Frame, area: Rect) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(24), Constraint::Min(20)])
+            .split(area);
+
+        let items: Vec<ListItem> = self
+            .species
+            .iter()
+            .map(|species| ListItem::new(species.name().to_string()))
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().title(" Species ").borders(Borders::ALL))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let mut state = ListState::default().with_selected(Some(self.species_idx));
+        frame.render_stateful_widget(list, columns[0], &mut state);
+
+        let creature = self.creature();
+        let mut lines = vec![Line::raw("")];
+        lines.extend(dress(
+            get_creature_art(&creature.species, &CreatureMood::Happy, None, self.frame),
+            &creature.appearance,
+        ));
+        lines.push(Line::raw(""));
+        let color = &self.colors[self.color_idx];
+        lines.push(Line::from(vec![
+            Span::raw("◀ "),
+            Span::styled(
+                format!("{:?}", color),
+                Style::default().fg(color.to_ratatui_color()),
+            ),
+            Span::raw(" ▶"),
+        ]));
+        lines.push(Line::raw(""));
+        let name = if self.step == Step::Name {
+            Line::from(vec![
+                Span::raw("Name: "),
+                Span::styled(
+                    format!("{}▏", self.name),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ])
+        } else {
+            Line::styled(creature.name.clone(), Style::default().fg(Color::White))
+        };
+        lines.push(name);
+        if self.step == Step::Name && self.name.is_empty() {
+            lines.push(Line::styled(
+                format!("(blank for {})", DEFAULT_NAME),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+
+        let preview = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(preview, columns[1]);
+    }
+
+    fn render_templates(&self, frame: &mut Frame, area: Rect) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(32), Constraint::Min(20)])
+            .split(area);
+
+        let items: Vec<ListItem> = TEMPLATES
+            .iter()
+            .map(|(name, description, _)| {
+                ListItem::new(vec![
+                    Line::styled(*name, Style::default().add_modifier(Modifier::BOLD)),
+                    Line::styled(*description, Style::default().fg(Color::DarkGray)),
+                ])
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().title(" Dashboards ").borders(Borders::ALL))
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        let mut state = ListState::default().with_selected(Some(self.template_idx));
+        frame.render_stateful_widget(list, columns[0], &mut state);
+
+        let widgets = template_widgets(self.template_idx);
+        let placements: Vec<Placement> = widgets
+            .iter()
+            .enumerate()
+            .map(|(idx, widget)| Placement {
+                idx,
+                row: widget.position().row,
+                col: widget.position().col,
+                priority: 0,
+            })
+            .collect();
+        for (idx, rect) in layout::arrange(columns[1], &placements, 0, 0) {
+            let widget = &widgets[idx];
+            let body = Paragraph::new(widget.kind())
+                .style(Style::default().fg(Color::DarkGray))
+                .wrap(Wrap { trim: true })
+                .block(
+                    Block::default()
+                        .title(format!(" {} ", widget.title()))
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Cyan)),
+                );
+            frame.render_widget(body, rect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_template_has_a_creature() {
+        for (index, (name, _, _)) in TEMPLATES.iter().enumerate() {
+            let widgets = template_widgets(index);
+            assert!(
+                widgets
+                    .iter()
+                    .any(|w| matches!(w, WidgetConfig::Creature(_))),
+                "{} has no creature widget",
+                name
+            );
+        }
+    }
+}
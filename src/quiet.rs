@@ -0,0 +1,108 @@
+use crate::config::{QuietHoursConfig, QuietRefresh};
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Weekday};
+
+/// Refresh interval multiplier for `refresh = "slow"`
+pub const SLOW_REFRESH: f64 = 4.0;
+
+/// The `[quiet_hours]` schedule
+#[derive(Debug, Clone, Default)]
+pub struct QuietHours {
+    ranges: Vec<(NaiveTime, NaiveTime)>,
+    days: Vec<Weekday>,
+    pub refresh: QuietRefresh,
+}
+
+impl QuietHours {
+    pub fn new(config: &QuietHoursConfig) -> Self {
+        let ranges = config
+            .hours
+            .iter()
+            .filter_map(|range| {
+                let parsed = parse_range(range);
+                if parsed.is_none() {
+                    tracing::warn!("Ignoring quiet hours {:?}; expected HH:MM-HH:MM", range);
+                }
+                parsed
+            })
+            .collect();
+        Self {
+            ranges,
+            days: config.days.clone(),
+            refresh: config.refresh,
+        }
+    }
+
+    /// Whether `now` is in quiet hours. A range that runs past midnight
+    /// counts as part of the day it started on.
+    pub fn contains(&self, now: NaiveDateTime) -> bool {
+        let time = now.time();
+        self.ranges.iter().any(|&(start, end)| {
+            if !in_range(time, start, end) {
+                return false;
+            }
+            let started_on = if start > end && time < end {
+                now.weekday().pred()
+            } else {
+                now.weekday()
+            };
+            self.days.is_empty() || self.days.contains(&started_on)
+        })
+    }
+}
+
+/// `22:00-07:00` into its start and end
+pub fn parse_range(range: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (start, end) = range.split_once('-')?;
+    let parse = |time: &str| NaiveTime::parse_from_str(time.trim(), "%H:%M").ok();
+    Some((parse(start)?, parse(end)?))
+}
+
+/// Whether `time` falls between `start` and `end`, which may wrap past
+/// midnight
+pub fn in_range(time: NaiveTime, start: NaiveTime, end: NaiveTime) -> bool {
+    if start <= end {
+        start <= time && time < end
+    } else {
+        time >= start || time < end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quiet_hours_can_wrap_past_midnight() {
+        let (start, end) = parse_range("22:00 - 07:00").unwrap();
+        let at = |h| NaiveTime::from_hms_opt(h, 30, 0).unwrap();
+        assert!(in_range(at(23), start, end));
+        assert!(in_range(at(6), start, end));
+        assert!(!in_range(at(12), start, end));
+
+        let (start, end) = parse_range("13:00-14:00").unwrap();
+        assert!(in_range(at(13), start, end));
+        assert!(!in_range(at(14), start, end));
+        assert!(parse_range("late").is_none());
+    }
+
+    #[test]
+    fn nights_belong_to_the_day_they_start() {
+        let config: QuietHoursConfig =
+            toml::from_str("hours = [\"22:00-07:00\", \"nonsense\"]\ndays = [\"fri\", \"sat\"]")
+                .unwrap();
+        let quiet = QuietHours::new(&config);
+        // 2026-03-06 is a Friday
+        let at = |day, hour| {
+            chrono::NaiveDate::from_ymd_opt(2026, 3, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        assert!(quiet.contains(at(6, 23)));
+        assert!(quiet.contains(at(7, 6)));
+        assert!(quiet.contains(at(8, 6)));
+        assert!(!quiet.contains(at(8, 23)));
+        assert!(!quiet.contains(at(6, 6)));
+        assert!(!quiet.contains(at(7, 12)));
+    }
+}
@@ -0,0 +1,18 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A random number in `[0, 1)`. Not cryptographically secure; good enough
+/// for jitter and games.
+pub fn unit() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// A random number in `[-1, 1)`
+pub fn signed_unit() -> f64 {
+    unit() * 2.0 - 1.0
+}
@@ -0,0 +1,149 @@
+use std::process::Command;
+
+/// A command line from the config, run through the platform's shell so
+/// users can write arguments and pipes the way they would at a prompt
+pub fn command(command_line: &str) -> Command {
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.arg("/C");
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.arg("-c");
+        command
+    };
+    command.arg(command_line);
+    command
+}
+
+/// `arg` quoted so the shell passes it on as a single word. cmd has no
+/// quoting that stops `%VAR%` expansion, so URLs are percent-encoded there
+pub fn quote(arg: &str) -> String {
+    if cfg!(windows) {
+        format!("\"{}\"", arg.replace('%', "%25").replace('"', "%22"))
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Fill `{url}` in an opener command line with `url`, or add it at the end
+pub fn with_url(template: &str, url: &str) -> String {
+    if template.contains("{url}") {
+        template.replace("{url}", &quote(url))
+    } else {
+        format!("{} {}", template, quote(url))
+    }
+}
+
+/// An opener command for `url`. On Windows it is run directly rather than
+/// through cmd, with the URL as an argument of its own.
+#[cfg(windows)]
+pub fn opener(template: &str, url: &str) -> Command {
+    let mut args = opener_args(template, url).into_iter();
+    let mut command = Command::new(args.next().unwrap_or_default());
+    command.args(args);
+    command
+}
+
+#[cfg(not(windows))]
+pub fn opener(template: &str, url: &str) -> Command {
+    command(&with_url(template, url))
+}
+
+/// `template` split into words, honouring double quotes, with `{url}`
+/// filled in or `url` added as the last word
+#[cfg(any(windows, test))]
+fn opener_args(template: &str, url: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quoted = false;
+    for c in template.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                word.get_or_insert_with(String::new);
+            }
+            c if c.is_whitespace() && !quoted => words.extend(word.take()),
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    words.extend(word);
+
+    if words.iter().any(|word| word.contains("{url}")) {
+        for word in &mut words {
+            *word = word.replace("{url}", url);
+        }
+    } else {
+        words.push(url.to_string());
+    }
+    words
+}
+
+/// Terminal multiplexer feedtui is running in, which can open panes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Multiplexer {
+    Tmux,
+    Wezterm,
+}
+
+impl Multiplexer {
+    pub fn detect() -> Option<Self> {
+        if std::env::var_os("TMUX").is_some() {
+            Some(Self::Tmux)
+        } else if std::env::var_os("WEZTERM_PANE").is_some() {
+            Some(Self::Wezterm)
+        } else {
+            None
+        }
+    }
+
+    /// A command line running `command_line` in a new pane to the right
+    pub fn split(self, command_line: &str) -> String {
+        match self {
+            Self::Tmux => format!("tmux split-window -h {}", quote(command_line)),
+            Self::Wezterm => format!(
+                "wezterm cli split-pane --right -- sh -c {}",
+                quote(command_line)
+            ),
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn urls_are_quoted_into_the_template() {
+        assert_eq!(
+            with_url("firefox --new-tab {url}", "https://x.com/?a=1&b=2"),
+            "firefox --new-tab 'https://x.com/?a=1&b=2'"
+        );
+        assert_eq!(
+            with_url("mpv", "https://x.com/it's"),
+            "mpv 'https://x.com/it'\\''s'"
+        );
+    }
+
+    #[test]
+    fn windows_openers_get_the_url_as_its_own_argument() {
+        let url = "https://x.com/?q=%PATH%&a=\"b\"";
+        assert_eq!(
+            opener_args(r#""C:\Program Files\Firefox\firefox.exe" -url {url}"#, url),
+            [r"C:\Program Files\Firefox\firefox.exe", "-url", url]
+        );
+        assert_eq!(opener_args("mpv  --fs", url), ["mpv", "--fs", url]);
+    }
+
+    #[test]
+    fn panes_run_the_whole_command_line() {
+        let command = with_url("w3m {url}", "https://x.com/");
+        assert_eq!(
+            Multiplexer::Tmux.split(&command),
+            r"tmux split-window -h 'w3m '\''https://x.com/'\'''"
+        );
+        assert!(Multiplexer::Wezterm
+            .split(&command)
+            .starts_with("wezterm cli split-pane --right -- sh -c 'w3m "));
+    }
+}
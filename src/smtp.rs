@@ -0,0 +1,243 @@
+use crate::config::{SmtpConfig, SmtpSecurity};
+use anyhow::{anyhow, bail, Context, Result};
+use base64::Engine;
+use chrono::Local;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_native_tls::{native_tls, TlsConnector};
+
+/// Longest a whole delivery may take
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A message with plain-text and HTML versions of the same content
+pub struct Email {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+/// Deliver `email` to everyone in `to` through the configured server
+pub async fn send(config: &SmtpConfig, email: &Email) -> Result<()> {
+    if config.to.is_empty() {
+        bail!("no recipients in [smtp] to");
+    }
+    let message = build_message(config, email);
+    tokio::time::timeout(TIMEOUT, deliver(config, &message))
+        .await
+        .map_err(|_| anyhow!("timed out talking to {}", config.host))?
+        .with_context(|| format!("sending mail through {}", config.host))
+}
+
+async fn deliver(config: &SmtpConfig, message: &str) -> Result<()> {
+    let tcp = TcpStream::connect((config.host.as_str(), config.port())).await?;
+    match config.security {
+        SmtpSecurity::Tls => {
+            let tls = connect_tls(&config.host, tcp).await?;
+            let mut session = Session::new(tls);
+            session.reply(220).await?;
+            session.ehlo().await?;
+            session.transaction(config, message).await
+        }
+        SmtpSecurity::Starttls => {
+            let mut session = Session::new(tcp);
+            session.reply(220).await?;
+            session.ehlo().await?;
+            session.command("STARTTLS", 220).await?;
+            let tls = connect_tls(&config.host, session.into_inner()).await?;
+            let mut session = Session::new(tls);
+            session.ehlo().await?;
+            session.transaction(config, message).await
+        }
+        SmtpSecurity::None => {
+            let mut session = Session::new(tcp);
+            session.reply(220).await?;
+            session.ehlo().await?;
+            session.transaction(config, message).await
+        }
+    }
+}
+
+async fn connect_tls(host: &str, tcp: TcpStream) -> Result<tokio_native_tls::TlsStream<TcpStream>> {
+    let connector = TlsConnector::from(native_tls::TlsConnector::new()?);
+    Ok(connector.connect(host, tcp).await?)
+}
+
+struct Session<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Session<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+        }
+    }
+
+    fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    /// Read a (possibly multi-line) reply, failing unless it has `code`
+    async fn reply(&mut self, code: u16) -> Result<String> {
+        let mut text = String::new();
+        loop {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await? == 0 {
+                bail!("connection closed by the server");
+            }
+            let line = line.trim_end();
+            text.push_str(line.get(4..).unwrap_or_default());
+            text.push('\n');
+            // "250-..." continues the reply, "250 ..." ends it
+            if line.as_bytes().get(3) != Some(&b'-') {
+                let got: u16 = line.get(..3).and_then(|c| c.parse().ok()).unwrap_or(0);
+                if got != code {
+                    bail!("server replied {}", line);
+                }
+                return Ok(text);
+            }
+        }
+    }
+
+    async fn command(&mut self, command: &str, code: u16) -> Result<String> {
+        let stream = self.stream.get_mut();
+        stream.write_all(command.as_bytes()).await?;
+        stream.write_all(b"\r\n").await?;
+        stream.flush().await?;
+        self.reply(code).await
+    }
+
+    async fn ehlo(&mut self) -> Result<()> {
+        self.command("EHLO feedtui", 250).await.map(|_| ())
+    }
+
+    async fn transaction(&mut self, config: &SmtpConfig, message: &str) -> Result<()> {
+        if let Some(ref username) = config.username {
+            let password = config.password.as_deref().unwrap_or_default();
+            let token = base64::engine::general_purpose::STANDARD
+                .encode(format!("\0{}\0{}", username, password));
+            self.command(&format!("AUTH PLAIN {}", token), 235)
+                .await
+                .context("logging in")?;
+        }
+        self.command(&format!("MAIL FROM:<{}>", address(&config.from)), 250)
+            .await?;
+        for to in &config.to {
+            self.command(&format!("RCPT TO:<{}>", address(to)), 250)
+                .await
+                .with_context(|| format!("recipient {}", to))?;
+        }
+        self.command("DATA", 354).await?;
+        self.command(&format!("{}\r\n.", dot_stuff(message)), 250)
+            .await?;
+        // The message is accepted; a failed goodbye changes nothing
+        let _ = self.command("QUIT", 221).await;
+        Ok(())
+    }
+}
+
+/// The bare address of `Name <user@host>`
+fn address(mailbox: &str) -> &str {
+    match (mailbox.rfind('<'), mailbox.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &mailbox[start + 1..end],
+        _ => mailbox.trim(),
+    }
+}
+
+/// Double the dot of lines starting with one, so none ends the message early
+fn dot_stuff(message: &str) -> String {
+    message
+        .split("\r\n")
+        .map(|line| {
+            if line.starts_with('.') {
+                format!(".{}", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// A header value, encoded when it isn't plain ASCII
+fn header_text(text: &str) -> String {
+    if text.is_ascii() {
+        text.to_string()
+    } else {
+        format!(
+            "=?UTF-8?B?{}?=",
+            base64::engine::general_purpose::STANDARD.encode(text)
+        )
+    }
+}
+
+/// Base64 body text, wrapped at 76 columns
+fn body_base64(text: &str) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    encoded
+        .as_bytes()
+        .chunks(76)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+fn build_message(config: &SmtpConfig, email: &Email) -> String {
+    let now = Local::now();
+    let boundary = format!("feedtui-{}", now.timestamp_nanos_opt().unwrap_or_default());
+    let mut lines = vec![
+        format!("From: {}", config.from),
+        format!("To: {}", config.to.join(", ")),
+        format!("Subject: {}", header_text(&email.subject)),
+        format!("Date: {}", now.to_rfc2822()),
+        "MIME-Version: 1.0".to_string(),
+        format!(
+            "Content-Type: multipart/alternative; boundary=\"{}\"",
+            boundary
+        ),
+        String::new(),
+    ];
+    for (content_type, body) in [("text/plain", &email.text), ("text/html", &email.html)] {
+        lines.push(format!("--{}", boundary));
+        lines.push(format!("Content-Type: {}; charset=utf-8", content_type));
+        lines.push("Content-Transfer-Encoding: base64".to_string());
+        lines.push(String::new());
+        lines.push(body_base64(body));
+    }
+    lines.push(format!("--{}--", boundary));
+    lines.join("\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_has_both_parts_and_encoded_subject() {
+        let config: SmtpConfig = toml::from_str(
+            r#"
+host = "smtp.example.com"
+from = "feedtui <me@example.com>"
+to = ["me@example.com"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.port(), 587);
+        assert_eq!(address(&config.from), "me@example.com");
+
+        let message = build_message(
+            &config,
+            &Email {
+                subject: "Briefing — Friday".to_string(),
+                text: "# Briefing".to_string(),
+                html: "<h1>Briefing</h1>".to_string(),
+            },
+        );
+        assert!(message.contains("Subject: =?UTF-8?B?"));
+        assert!(message.contains("Content-Type: text/plain; charset=utf-8"));
+        assert!(message.contains("Content-Type: text/html; charset=utf-8"));
+        assert!(message.ends_with("--"));
+        assert_eq!(dot_stuff(".\r\nok\r\n.x"), "..\r\nok\r\n..x");
+    }
+}
@@ -0,0 +1,160 @@
+use crate::app::App;
+use crate::config::Config;
+use anyhow::Result;
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+use ratatui::text::Span;
+use std::path::Path;
+
+/// Entry point for `feedtui snapshot`
+pub async fn run_snapshot(config: Config, out: &Path, width: u16, height: u16) -> Result<()> {
+    let mut app = App::new(config);
+    app.fetch_once().await;
+    let buffer = app.render_to_buffer(width, height)?;
+
+    let is_html = out
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm"))
+        .unwrap_or(false);
+
+    let contents = if is_html {
+        buffer_to_html(&buffer)
+    } else {
+        buffer_to_text(&buffer)
+    };
+    std::fs::write(out, contents)?;
+
+    println!("Snapshot written to {}", out.display());
+    Ok(())
+}
+
+/// Iterate over the visible cells of each row, skipping the cells hidden
+/// behind wide (e.g. emoji) symbols
+fn visible_rows(buffer: &Buffer) -> impl Iterator<Item = Vec<&Cell>> {
+    buffer
+        .content
+        .chunks(buffer.area.width.max(1) as usize)
+        .map(|row| {
+            let mut cells = Vec::with_capacity(row.len());
+            let mut skip = 0usize;
+            for cell in row {
+                if skip == 0 {
+                    cells.push(cell);
+                }
+                skip = skip.max(Span::raw(cell.symbol()).width()).saturating_sub(1);
+            }
+            cells
+        })
+}
+
+fn buffer_to_text(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    for row in visible_rows(buffer) {
+        let line: String = row.iter().map(|cell| cell.symbol()).collect();
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+fn buffer_to_html(buffer: &Buffer) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>feedtui snapshot</title>\n\
+         <style>body { background: #000; color: #ccc; } \
+         pre { font-family: monospace; line-height: 1.2; }</style>\n</head>\n<body>\n<pre>\n",
+    );
+
+    for row in visible_rows(buffer) {
+        let mut current: Option<String> = None;
+        for cell in row {
+            let style = cell_style(cell);
+            if current.as_ref() != Some(&style) {
+                if current.is_some() {
+                    out.push_str("</span>");
+                }
+                out.push_str(&format!("<span style=\"{}\">", style));
+                current = Some(style);
+            }
+            out.push_str(&escape_html(cell.symbol()));
+        }
+        if current.is_some() {
+            out.push_str("</span>");
+        }
+        out.push('\n');
+    }
+
+    out.push_str("</pre>\n</body>\n</html>\n");
+    out
+}
+
+fn cell_style(cell: &Cell) -> String {
+    let mut css = Vec::new();
+    if let Some(fg) = color_to_css(cell.fg) {
+        css.push(format!("color:{}", fg));
+    }
+    if let Some(bg) = color_to_css(cell.bg) {
+        css.push(format!("background:{}", bg));
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        css.push("font-weight:bold".to_string());
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        css.push("font-style:italic".to_string());
+    }
+    if cell.modifier.contains(Modifier::UNDERLINED) {
+        css.push("text-decoration:underline".to_string());
+    }
+    css.join(";")
+}
+
+fn color_to_css(color: Color) -> Option<String> {
+    let rgb = match color {
+        Color::Reset => return None,
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 49, 49),
+        Color::Green => (13, 188, 121),
+        Color::Yellow => (229, 229, 16),
+        Color::Blue => (36, 114, 200),
+        Color::Magenta => (188, 63, 188),
+        Color::Cyan => (17, 168, 205),
+        Color::Gray => (204, 204, 204),
+        Color::DarkGray => (118, 118, 118),
+        Color::LightRed => (241, 76, 76),
+        Color::LightGreen => (35, 209, 139),
+        Color::LightYellow => (245, 245, 67),
+        Color::LightBlue => (59, 142, 234),
+        Color::LightMagenta => (214, 112, 214),
+        Color::LightCyan => (41, 184, 219),
+        Color::White => (255, 255, 255),
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) => indexed_to_rgb(i),
+    };
+    Some(format!("#{:02x}{:02x}{:02x}", rgb.0, rgb.1, rgb.2))
+}
+
+/// Approximate an xterm 256-color index as RGB
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => {
+            let base = if index > 7 { 255 } else { 205 };
+            let bit = |n: u8| if index & n != 0 { base } else { 0 };
+            (bit(1), bit(2), bit(4))
+        }
+        16..=231 => {
+            let i = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
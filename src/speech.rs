@@ -0,0 +1,65 @@
+use crate::shell;
+use anyhow::Result;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Child;
+
+/// Text-to-speech command used when none is configured
+const DEFAULT_COMMAND: Option<&str> = if cfg!(target_os = "macos") {
+    Some("say")
+} else if cfg!(windows) {
+    None
+} else {
+    Some("espeak --stdin")
+};
+
+/// Reads text aloud through an external command, one thing at a time
+#[derive(Default)]
+pub struct Speaker {
+    child: Option<Child>,
+}
+
+impl Speaker {
+    /// Start reading `text` by piping it to `command`, stopping anything
+    /// still being read
+    pub fn speak(&mut self, command: Option<&str>, text: &str) -> Result<()> {
+        self.stop();
+        let Some(command) = command.or(DEFAULT_COMMAND) else {
+            anyhow::bail!("Set `command` under [speech] to read aloud");
+        };
+
+        let mut child = tokio::process::Command::from(shell::command(command))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            let text = text.to_string();
+            tokio::spawn(async move {
+                // Stopping early closes the pipe before everything is written
+                let _ = stdin.write_all(text.as_bytes()).await;
+            });
+        }
+        self.child = Some(child);
+        Ok(())
+    }
+
+    /// Whether something is still being read
+    pub fn is_speaking(&mut self) -> bool {
+        let finished = match self.child.as_mut() {
+            Some(child) => !matches!(child.try_wait(), Ok(None)),
+            None => return false,
+        };
+        if finished {
+            self.child = None;
+        }
+        !finished
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.start_kill();
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use crate::ui::charts;
+use anyhow::Result;
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Days of history kept
+const MAX_DAYS: usize = 365;
+/// Days charted by `feedtui stats` and the stats overlay
+pub const CHART_DAYS: usize = 30;
+/// Days the per-widget table covers
+pub const TABLE_DAYS: usize = 7;
+
+/// One widget's use over a day
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WidgetUsage {
+    /// Title when last used; entries are keyed by widget id
+    pub title: String,
+    /// Seconds the widget had focus while the dashboard was in use
+    pub focused_secs: u64,
+    /// Items opened in the browser
+    pub opened: u32,
+    /// Items opened in the article reader
+    pub read: u32,
+    pub fetches: u32,
+}
+
+impl WidgetUsage {
+    fn add(&mut self, other: &WidgetUsage) {
+        self.title.clone_from(&other.title);
+        self.focused_secs += other.focused_secs;
+        self.opened += other.opened;
+        self.read += other.read;
+        self.fetches += other.fetches;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayUsage {
+    pub date: NaiveDate,
+    /// Seconds the dashboard was open and in use
+    pub active_secs: u64,
+    /// By widget id
+    pub widgets: BTreeMap<String, WidgetUsage>,
+}
+
+impl DayUsage {
+    /// `field` summed over every widget
+    pub fn sum(&self, field: impl Fn(&WidgetUsage) -> u32) -> u64 {
+        self.widgets.values().map(|w| field(w) as u64).sum()
+    }
+}
+
+/// Lifetime totals, which achievements are judged by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub active_secs: u64,
+    pub opened: u64,
+    pub read: u64,
+    pub fetches: u64,
+    pub days_active: usize,
+}
+
+pub struct Achievement {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    earned: fn(&Totals) -> bool,
+}
+
+impl Achievement {
+    pub fn is_earned(&self, totals: &Totals) -> bool {
+        (self.earned)(totals)
+    }
+}
+
+pub const ACHIEVEMENTS: [Achievement; 7] = [
+    Achievement {
+        id: "first_read",
+        name: "First Read",
+        description: "Read an article in the reader",
+        earned: |t| t.read >= 1,
+    },
+    Achievement {
+        id: "bookworm",
+        name: "Bookworm",
+        description: "Read 100 articles",
+        earned: |t| t.read >= 100,
+    },
+    Achievement {
+        id: "scholar",
+        name: "Scholar",
+        description: "Read 1,000 articles",
+        earned: |t| t.read >= 1000,
+    },
+    Achievement {
+        id: "explorer",
+        name: "Explorer",
+        description: "Open 50 links",
+        earned: |t| t.opened >= 50,
+    },
+    Achievement {
+        id: "regular",
+        name: "Regular",
+        description: "Use feedtui on 7 different days",
+        earned: |t| t.days_active >= 7,
+    },
+    Achievement {
+        id: "devoted",
+        name: "Devoted",
+        description: "Use feedtui on 30 different days",
+        earned: |t| t.days_active >= 30,
+    },
+    Achievement {
+        id: "marathon",
+        name: "Marathon",
+        description: "Spend 10 hours on the dashboard",
+        earned: |t| t.active_secs >= 10 * 3600,
+    },
+];
+
+/// Usage aggregated by day, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageStats {
+    pub days: Vec<DayUsage>,
+}
+
+impl UsageStats {
+    /// ~/.feedtui/stats.json
+    pub fn default_path() -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".feedtui")
+            .join("stats.json")
+    }
+
+    /// Stats saved at `path`, or none yet if there is no file
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn day(&mut self, date: NaiveDate) -> &mut DayUsage {
+        if self.days.last().is_none_or(|day| day.date != date) {
+            self.days.push(DayUsage {
+                date,
+                active_secs: 0,
+                widgets: BTreeMap::new(),
+            });
+            if self.days.len() > MAX_DAYS {
+                self.days.remove(0);
+            }
+        }
+        self.days.last_mut().expect("a day was just added")
+    }
+
+    fn widget(&mut self, date: NaiveDate, id: &str, title: &str) -> &mut WidgetUsage {
+        let usage = self.day(date).widgets.entry(id.to_string()).or_default();
+        if usage.title != title {
+            usage.title = title.to_string();
+        }
+        usage
+    }
+
+    /// Count `secs` of use, against the focused widget's id and title if any
+    pub fn record_active(&mut self, date: NaiveDate, secs: u64, focused: Option<(&str, &str)>) {
+        self.day(date).active_secs += secs;
+        if let Some((id, title)) = focused {
+            self.widget(date, id, title).focused_secs += secs;
+        }
+    }
+
+    pub fn record_opened(&mut self, date: NaiveDate, id: &str, title: &str) {
+        self.widget(date, id, title).opened += 1;
+    }
+
+    pub fn record_read(&mut self, date: NaiveDate, id: &str, title: &str) {
+        self.widget(date, id, title).read += 1;
+    }
+
+    pub fn record_fetch(&mut self, date: NaiveDate, id: &str, title: &str) {
+        self.widget(date, id, title).fetches += 1;
+    }
+
+    pub fn totals(&self) -> Totals {
+        Totals {
+            active_secs: self.days.iter().map(|d| d.active_secs).sum(),
+            opened: self.days.iter().map(|d| d.sum(|w| w.opened)).sum(),
+            read: self.days.iter().map(|d| d.sum(|w| w.read)).sum(),
+            fetches: self.days.iter().map(|d| d.sum(|w| w.fetches)).sum(),
+            days_active: self.days.iter().filter(|d| d.active_secs > 0).count(),
+        }
+    }
+
+    pub fn today(&self, today: NaiveDate) -> Option<&DayUsage> {
+        self.days.last().filter(|day| day.date == today)
+    }
+
+    /// The `n` days up to and including `today`, oldest first, with `None`
+    /// for days without use
+    pub fn recent_days(&self, today: NaiveDate, n: usize) -> Vec<Option<&DayUsage>> {
+        (0..n as u64)
+            .rev()
+            .map(|ago| {
+                let date = today - chrono::Days::new(ago);
+                self.days.iter().rev().find(|day| day.date == date)
+            })
+            .collect()
+    }
+
+    /// Each widget's use over the `n` days up to `today`, most focused first
+    pub fn widgets_over(&self, today: NaiveDate, n: usize) -> Vec<WidgetUsage> {
+        let mut by_id: BTreeMap<&str, WidgetUsage> = BTreeMap::new();
+        for day in self.recent_days(today, n).into_iter().flatten() {
+            for (id, usage) in &day.widgets {
+                by_id.entry(id).or_default().add(usage);
+            }
+        }
+        let mut widgets: Vec<WidgetUsage> = by_id.into_values().collect();
+        widgets.sort_by(|a, b| {
+            b.focused_secs
+                .cmp(&a.focused_secs)
+                .then(b.fetches.cmp(&a.fetches))
+        });
+        widgets
+    }
+
+    /// Minutes of use and articles read for each of the `n` days up to
+    /// `today`, for sparklines
+    pub fn daily_series(&self, today: NaiveDate, n: usize) -> (Vec<f64>, Vec<f64>) {
+        self.recent_days(today, n)
+            .into_iter()
+            .map(|day| match day {
+                Some(day) => (day.active_secs as f64 / 60.0, day.sum(|w| w.read) as f64),
+                None => (0.0, 0.0),
+            })
+            .unzip()
+    }
+}
+
+/// A duration such as `1h 05m`, `12m`, or `40s`
+pub fn format_duration(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+/// Entry point for `feedtui stats`
+pub fn run_stats() -> Result<()> {
+    let stats = UsageStats::load(&UsageStats::default_path())?;
+    let today = Local::now().date_naive();
+    let totals = stats.totals();
+
+    println!("=== feedtui stats ===\n");
+    if let Some(day) = stats.today(today) {
+        println!(
+            "Today: {} active, {} opened, {} read, {} fetches",
+            format_duration(day.active_secs),
+            day.sum(|w| w.opened),
+            day.sum(|w| w.read),
+            day.sum(|w| w.fetches)
+        );
+    } else {
+        println!("Today: not used yet");
+    }
+    println!(
+        "All time: {} active over {} days, {} opened, {} read",
+        format_duration(totals.active_secs),
+        totals.days_active,
+        totals.opened,
+        totals.read
+    );
+
+    let (minutes, reads) = stats.daily_series(today, CHART_DAYS);
+    println!("\nLast {} days", CHART_DAYS);
+    println!("  Minutes  {}", charts::sparkline(&minutes, CHART_DAYS));
+    println!("  Read     {}", charts::sparkline(&reads, CHART_DAYS));
+
+    println!("\nWidgets, last {} days", TABLE_DAYS);
+    println!(
+        "  {:<24} {:>8} {:>7} {:>5} {:>8}",
+        "WIDGET", "FOCUSED", "OPENED", "READ", "FETCHES"
+    );
+    for usage in stats.widgets_over(today, TABLE_DAYS) {
+        println!(
+            "  {:<24} {:>8} {:>7} {:>5} {:>8}",
+            usage.title,
+            format_duration(usage.focused_secs),
+            usage.opened,
+            usage.read,
+            usage.fetches
+        );
+    }
+
+    println!("\nAchievements");
+    for achievement in &ACHIEVEMENTS {
+        let mark = if achievement.is_earned(&totals) {
+            "✓"
+        } else {
+            "·"
+        };
+        println!(
+            "  {} {} - {}",
+            mark, achievement.name, achievement.description
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregates_by_day_and_widget() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        let mut stats = UsageStats::default();
+        stats.record_active(day(1), 600, Some(("rss-1", "News")));
+        stats.record_read(day(1), "rss-1", "News");
+        stats.record_active(day(3), 60, None);
+        stats.record_read(day(3), "rss-1", "Tech News");
+        stats.record_opened(day(3), "hackernews-1", "HN");
+
+        assert_eq!(stats.days.len(), 2);
+        let totals = stats.totals();
+        assert_eq!((totals.read, totals.opened, totals.days_active), (2, 1, 2));
+        assert_eq!(totals.active_secs, 660);
+
+        let recent = stats.recent_days(day(3), 3);
+        assert!(recent[0].is_some() && recent[1].is_none() && recent[2].is_some());
+
+        let widgets = stats.widgets_over(day(3), 3);
+        assert_eq!(widgets[0].title, "Tech News");
+        assert_eq!((widgets[0].focused_secs, widgets[0].read), (600, 2));
+        assert_eq!(stats.widgets_over(day(3), 1)[0].title, "HN");
+    }
+}
@@ -0,0 +1,111 @@
+use crate::config::{TranslateConfig, TranslateProvider};
+use crate::feeds::http;
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+const LIBRETRANSLATE_URL: &str = "https://libretranslate.com/translate";
+const DEEPL_URL: &str = "https://api.deepl.com/v2/translate";
+/// DeepL's free plan has its own host; its keys end in `:fx`
+const DEEPL_FREE_URL: &str = "https://api-free.deepl.com/v2/translate";
+
+/// Translate each of `texts` into the configured target language, keeping
+/// their order
+pub async fn translate(config: TranslateConfig, texts: Vec<String>) -> Result<Vec<String>> {
+    let client = http::client(http::timeout(None));
+    let response: Value = match config.provider {
+        TranslateProvider::Libretranslate => {
+            let endpoint = config.endpoint.as_deref().unwrap_or(LIBRETRANSLATE_URL);
+            let mut body = serde_json::json!({
+                "q": texts,
+                "source": "auto",
+                "target": config.target,
+                "format": "text",
+            });
+            if let Some(ref key) = config.api_key {
+                body["api_key"] = Value::String(key.clone());
+            }
+            client.post(endpoint).json(&body).send().await?
+        }
+        TranslateProvider::Deepl => {
+            let key = config
+                .api_key
+                .as_deref()
+                .context("DeepL needs `api_key` under [translate]")?;
+            let endpoint = config
+                .endpoint
+                .as_deref()
+                .unwrap_or(if key.ends_with(":fx") {
+                    DEEPL_FREE_URL
+                } else {
+                    DEEPL_URL
+                });
+            let body = serde_json::json!({
+                "text": texts,
+                "target_lang": config.target.to_uppercase(),
+            });
+            client
+                .post(endpoint)
+                .header("Authorization", format!("DeepL-Auth-Key {}", key))
+                .json(&body)
+                .send()
+                .await?
+        }
+    }
+    .error_for_status()?
+    .json()
+    .await?;
+
+    let translated = match config.provider {
+        TranslateProvider::Libretranslate => libretranslate_texts(&response),
+        TranslateProvider::Deepl => deepl_texts(&response),
+    }
+    .context("Unexpected response from the translation service")?;
+    if translated.len() != texts.len() {
+        anyhow::bail!(
+            "Asked to translate {} texts but got {} back",
+            texts.len(),
+            translated.len()
+        );
+    }
+    Ok(translated)
+}
+
+/// `{"translatedText": ["..."]}`
+fn libretranslate_texts(response: &Value) -> Option<Vec<String>> {
+    response
+        .get("translatedText")?
+        .as_array()?
+        .iter()
+        .map(|text| text.as_str().map(str::to_string))
+        .collect()
+}
+
+/// `{"translations": [{"text": "..."}]}`
+fn deepl_texts(response: &Value) -> Option<Vec<String>> {
+    response
+        .get("translations")?
+        .as_array()?
+        .iter()
+        .map(|t| t.get("text")?.as_str().map(str::to_string))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_translations_from_either_provider() {
+        let libre = serde_json::json!({ "translatedText": ["Hello", "World"] });
+        assert_eq!(
+            libretranslate_texts(&libre),
+            Some(vec!["Hello".to_string(), "World".to_string()])
+        );
+
+        let deepl = serde_json::json!({
+            "translations": [{ "detected_source_language": "DE", "text": "Hello" }]
+        });
+        assert_eq!(deepl_texts(&deepl), Some(vec!["Hello".to_string()]));
+        assert_eq!(deepl_texts(&libre), None);
+    }
+}
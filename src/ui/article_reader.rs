@@ -1,34 +1,87 @@
+use crate::config::{ReaderConfig, TranslateConfig};
+use crate::translate;
+use crate::ui::article_text::{self, Article, TextBlock};
+use crate::ui::centered_rect;
+use crate::ui::icons::Icon;
+use crate::ui::image::{self, Protocol};
 use crate::ui::widgets::SelectedItem;
+use anyhow::Result;
+use crossterm::{cursor::MoveTo, queue};
+use futures::FutureExt;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
+    widgets::{
+        Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+    },
     Frame,
 };
+use std::io::{self, Write};
+use tokio::task::JoinHandle;
+
+/// Most rows given to an image at the top of the reader
+const MAX_IMAGE_ROWS: u16 = 12;
 
 /// Article reader overlay for viewing feed content in the terminal
 pub struct ArticleReader {
     pub visible: bool,
     item: Option<SelectedItem>,
+    /// Parsed once per item rather than every frame
+    article: Article,
     scroll_offset: u16,
     content_height: u16,
+    max_width: usize,
+    /// `None` when the terminal can't draw images
+    protocol: Option<Protocol>,
+    image_task: Option<JoinHandle<Result<Vec<u8>>>>,
+    image: Option<Vec<u8>>,
+    /// Space kept free for the image in the last frame
+    image_area: Option<Rect>,
+    /// Where the image is currently on screen
+    drawn_area: Option<Rect>,
+    /// Set when the screen was cleared and the image must be sent again
+    redraw_image: bool,
+    translation_task: Option<JoinHandle<Result<Vec<String>>>>,
+    /// Translated title and article, shown above the original
+    translation: Option<(String, Article)>,
 }
 
-impl Default for ArticleReader {
-    fn default() -> Self {
+impl ArticleReader {
+    pub fn new(config: &ReaderConfig) -> Self {
         Self {
             visible: false,
             item: None,
+            article: Article::default(),
             scroll_offset: 0,
             content_height: 0,
+            max_width: config.max_width.max(20),
+            protocol: image::protocol(config.images),
+            image_task: None,
+            image: None,
+            image_area: None,
+            drawn_area: None,
+            redraw_image: false,
+            translation_task: None,
+            translation: None,
         }
     }
-}
 
-impl ArticleReader {
     /// Show the article reader with the given item
     pub fn show(&mut self, item: SelectedItem) {
+        self.article = item
+            .description
+            .as_deref()
+            .map(article_text::parse)
+            .unwrap_or_default();
+        self.clear_image();
+        self.clear_translation();
+        if self.protocol.is_some() {
+            self.image_task = Some(tokio::spawn(image::load(
+                item.image_url.clone(),
+                item.url.clone(),
+            )));
+        }
         self.item = Some(item);
         self.scroll_offset = 0;
         self.visible = true;
@@ -39,6 +92,137 @@ impl ArticleReader {
         self.visible = false;
         self.item = None;
         self.scroll_offset = 0;
+        self.clear_image();
+        self.clear_translation();
+    }
+
+    fn clear_image(&mut self) {
+        if let Some(task) = self.image_task.take() {
+            task.abort();
+        }
+        self.image = None;
+        self.image_area = None;
+    }
+
+    /// Pick up a finished image download; returns whether one arrived
+    pub fn poll_image(&mut self) -> bool {
+        let Some(task) = self.image_task.as_mut().filter(|task| task.is_finished()) else {
+            return false;
+        };
+        let result = task.now_or_never();
+        self.image_task = None;
+        match result {
+            Some(Ok(Ok(bytes))) => {
+                self.image = self
+                    .protocol
+                    .filter(|&protocol| image::supports(protocol, &bytes))
+                    .map(|_| bytes);
+                self.image.is_some()
+            }
+            Some(Ok(Err(e))) => {
+                tracing::debug!("no image for article: {:#}", e);
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Translate the title and article, or go back to the original alone if
+    /// a translation is already shown or on its way
+    pub fn toggle_translation(&mut self, config: &TranslateConfig) {
+        if self.translation.is_some() || self.translation_task.is_some() {
+            self.clear_translation();
+            return;
+        }
+        let Some(item) = &self.item else {
+            return;
+        };
+        let mut texts = vec![item.title.clone()];
+        texts.extend(
+            self.article
+                .blocks
+                .iter()
+                .filter_map(translatable_text)
+                .map(str::to_string),
+        );
+        self.translation_task = Some(tokio::spawn(translate::translate(config.clone(), texts)));
+    }
+
+    fn clear_translation(&mut self) {
+        if let Some(task) = self.translation_task.take() {
+            task.abort();
+        }
+        self.translation = None;
+    }
+
+    /// Pick up a finished translation; `None` while there is nothing new
+    pub fn poll_translation(&mut self) -> Option<Result<()>> {
+        let task = self
+            .translation_task
+            .as_mut()
+            .filter(|task| task.is_finished())?;
+        let result = task.now_or_never()?;
+        self.translation_task = None;
+
+        let mut texts = match result.map_err(anyhow::Error::from).and_then(|r| r) {
+            Ok(texts) => texts.into_iter(),
+            Err(e) => return Some(Err(e)),
+        };
+        let title = texts.next().unwrap_or_default();
+        let blocks = self
+            .article
+            .blocks
+            .iter()
+            .map(|block| {
+                let mut block = block.clone();
+                if let TextBlock::Heading(text)
+                | TextBlock::Paragraph(text)
+                | TextBlock::Quote(text)
+                | TextBlock::ListItem { text, .. } = &mut block
+                {
+                    *text = texts.next().unwrap_or_default();
+                }
+                block
+            })
+            .collect();
+        // Footnote markers still point at the original's link list
+        let article = Article {
+            blocks,
+            links: Vec::new(),
+        };
+        self.translation = Some((title, article));
+        Some(Ok(()))
+    }
+
+    /// Send the image again after the terminal cleared the screen
+    pub fn invalidate_image(&mut self) {
+        self.redraw_image = true;
+    }
+
+    /// Draw, move or remove the image to match the last frame. Images are
+    /// written straight to the terminal after ratatui has drawn, since they
+    /// don't fit in its cell buffer.
+    pub fn draw_image(&mut self, out: &mut impl Write) -> io::Result<()> {
+        let Some(protocol) = self.protocol else {
+            return Ok(());
+        };
+        let target = self.image_area.filter(|_| self.visible);
+        if target == self.drawn_area && !self.redraw_image {
+            return Ok(());
+        }
+        self.redraw_image = false;
+
+        if self.drawn_area.take().is_some() {
+            write!(out, "{}", image::clear(protocol))?;
+        }
+        if let (Some(area), Some(bytes)) = (target, &self.image) {
+            if let Some(sequence) = image::escape(protocol, bytes, area) {
+                queue!(out, MoveTo(area.x, area.y))?;
+                write!(out, "{}", sequence)?;
+                self.drawn_area = Some(area);
+            }
+        }
+        out.flush()
     }
 
     /// Toggle visibility
@@ -71,6 +255,45 @@ impl ArticleReader {
         self.scroll_offset = (self.scroll_offset + page_size).min(max_scroll);
     }
 
+    /// The article as plain text, laid out as in the reader
+    pub fn plain_text(&self) -> Option<String> {
+        let item = self.item.as_ref()?;
+        let mut text = format!("{}\n{}\n", item.title, item.source);
+        if let Some(ref url) = item.url {
+            text.push_str(url);
+            text.push('\n');
+        }
+        text.push('\n');
+        for line in article_lines(&self.article, self.max_width) {
+            for span in line.spans {
+                text.push_str(&span.content);
+            }
+            text.push('\n');
+        }
+        Some(text)
+    }
+
+    /// The article as it should be read aloud: no URLs, footnote markers
+    /// or code
+    pub fn spoken_text(&self) -> Option<String> {
+        let item = self.item.as_ref()?;
+        let mut parts = vec![item.title.clone()];
+        for block in &self.article.blocks {
+            match block {
+                TextBlock::Heading(text)
+                | TextBlock::Paragraph(text)
+                | TextBlock::Quote(text)
+                | TextBlock::ListItem { text, .. } => parts.push(strip_footnotes(text)),
+                TextBlock::Preformatted(_) => {}
+            }
+        }
+        Some(parts.join("\n\n"))
+    }
+
+    pub fn item(&self) -> Option<&SelectedItem> {
+        self.item.as_ref()
+    }
+
     /// Get the current item's URL
     pub fn get_url(&self) -> Option<&str> {
         self.item.as_ref().and_then(|i| i.url.as_deref())
@@ -95,76 +318,139 @@ impl ArticleReader {
         // Create the main block
         let block = Block::default()
             .title(format!(" {} ", item.title))
-            .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Yellow));
 
         let inner = block.inner(popup_area);
         frame.render_widget(block, popup_area);
 
+        // Split inner area for content and scrollbar, then center a column
+        // no wider than `max_width` for comfortable line lengths
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        let width = (content_layout[0].width as usize).min(self.max_width);
+        let text_area = Rect {
+            x: content_layout[0].x + (content_layout[0].width - width as u16) / 2,
+            width: width as u16,
+            ..content_layout[0]
+        };
+
+        // A loaded image sits above the text and stays put while it scrolls
+        let text_area = if self.image.is_some() {
+            let rows = MAX_IMAGE_ROWS.min(text_area.height / 3);
+            let [image_area, rest] =
+                Layout::vertical([Constraint::Length(rows), Constraint::Min(1)]).areas(text_area);
+            self.image_area = Some(image_area);
+            rest
+        } else {
+            self.image_area = None;
+            text_area
+        };
+
         // Build content lines
         let mut lines: Vec<Line> = Vec::new();
 
         // Source and metadata
         lines.push(Line::from(vec![
             Span::styled("Source: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(&item.source, Style::default().fg(Color::Cyan)),
+            Span::styled(item.source.clone(), Style::default().fg(Color::Cyan)),
         ]));
 
         if let Some(ref metadata) = item.metadata {
             lines.push(Line::from(vec![
                 Span::styled("Info: ", Style::default().fg(Color::DarkGray)),
-                Span::styled(metadata, Style::default().fg(Color::Green)),
+                Span::styled(metadata.clone(), Style::default().fg(Color::Green)),
             ]));
         }
 
         if let Some(ref url) = item.url {
             lines.push(Line::from(vec![
                 Span::styled("URL: ", Style::default().fg(Color::DarkGray)),
-                Span::styled(url, Style::default().fg(Color::Blue).add_modifier(Modifier::UNDERLINED)),
+                Span::styled(
+                    url.clone(),
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::UNDERLINED),
+                ),
             ]));
         }
 
-        lines.push(Line::from(""));
-        lines.push(Line::from(vec![
-            Span::styled(
-                "─".repeat(inner.width.saturating_sub(2) as usize),
+        // Terminals without graphics get a placeholder for known images
+        if let (None, Some(url)) = (&self.image, &item.image_url) {
+            let name = url.rsplit('/').next().unwrap_or(url);
+            lines.push(Line::from(Span::styled(
+                format!("[image: {}]", name),
                 Style::default().fg(Color::DarkGray),
-            ),
-        ]));
+            )));
+        }
+
+        let rule = Line::from(Span::styled(
+            "─".repeat(width),
+            Style::default().fg(Color::DarkGray),
+        ));
+        lines.push(Line::from(""));
+        lines.push(rule.clone());
         lines.push(Line::from(""));
 
+        if self.translation_task.is_some() {
+            lines.push(Line::from(Span::styled(
+                format!("Translating{}", Icon::Ellipsis.glyph()),
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            )));
+            lines.push(Line::from(""));
+        }
+        if let Some((title, article)) = &self.translation {
+            let label = Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC);
+            lines.push(Line::styled("Translation", label));
+            lines.push(Line::from(""));
+            lines.extend(wrapped(
+                title,
+                width,
+                "",
+                "",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            lines.push(Line::from(""));
+            lines.extend(article_lines(article, width));
+            lines.push(Line::from(""));
+            lines.push(rule.clone());
+            lines.push(Line::from(""));
+            lines.push(Line::styled("Original", label));
+            lines.push(Line::from(""));
+        }
+
         // Description/content
-        if let Some(ref description) = item.description {
-            // Strip HTML tags for cleaner display
-            let clean_text = strip_html_tags(description);
-            for line in clean_text.lines() {
-                if !line.trim().is_empty() {
-                    lines.push(Line::from(Span::styled(
-                        line.to_string(),
-                        Style::default().fg(Color::White),
-                    )));
-                }
-            }
-        } else {
+        if self.article.blocks.is_empty() {
             lines.push(Line::from(Span::styled(
                 "No description available.",
-                Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
             )));
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "Press 'o' to open in browser for full content.",
                 Style::default().fg(Color::Yellow),
             )));
+        } else {
+            lines.extend(article_lines(&self.article, width));
         }
 
         lines.push(Line::from(""));
-        lines.push(Line::from(vec![
-            Span::styled(
-                "─".repeat(inner.width.saturating_sub(2) as usize),
-                Style::default().fg(Color::DarkGray),
-            ),
-        ]));
+        lines.push(rule);
 
         // Help text
         lines.push(Line::from(""));
@@ -173,6 +459,10 @@ impl ArticleReader {
             Span::styled("Close  ", Style::default().fg(Color::DarkGray)),
             Span::styled("[o] ", Style::default().fg(Color::Yellow)),
             Span::styled("Open in browser  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[p] ", Style::default().fg(Color::Yellow)),
+            Span::styled("Pager  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[T] ", Style::default().fg(Color::Yellow)),
+            Span::styled("Translate  ", Style::default().fg(Color::DarkGray)),
             Span::styled("[j/k or arrows] ", Style::default().fg(Color::Yellow)),
             Span::styled("Scroll", Style::default().fg(Color::DarkGray)),
         ]));
@@ -180,18 +470,13 @@ impl ArticleReader {
         // Update content height for scrolling
         self.content_height = lines.len() as u16;
 
-        // Create scrollable paragraph
+        // Text is already wrapped to fit; preformatted lines may still run
+        // long and are wrapped by the paragraph
         let paragraph = Paragraph::new(lines)
             .wrap(Wrap { trim: false })
             .scroll((self.scroll_offset, 0));
 
-        // Split inner area for content and scrollbar
-        let content_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Min(1), Constraint::Length(1)])
-            .split(inner);
-
-        frame.render_widget(paragraph, content_layout[0]);
+        frame.render_widget(paragraph, text_area);
 
         // Render scrollbar if content exceeds viewport
         if self.content_height > inner.height {
@@ -207,86 +492,141 @@ impl ArticleReader {
     }
 }
 
-/// Create a centered rectangle with given percentage of width and height
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
+/// Lay out parsed article text at `width` columns, with the link footnotes
+/// listed at the end
+/// The text of blocks worth translating; code is left as written
+fn translatable_text(block: &TextBlock) -> Option<&str> {
+    match block {
+        TextBlock::Heading(text)
+        | TextBlock::Paragraph(text)
+        | TextBlock::Quote(text)
+        | TextBlock::ListItem { text, .. } => Some(text),
+        TextBlock::Preformatted(_) => None,
+    }
 }
 
-/// Simple HTML tag stripping
-fn strip_html_tags(html: &str) -> String {
-    let mut result = String::new();
-    let mut in_tag = false;
-    let mut in_entity = false;
-    let mut entity = String::new();
-
-    for ch in html.chars() {
-        if ch == '<' {
-            in_tag = true;
-        } else if ch == '>' {
-            in_tag = false;
-        } else if ch == '&' && !in_tag {
-            in_entity = true;
-            entity.clear();
-        } else if ch == ';' && in_entity {
-            in_entity = false;
-            // Convert common HTML entities
-            match entity.as_str() {
-                "amp" => result.push('&'),
-                "lt" => result.push('<'),
-                "gt" => result.push('>'),
-                "quot" => result.push('"'),
-                "apos" => result.push('\''),
-                "nbsp" => result.push(' '),
-                "#39" => result.push('\''),
-                _ => {
-                    // Try numeric entities
-                    if entity.starts_with('#') {
-                        if let Ok(code) = entity[1..].parse::<u32>() {
-                            if let Some(c) = char::from_u32(code) {
-                                result.push(c);
-                            }
-                        }
-                    }
-                }
+/// Drop the `[n]` link markers added by `article_text::parse`
+fn strip_footnotes(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match rest[1..].find(']') {
+            Some(end) if end > 0 && rest[1..end + 1].bytes().all(|b| b.is_ascii_digit()) => {
+                rest = &rest[end + 2..];
+            }
+            _ => {
+                result.push('[');
+                rest = &rest[1..];
             }
-            entity.clear();
-        } else if in_entity {
-            entity.push(ch);
-        } else if !in_tag {
-            result.push(ch);
         }
     }
+    result.push_str(rest);
+    result
+}
+
+fn article_lines(article: &Article, width: usize) -> Vec<Line<'static>> {
+    let body = Style::default().fg(Color::White);
+    let mut lines = Vec::new();
+
+    for (idx, block) in article.blocks.iter().enumerate() {
+        // Blank lines between blocks, except inside a run of list items
+        let continues_list = matches!(block, TextBlock::ListItem { .. })
+            && matches!(
+                idx.checked_sub(1).map(|i| &article.blocks[i]),
+                Some(TextBlock::ListItem { .. })
+            );
+        if idx > 0 && !continues_list {
+            lines.push(Line::from(""));
+        }
 
-    // Clean up multiple whitespace
-    let mut clean = String::new();
-    let mut last_was_space = false;
-    for ch in result.chars() {
-        if ch.is_whitespace() {
-            if !last_was_space {
-                clean.push(if ch == '\n' { '\n' } else { ' ' });
-                last_was_space = true;
+        match block {
+            TextBlock::Heading(text) => lines.extend(wrapped(
+                text,
+                width,
+                "",
+                "",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            TextBlock::Paragraph(text) => lines.extend(wrapped(text, width, "", "", body)),
+            TextBlock::ListItem {
+                marker,
+                depth,
+                text,
+            } => {
+                let indent = "  ".repeat(depth.saturating_sub(1));
+                let first = format!("{}{} ", indent, marker);
+                let rest = " ".repeat(first.chars().count());
+                lines.extend(wrapped(text, width, &first, &rest, body));
             }
-        } else {
-            clean.push(ch);
-            last_was_space = false;
+            TextBlock::Quote(text) => lines.extend(wrapped(
+                text,
+                width,
+                "│ ",
+                "│ ",
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::ITALIC),
+            )),
+            TextBlock::Preformatted(text) => lines.extend(
+                text.lines()
+                    .map(|line| Line::styled(line.to_string(), Style::default().fg(Color::Gray))),
+            ),
+        }
+    }
+
+    if !article.links.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            "Links",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+        for (idx, link) in article.links.iter().enumerate() {
+            let first = format!("[{}] ", idx + 1);
+            let rest = " ".repeat(first.len());
+            lines.extend(wrapped(
+                link,
+                width,
+                &first,
+                &rest,
+                Style::default().fg(Color::Blue),
+            ));
         }
     }
 
-    clean.trim().to_string()
+    lines
+}
+
+fn wrapped(
+    text: &str,
+    width: usize,
+    first_indent: &str,
+    indent: &str,
+    style: Style,
+) -> Vec<Line<'static>> {
+    let options = textwrap::Options::new(width.max(1))
+        .initial_indent(first_indent)
+        .subsequent_indent(indent);
+    textwrap::wrap(text, options)
+        .into_iter()
+        .map(|line| Line::styled(line.into_owned(), style))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_only_numeric_footnote_markers() {
+        assert_eq!(
+            strip_footnotes("Read this[1] & [that][12]. [a] [] [3"),
+            "Read this & [that]. [a] [] [3"
+        );
+    }
 }
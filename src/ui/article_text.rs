@@ -0,0 +1,366 @@
+use crate::ui::icons::Icon;
+
+/// A piece of article text laid out on its own lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextBlock {
+    Heading(String),
+    Paragraph(String),
+    /// `depth` starts at 1 for a top-level list
+    ListItem {
+        marker: String,
+        depth: usize,
+        text: String,
+    },
+    Quote(String),
+    /// Kept exactly as written, line breaks included
+    Preformatted(String),
+}
+
+/// Article content split into blocks, with links pulled out as footnotes
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Article {
+    pub blocks: Vec<TextBlock>,
+    /// Targets of the `[n]` markers in the text, numbered from 1
+    pub links: Vec<String>,
+}
+
+/// Break feed content into blocks. HTML keeps its headings, lists, quotes
+/// and links; plain text is split into paragraphs at blank lines. Either
+/// way, line breaks inside a paragraph are dropped so it can be re-flowed.
+pub fn parse(content: &str) -> Article {
+    if !content.contains('<') {
+        let blocks = content
+            .split("\n\n")
+            .map(collapse_whitespace)
+            .filter(|text| !text.is_empty())
+            .map(TextBlock::Paragraph)
+            .collect();
+        return Article {
+            blocks,
+            links: Vec::new(),
+        };
+    }
+
+    let mut parser = HtmlParser::default();
+    let mut rest = content;
+    while let Some(start) = rest.find('<') {
+        parser.text(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let end = rest.find('>').unwrap_or(rest.len());
+        parser.tag(&rest[..end]);
+        rest = rest.get(end + 1..).unwrap_or("");
+    }
+    parser.text(rest);
+    parser.flush();
+
+    Article {
+        blocks: parser.blocks,
+        links: parser.links,
+    }
+}
+
+#[derive(Default)]
+struct HtmlParser {
+    blocks: Vec<TextBlock>,
+    links: Vec<String>,
+    text: String,
+    /// Block the pending text will become; `None` for a plain paragraph
+    current: Option<TextBlock>,
+    /// Open lists, with the next number for ordered ones
+    lists: Vec<Option<usize>>,
+    quote_depth: usize,
+    preformatted: bool,
+    /// Link targets of the open `<a>` tags
+    hrefs: Vec<Option<String>>,
+    /// Inside `<script>` or `<style>`, whose text is never shown
+    hidden: bool,
+}
+
+impl HtmlParser {
+    fn text(&mut self, raw: &str) {
+        if self.hidden {
+            return;
+        }
+        let decoded = decode_entities(raw);
+        if self.preformatted {
+            self.text.push_str(&decoded);
+            return;
+        }
+        for c in decoded.chars() {
+            if c.is_whitespace() {
+                if !self.text.is_empty() && !self.text.ends_with(' ') {
+                    self.text.push(' ');
+                }
+            } else {
+                self.text.push(c);
+            }
+        }
+    }
+
+    fn tag(&mut self, tag: &str) {
+        let tag = tag.trim().trim_end_matches('/');
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("script" | "style", closing) => self.hidden = !closing,
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) => {
+                self.flush();
+                self.current = Some(TextBlock::Heading(String::new()));
+            }
+            ("ul", false) => {
+                self.flush();
+                self.lists.push(None);
+            }
+            ("ol", false) => {
+                self.flush();
+                self.lists.push(Some(1));
+            }
+            ("ul" | "ol", true) => {
+                self.flush();
+                self.lists.pop();
+            }
+            ("li", false) => {
+                self.flush();
+                let depth = self.lists.len().max(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}.", *n - 1)
+                    }
+                    _ => Icon::Bullet.glyph().to_string(),
+                };
+                self.current = Some(TextBlock::ListItem {
+                    marker,
+                    depth,
+                    text: String::new(),
+                });
+            }
+            ("blockquote", closing) => {
+                self.flush();
+                if closing {
+                    self.quote_depth = self.quote_depth.saturating_sub(1);
+                } else {
+                    self.quote_depth += 1;
+                }
+            }
+            ("pre", closing) => {
+                self.flush();
+                self.preformatted = !closing;
+                if !closing {
+                    self.current = Some(TextBlock::Preformatted(String::new()));
+                }
+            }
+            ("br", _) if self.preformatted => self.text.push('\n'),
+            ("a", false) => self.hrefs.push(attribute(tag, "href")),
+            ("a", true) => {
+                if let Some(Some(href)) = self.hrefs.pop() {
+                    self.footnote(href);
+                }
+            }
+            (
+                "p" | "div" | "br" | "hr" | "section" | "article" | "header" | "footer" | "table"
+                | "tr" | "figure" | "figcaption" | "li" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6",
+                _,
+            ) => self.flush(),
+            _ => {}
+        }
+    }
+
+    /// Mark the end of a link's text with its footnote number
+    fn footnote(&mut self, href: String) {
+        if !href.starts_with("http") || self.text.trim_end().ends_with(href.as_str()) {
+            return;
+        }
+        let number = match self.links.iter().position(|link| *link == href) {
+            Some(idx) => idx + 1,
+            None => {
+                self.links.push(href);
+                self.links.len()
+            }
+        };
+
+        let trailing_space = self.text.ends_with(' ');
+        if trailing_space {
+            self.text.pop();
+        }
+        self.text.push_str(&format!("[{}]", number));
+        if trailing_space {
+            self.text.push(' ');
+        }
+    }
+
+    /// End the current block, keeping it if it has any text
+    fn flush(&mut self) {
+        let text = if self.preformatted {
+            self.text.trim_matches('\n').to_string()
+        } else {
+            self.text.trim().to_string()
+        };
+        self.text.clear();
+        let current = self.current.take();
+        if text.is_empty() {
+            return;
+        }
+
+        self.blocks.push(match current {
+            Some(TextBlock::Heading(_)) => TextBlock::Heading(text),
+            Some(TextBlock::ListItem { marker, depth, .. }) => TextBlock::ListItem {
+                marker,
+                depth,
+                text,
+            },
+            Some(TextBlock::Preformatted(_)) => TextBlock::Preformatted(text),
+            _ if self.quote_depth > 0 => TextBlock::Quote(text),
+            _ => TextBlock::Paragraph(text),
+        });
+    }
+}
+
+/// The value of `name="..."` in a tag, if present
+pub fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let start = lower.find(&format!("{}=", name))? + name.len() + 1;
+    let value = &tag[start..];
+    let value = match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next()?,
+        _ => value.split(|c: char| c.is_whitespace()).next()?,
+    };
+    Some(decode_entities(value))
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Replace HTML entities such as `&amp;` and `&#8217;` with their characters
+pub fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end + 1])?, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                result.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        "hellip" => Some('…'),
+        "mdash" => Some('—'),
+        "ndash" => Some('–'),
+        "lsquo" => Some('‘'),
+        "rsquo" => Some('’'),
+        "ldquo" => Some('“'),
+        "rdquo" => Some('”'),
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_is_split_at_blank_lines_and_reflowed() {
+        let article = parse("First line\nstill first.\n\nSecond.");
+        assert_eq!(
+            article.blocks,
+            vec![
+                TextBlock::Paragraph("First line still first.".to_string()),
+                TextBlock::Paragraph("Second.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn html_keeps_structure_and_numbers_links() {
+        let article = parse(
+            "<h2>Title</h2><p>Read <a href=\"https://a.example\">this</a> &amp; \
+             <a href='https://b.example'>that</a>.</p>\
+             <ol><li>one</li><li>two <a href=\"https://a.example\">again</a></li></ol>\
+             <blockquote><p>quoted</p></blockquote><script>hidden()</script>",
+        );
+        assert_eq!(
+            article.blocks,
+            vec![
+                TextBlock::Heading("Title".to_string()),
+                TextBlock::Paragraph("Read this[1] & that[2].".to_string()),
+                TextBlock::ListItem {
+                    marker: "1.".to_string(),
+                    depth: 1,
+                    text: "one".to_string()
+                },
+                TextBlock::ListItem {
+                    marker: "2.".to_string(),
+                    depth: 1,
+                    text: "two again[1]".to_string()
+                },
+                TextBlock::Quote("quoted".to_string()),
+            ]
+        );
+        assert_eq!(
+            article.links,
+            vec!["https://a.example", "https://b.example"]
+        );
+    }
+
+    #[test]
+    fn preformatted_text_keeps_its_lines() {
+        let article = parse("<pre>fn main() {\n    run();\n}</pre><p>after</p>");
+        assert_eq!(
+            article.blocks,
+            vec![
+                TextBlock::Preformatted("fn main() {\n    run();\n}".to_string()),
+                TextBlock::Paragraph("after".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_named_and_numeric_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#39;c&#x27; & d"),
+            "a <b> 'c' & d"
+        );
+    }
+}
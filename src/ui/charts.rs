@@ -0,0 +1,116 @@
+use crate::ui::icons;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::Gauge,
+};
+
+/// Braille dots from the bottom of a cell's left and right columns up
+const LEFT_DOTS: [u32; 4] = [0x40, 0x04, 0x02, 0x01];
+const RIGHT_DOTS: [u32; 4] = [0x80, 0x20, 0x10, 0x08];
+const BRAILLE_BLANK: u32 = 0x2800;
+
+/// Heights for the ASCII sparkline, lowest first
+const ASCII_LEVELS: [char; 4] = ['_', '.', '-', '\''];
+
+/// The latest values as a sparkline `width` cells wide: braille packs two
+/// values per cell, ASCII mode one
+pub fn sparkline(values: &[f64], width: usize) -> String {
+    if icons::ascii() {
+        let levels = levels(&values[values.len().saturating_sub(width)..]);
+        levels
+            .iter()
+            .map(|&level| ASCII_LEVELS[level - 1])
+            .collect()
+    } else {
+        braille_sparkline(&values[values.len().saturating_sub(width * 2)..])
+    }
+}
+
+fn braille_sparkline(values: &[f64]) -> String {
+    let fill = |dots: &[u32; 4], level: usize| dots[..level].iter().fold(0, |bits, dot| bits | dot);
+    levels(values)
+        .chunks(2)
+        .map(|pair| {
+            let left = fill(&LEFT_DOTS, pair[0]);
+            let right = pair.get(1).map_or(0, |&level| fill(&RIGHT_DOTS, level));
+            char::from_u32(BRAILLE_BLANK | left | right).unwrap_or(' ')
+        })
+        .collect()
+}
+
+/// Each value scaled between the smallest and largest to a height of 1-4
+fn levels(values: &[f64]) -> Vec<usize> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    values
+        .iter()
+        .map(|&value| {
+            if max > min {
+                ((value - min) / (max - min) * 3.0).round() as usize + 1
+            } else {
+                1
+            }
+        })
+        .collect()
+}
+
+/// A horizontal bar `width` cells wide, `fraction` of it filled
+pub fn bar(fraction: f64, width: usize, color: Color) -> Vec<Span<'static>> {
+    let filled = ((fraction.clamp(0.0, 1.0) * width as f64).ceil() as usize).min(width);
+    vec![
+        Span::styled("█".repeat(filled), Style::default().fg(color)),
+        Span::styled(
+            "░".repeat(width - filled),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]
+}
+
+/// A full-width gauge with `label` drawn over it
+pub fn gauge(fraction: f64, label: String, color: Color) -> Gauge<'static> {
+    Gauge::default()
+        .gauge_style(
+            Style::default()
+                .fg(color)
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .ratio(fraction.clamp(0.0, 1.0))
+        .label(label)
+}
+
+/// The color of the highest threshold `value` has reached. `thresholds` are
+/// in ascending order; values below all of them get the first color.
+pub fn threshold_color(value: f64, thresholds: &[(f64, Color)]) -> Color {
+    thresholds
+        .iter()
+        .rev()
+        .find(|(at, _)| value >= *at)
+        .or(thresholds.first())
+        .map_or(Color::Reset, |&(_, color)| color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn braille_sparkline_stacks_dots_by_height() {
+        assert_eq!(braille_sparkline(&[0.0, 1.0, 2.0, 3.0]), "⣠⣾");
+        assert_eq!(braille_sparkline(&[5.0, 5.0, 5.0]), "⣀⡀");
+        assert_eq!(braille_sparkline(&[]), "");
+    }
+
+    #[test]
+    fn threshold_color_picks_the_highest_reached() {
+        let thresholds = [
+            (0.0, Color::Green),
+            (70.0, Color::Yellow),
+            (90.0, Color::Red),
+        ];
+        assert_eq!(threshold_color(-5.0, &thresholds), Color::Green);
+        assert_eq!(threshold_color(70.0, &thresholds), Color::Yellow);
+        assert_eq!(threshold_color(99.0, &thresholds), Color::Red);
+    }
+}
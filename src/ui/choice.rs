@@ -0,0 +1,108 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// What a key did to the list
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChoiceResult {
+    /// Still choosing
+    Choosing,
+    Chosen(usize),
+    Cancelled,
+}
+
+/// Popup asking to pick one of several options
+#[derive(Default)]
+pub struct ChoiceDialog {
+    pub visible: bool,
+    title: String,
+    options: Vec<String>,
+    state: ListState,
+}
+
+impl ChoiceDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open(&mut self, title: impl Into<String>, options: Vec<String>) {
+        self.title = title.into();
+        self.options = options;
+        self.state.select(Some(0));
+        self.visible = true;
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ChoiceResult {
+        match key.code {
+            KeyCode::Enter => {
+                self.visible = false;
+                return ChoiceResult::Chosen(self.state.selected().unwrap_or(0));
+            }
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.visible = false;
+                return ChoiceResult::Cancelled;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                let last = self.options.len().saturating_sub(1);
+                let next = self.state.selected().map_or(0, |i| (i + 1).min(last));
+                self.state.select(Some(next));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                let prev = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+                self.state.select(Some(prev));
+            }
+            KeyCode::Char(c) => {
+                // Options are numbered from 1
+                if let Some(i) = c.to_digit(10).and_then(|n| (n as usize).checked_sub(1)) {
+                    if i < self.options.len() {
+                        self.visible = false;
+                        return ChoiceResult::Chosen(i);
+                    }
+                }
+            }
+            _ => {}
+        }
+        ChoiceResult::Choosing
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let width = 70.min(area.width);
+        let height = (self.options.len() as u16 + 2).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        frame.render_widget(Clear, popup_area);
+
+        let items: Vec<ListItem> = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| ListItem::new(format!("{}. {}", i + 1, option)))
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(format!(" {} ", self.title))
+                    .title_bottom(" Enter: choose | Esc: cancel ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            );
+        frame.render_stateful_widget(list, popup_area, &mut self.state);
+    }
+}
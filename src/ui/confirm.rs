@@ -0,0 +1,64 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Yes/no question asked before a destructive action
+#[derive(Default)]
+pub struct ConfirmDialog {
+    pub visible: bool,
+    message: String,
+}
+
+impl ConfirmDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open(&mut self, message: impl Into<String>) {
+        self.message = message.into();
+        self.visible = true;
+    }
+
+    /// `Some(true)` once confirmed, `Some(false)` once declined, and `None`
+    /// for keys that don't answer
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<bool> {
+        let answer = match key.code {
+            KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => true,
+            KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Char('q') => false,
+            _ => return None,
+        };
+        self.visible = false;
+        Some(answer)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let width = 50.min(area.width);
+        let height = 5.min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(" Confirm ")
+            .title_bottom(" y/Enter: yes | n/Esc: no ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red));
+        let text = Paragraph::new(self.message.as_str())
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(block);
+        frame.render_widget(text, popup_area);
+    }
+}
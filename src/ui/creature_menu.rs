@@ -1,7 +1,11 @@
 use crate::creature::{
-    art::get_creature_art, get_all_outfits, get_skill_tree, Creature, CreatureColor,
-    CreatureSpecies,
+    art::{background_color, dress, get_creature_art},
+    get_all_items, get_all_outfits, get_skill_tree, CareAction, Creature, CreatureColor,
+    CreatureSpecies, ItemKind,
 };
+use crate::ui::centered_rect;
+use crate::ui::charts;
+use crate::ui::palette::palette;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -9,13 +13,44 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs},
     Frame,
 };
+use std::path::PathBuf;
+
+/// Energy or happiness below this halves XP gain
+const LOW_STAT: u8 = 20;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MenuTab {
     Stats,
     Skills,
     Outfits,
+    Inventory,
     Customize,
+    Roster,
+}
+
+/// The list on the Customize tab that has the cursor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CustomizeList {
+    Species,
+    Primary,
+    Secondary,
+    Accessory,
+}
+
+impl CustomizeList {
+    const ORDER: [CustomizeList; 4] = [
+        CustomizeList::Species,
+        CustomizeList::Primary,
+        CustomizeList::Secondary,
+        CustomizeList::Accessory,
+    ];
+}
+
+/// What Enter on the Roster tab asks the app to do
+pub enum RosterAction {
+    /// Make the creature saved at this path the active one
+    Switch(PathBuf),
+    Hatch,
 }
 
 impl MenuTab {
@@ -24,7 +59,9 @@ impl MenuTab {
             MenuTab::Stats,
             MenuTab::Skills,
             MenuTab::Outfits,
+            MenuTab::Inventory,
             MenuTab::Customize,
+            MenuTab::Roster,
         ]
     }
 
@@ -33,7 +70,9 @@ impl MenuTab {
             MenuTab::Stats => "Stats",
             MenuTab::Skills => "Skills",
             MenuTab::Outfits => "Outfits",
+            MenuTab::Inventory => "Inventory",
             MenuTab::Customize => "Customize",
+            MenuTab::Roster => "Roster",
         }
     }
 }
@@ -43,8 +82,16 @@ pub struct CreatureMenu {
     current_tab: MenuTab,
     skill_list_state: ListState,
     outfit_list_state: ListState,
+    inventory_list_state: ListState,
     species_list_state: ListState,
-    color_list_state: ListState,
+    primary_list_state: ListState,
+    secondary_list_state: ListState,
+    accessory_list_state: ListState,
+    customize_list: CustomizeList,
+    roster_list_state: ListState,
+    /// Saved creatures, refreshed by the app when the roster changes
+    roster: Vec<(PathBuf, Creature)>,
+    active_path: PathBuf,
 }
 
 impl Default for CreatureMenu {
@@ -53,18 +100,33 @@ impl Default for CreatureMenu {
         skill_list_state.select(Some(0));
         let mut outfit_list_state = ListState::default();
         outfit_list_state.select(Some(0));
+        let mut inventory_list_state = ListState::default();
+        inventory_list_state.select(Some(0));
         let mut species_list_state = ListState::default();
         species_list_state.select(Some(0));
-        let mut color_list_state = ListState::default();
-        color_list_state.select(Some(0));
+        let mut primary_list_state = ListState::default();
+        primary_list_state.select(Some(0));
+        let mut secondary_list_state = ListState::default();
+        secondary_list_state.select(Some(0));
+        let mut accessory_list_state = ListState::default();
+        accessory_list_state.select(Some(0));
+        let mut roster_list_state = ListState::default();
+        roster_list_state.select(Some(0));
 
         Self {
             visible: false,
             current_tab: MenuTab::Stats,
             skill_list_state,
             outfit_list_state,
+            inventory_list_state,
             species_list_state,
-            color_list_state,
+            primary_list_state,
+            secondary_list_state,
+            accessory_list_state,
+            customize_list: CustomizeList::Species,
+            roster_list_state,
+            roster: Vec::new(),
+            active_path: PathBuf::new(),
         }
     }
 }
@@ -74,6 +136,37 @@ impl CreatureMenu {
         self.visible = !self.visible;
     }
 
+    pub fn set_roster(&mut self, roster: Vec<(PathBuf, Creature)>, active_path: PathBuf) {
+        self.roster = roster;
+        self.active_path = active_path;
+    }
+
+    /// Whether the roster's best creature has reached the level to hatch
+    /// another; `active` stands in for its own, possibly stale, save
+    pub fn can_hatch(&self, active: &Creature) -> bool {
+        let best = self
+            .roster
+            .iter()
+            .filter(|(path, _)| *path != self.active_path)
+            .map(|(_, creature)| creature.level)
+            .chain([active.level])
+            .max()
+            .unwrap_or(1);
+        best >= Creature::hatch_level(self.roster.len().max(1))
+    }
+
+    /// The roster entry chosen with Enter, when the Roster tab is open
+    pub fn roster_action(&self) -> Option<RosterAction> {
+        if self.current_tab != MenuTab::Roster {
+            return None;
+        }
+        let selected = self.roster_list_state.selected()?;
+        match self.roster.get(selected) {
+            Some((path, _)) => Some(RosterAction::Switch(path.clone())),
+            None => Some(RosterAction::Hatch),
+        }
+    }
+
     pub fn next_tab(&mut self) {
         let tabs = MenuTab::all();
         let current_idx = tabs
@@ -98,6 +191,36 @@ impl CreatureMenu {
         self.current_tab = tabs[prev_idx];
     }
 
+    /// Move the cursor to the next list on the Customize tab
+    pub fn next_list(&mut self) {
+        self.cycle_list(1);
+    }
+
+    pub fn prev_list(&mut self) {
+        self.cycle_list(CustomizeList::ORDER.len() - 1);
+    }
+
+    fn cycle_list(&mut self, step: usize) {
+        if self.current_tab != MenuTab::Customize {
+            return;
+        }
+        let order = CustomizeList::ORDER;
+        let current = order
+            .iter()
+            .position(|l| *l == self.customize_list)
+            .unwrap_or(0);
+        self.customize_list = order[(current + step) % order.len()];
+    }
+
+    fn customize_state(&mut self) -> &mut ListState {
+        match self.customize_list {
+            CustomizeList::Species => &mut self.species_list_state,
+            CustomizeList::Primary => &mut self.primary_list_state,
+            CustomizeList::Secondary => &mut self.secondary_list_state,
+            CustomizeList::Accessory => &mut self.accessory_list_state,
+        }
+    }
+
     pub fn scroll_up(&mut self) {
         match self.current_tab {
             MenuTab::Skills => {
@@ -114,10 +237,25 @@ impl CreatureMenu {
                     }
                 }
             }
+            MenuTab::Inventory => {
+                if let Some(selected) = self.inventory_list_state.selected() {
+                    if selected > 0 {
+                        self.inventory_list_state.select(Some(selected - 1));
+                    }
+                }
+            }
             MenuTab::Customize => {
-                if let Some(selected) = self.species_list_state.selected() {
+                let state = self.customize_state();
+                if let Some(selected) = state.selected() {
+                    if selected > 0 {
+                        state.select(Some(selected - 1));
+                    }
+                }
+            }
+            MenuTab::Roster => {
+                if let Some(selected) = self.roster_list_state.selected() {
                     if selected > 0 {
-                        self.species_list_state.select(Some(selected - 1));
+                        self.roster_list_state.select(Some(selected - 1));
                     }
                 }
             }
@@ -143,11 +281,32 @@ impl CreatureMenu {
                     }
                 }
             }
+            MenuTab::Inventory => {
+                let item_count = creature.inventory_counts().len();
+                if let Some(selected) = self.inventory_list_state.selected() {
+                    if selected < item_count.saturating_sub(1) {
+                        self.inventory_list_state.select(Some(selected + 1));
+                    }
+                }
+            }
             MenuTab::Customize => {
-                let species_count = CreatureSpecies::all().len();
-                if let Some(selected) = self.species_list_state.selected() {
-                    if selected < species_count.saturating_sub(1) {
-                        self.species_list_state.select(Some(selected + 1));
+                let count = match self.customize_list {
+                    CustomizeList::Species => CreatureSpecies::all().len(),
+                    CustomizeList::Primary | CustomizeList::Secondary => CreatureColor::all().len(),
+                    CustomizeList::Accessory => accessory_choices(creature).len(),
+                };
+                let state = self.customize_state();
+                if let Some(selected) = state.selected() {
+                    if selected < count.saturating_sub(1) {
+                        state.select(Some(selected + 1));
+                    }
+                }
+            }
+            MenuTab::Roster => {
+                // The entry after the creatures is for hatching
+                if let Some(selected) = self.roster_list_state.selected() {
+                    if selected < self.roster.len() {
+                        self.roster_list_state.select(Some(selected + 1));
                     }
                 }
             }
@@ -179,12 +338,41 @@ impl CreatureMenu {
                     }
                 }
             }
+            MenuTab::Inventory => {
+                if let Some(selected) = self.inventory_list_state.selected() {
+                    if let Some((id, _)) = creature.inventory_counts().get(selected) {
+                        return creature.use_item(id);
+                    }
+                }
+            }
             MenuTab::Customize => {
-                let species = CreatureSpecies::all();
-                if let Some(selected) = self.species_list_state.selected() {
-                    if let Some(new_species) = species.get(selected) {
-                        creature.species = new_species.clone();
-                        return true;
+                let Some(selected) = self.customize_state().selected() else {
+                    return false;
+                };
+                match self.customize_list {
+                    CustomizeList::Species => {
+                        if let Some(new_species) = CreatureSpecies::all().get(selected) {
+                            creature.species = new_species.clone();
+                            return true;
+                        }
+                    }
+                    CustomizeList::Primary => {
+                        if let Some(color) = CreatureColor::all().get(selected) {
+                            creature.appearance.primary_color = color.clone();
+                            return true;
+                        }
+                    }
+                    CustomizeList::Secondary => {
+                        if let Some(color) = CreatureColor::all().get(selected) {
+                            creature.appearance.secondary_color = color.clone();
+                            return true;
+                        }
+                    }
+                    CustomizeList::Accessory => {
+                        if let Some(accessory) = accessory_choices(creature).get(selected) {
+                            creature.appearance.accessory = accessory.clone();
+                            return true;
+                        }
                     }
                 }
             }
@@ -246,14 +434,17 @@ impl CreatureMenu {
             MenuTab::Stats => self.render_stats(frame, chunks[1], creature),
             MenuTab::Skills => self.render_skills(frame, chunks[1], creature),
             MenuTab::Outfits => self.render_outfits(frame, chunks[1], creature),
+            MenuTab::Inventory => self.render_inventory(frame, chunks[1], creature),
             MenuTab::Customize => self.render_customize(frame, chunks[1], creature),
+            MenuTab::Roster => self.render_roster(frame, chunks[1], creature),
         }
 
         // Help text at bottom
-        let help =
-            Paragraph::new("Tab/Shift+Tab: Switch tabs | j/k: Navigate | Enter: Select | t: Close")
-                .style(Style::default().fg(Color::DarkGray))
-                .alignment(Alignment::Center);
+        let help = Paragraph::new(
+            "Tab/Shift+Tab: Switch tabs | j/k: Navigate | h/l: Switch list | Enter: Select | f/p/s: Feed/Play/Rest | g: Snake | n: Rename | R: Reset | t: Close",
+        )
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
 
         let help_area = Rect {
             x: popup_area.x,
@@ -271,19 +462,20 @@ impl CreatureMenu {
             .split(area);
 
         // Creature preview
-        let art_lines = get_creature_art(
-            &creature.species,
-            &creature.mood,
-            creature.equipped_outfit.as_deref(),
-            0,
+        let lines = dress(
+            get_creature_art(
+                &creature.species,
+                &creature.mood,
+                creature.equipped_outfit.as_deref(),
+                0,
+            ),
+            &creature.appearance,
         );
-        let color = creature.appearance.primary_color.to_ratatui_color();
-        let lines: Vec<Line> = art_lines
-            .iter()
-            .map(|line| Line::from(Span::styled(line.as_str(), Style::default().fg(color))))
-            .collect();
         let art = Paragraph::new(lines)
             .alignment(Alignment::Center)
+            .style(
+                Style::default().bg(background_color(&creature.appearance).unwrap_or(Color::Reset)),
+            )
             .block(Block::default().title(" Preview ").borders(Borders::ALL));
         frame.render_widget(art, chunks[0]);
 
@@ -322,6 +514,38 @@ impl CreatureMenu {
                 ),
             ]),
             Line::from(""),
+            care_bar("Energy", creature.stats.energy, Color::Yellow),
+            care_bar("Happiness", creature.stats.happiness, Color::Magenta),
+            Line::from(vec![
+                Span::styled("XP Rate: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!("x{}", creature.care_multiplier()),
+                    Style::default().fg(Color::Green),
+                ),
+            ]),
+            Line::from(
+                CareAction::all()
+                    .into_iter()
+                    .flat_map(|action| {
+                        let color = if creature.can_care(action) {
+                            Color::White
+                        } else {
+                            Color::DarkGray
+                        };
+                        [
+                            Span::styled(
+                                format!("[{}] ", care_key(action)),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::styled(
+                                format!("{} ({} pts)  ", action.name(), action.cost()),
+                                Style::default().fg(color),
+                            ),
+                        ]
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            Line::from(""),
             Line::from(vec![
                 Span::styled("Total Sessions: ", Style::default().fg(Color::Gray)),
                 Span::styled(
@@ -336,6 +560,25 @@ impl CreatureMenu {
                     Style::default().fg(Color::White),
                 ),
             ]),
+            Line::from(vec![
+                Span::styled("Streak: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!(
+                        "{} days (best {}, +{:.0}% XP)",
+                        creature.streak,
+                        creature.best_streak,
+                        (creature.streak_multiplier() - 1.0) * 100.0
+                    ),
+                    Style::default().fg(Color::LightRed),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Streak Freezes: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!("{}", creature.streak_freezes()),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]),
             Line::from(""),
             Line::from(vec![
                 Span::styled("Skills Unlocked: ", Style::default().fg(Color::Gray)),
@@ -460,6 +703,119 @@ impl CreatureMenu {
         frame.render_stateful_widget(list, area, &mut self.outfit_list_state);
     }
 
+    fn render_inventory(&mut self, frame: &mut Frame, area: Rect, creature: &Creature) {
+        let all_items = get_all_items();
+        let equipped = [
+            &creature.appearance.hat,
+            &creature.appearance.accessory,
+            &creature.appearance.background,
+        ];
+
+        let items: Vec<ListItem> = creature
+            .inventory_counts()
+            .into_iter()
+            .filter_map(|(id, count)| Some((all_items.get(&id)?, count)))
+            .map(|(item, count)| {
+                let worn = equipped.iter().any(|slot| slot.as_ref() == Some(&item.id));
+                let marker = match item.kind {
+                    ItemKind::Treat { .. } | ItemKind::StreakFreeze => format!("x{}", count),
+                    _ if worn => "[*]".to_string(),
+                    _ => "[ ]".to_string(),
+                };
+
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled(
+                            marker,
+                            Style::default().fg(if worn { Color::Green } else { Color::DarkGray }),
+                        ),
+                        Span::raw(" "),
+                        Span::styled(&item.name, Style::default().fg(Color::White)),
+                        Span::styled(
+                            format!(" ({})", item.kind.name()),
+                            Style::default().fg(Color::Cyan),
+                        ),
+                    ]),
+                    Line::from(Span::styled(
+                        format!("  {}", item.description),
+                        Style::default().fg(Color::Gray),
+                    )),
+                ])
+            })
+            .collect();
+
+        let title = if items.is_empty() {
+            " Inventory - items turn up as you read articles "
+        } else {
+            " Inventory - Enter: wear/remove or eat "
+        };
+        let list = List::new(items)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .highlight_style(Style::default().bg(Color::DarkGray));
+
+        frame.render_stateful_widget(list, area, &mut self.inventory_list_state);
+    }
+
+    fn render_roster(&mut self, frame: &mut Frame, area: Rect, creature: &Creature) {
+        let mut items: Vec<ListItem> = self
+            .roster
+            .iter()
+            .map(|(path, saved)| {
+                let active = *path == self.active_path;
+                // The active creature's save lags behind; show it live
+                let shown = if active { creature } else { saved };
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled(
+                            if active { "[*]" } else { "[ ]" },
+                            Style::default().fg(if active {
+                                Color::Green
+                            } else {
+                                Color::DarkGray
+                            }),
+                        ),
+                        Span::raw(" "),
+                        Span::styled(&shown.name, Style::default().fg(Color::White)),
+                        Span::styled(
+                            format!(" - Lv.{} {}", shown.level, shown.species.name()),
+                            Style::default().fg(Color::Cyan),
+                        ),
+                    ]),
+                    Line::from(Span::styled(
+                        format!(
+                            "  {} points, {} sessions",
+                            shown.points, shown.total_sessions
+                        ),
+                        Style::default().fg(Color::Gray),
+                    )),
+                ])
+            })
+            .collect();
+
+        let hatch = if self.can_hatch(creature) {
+            Span::styled("+ Hatch a new creature", Style::default().fg(Color::Yellow))
+        } else {
+            Span::styled(
+                format!(
+                    "+ Reach level {} to hatch another creature",
+                    Creature::hatch_level(self.roster.len().max(1))
+                ),
+                Style::default().fg(Color::DarkGray),
+            )
+        };
+        items.push(ListItem::new(Line::from(hatch)));
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(" Roster - Enter: switch or hatch ")
+                    .borders(Borders::ALL),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+
+        frame.render_stateful_widget(list, area, &mut self.roster_list_state);
+    }
+
     fn render_customize(&mut self, frame: &mut Frame, area: Rect, creature: &Creature) {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
@@ -496,53 +852,119 @@ impl CreatureMenu {
             .collect();
 
         let list = List::new(items)
-            .block(Block::default().title(" Species ").borders(Borders::ALL))
+            .block(self.customize_block(CustomizeList::Species, " Species "))
             .highlight_style(Style::default().bg(Color::DarkGray));
-
         frame.render_stateful_widget(list, chunks[0], &mut self.species_list_state);
 
-        // Color preview
-        let colors = CreatureColor::all();
-        let color_text: Vec<Line> = colors
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(CreatureColor::all().len() as u16 + 2),
+                Constraint::Min(3),
+            ])
+            .split(chunks[1]);
+        let colors = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(right[0]);
+
+        let color_list = |current: &CreatureColor| {
+            let items: Vec<ListItem> = CreatureColor::all()
+                .iter()
+                .map(|c| {
+                    let marker = if current == c { "[*]" } else { "[ ]" };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(marker, Style::default().fg(Color::White)),
+                        Span::raw(" "),
+                        Span::styled(
+                            format!("{:?}", c),
+                            Style::default().fg(c.to_ratatui_color()),
+                        ),
+                    ]))
+                })
+                .collect();
+            List::new(items).highlight_style(Style::default().bg(Color::DarkGray))
+        };
+        let primary = color_list(&creature.appearance.primary_color)
+            .block(self.customize_block(CustomizeList::Primary, " Primary "));
+        frame.render_stateful_widget(primary, colors[0], &mut self.primary_list_state);
+        let secondary = color_list(&creature.appearance.secondary_color)
+            .block(self.customize_block(CustomizeList::Secondary, " Secondary "));
+        frame.render_stateful_widget(secondary, colors[1], &mut self.secondary_list_state);
+
+        let items = get_all_items();
+        let accessories: Vec<ListItem> = accessory_choices(creature)
             .iter()
-            .map(|c| {
-                let selected = creature.appearance.primary_color == *c;
-                let marker = if selected { "[*]" } else { "[ ]" };
-                Line::from(vec![
-                    Span::styled(marker, Style::default().fg(Color::White)),
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("{:?}", c),
-                        Style::default().fg(c.to_ratatui_color()),
-                    ),
-                ])
+            .map(|choice| {
+                let marker = if creature.appearance.accessory == *choice {
+                    "[*]"
+                } else {
+                    "[ ]"
+                };
+                let name = choice
+                    .as_ref()
+                    .and_then(|id| items.get(id))
+                    .map_or("None", |item| item.name.as_str());
+                ListItem::new(format!("{} {}", marker, name))
             })
             .collect();
+        let list = List::new(accessories)
+            .block(self.customize_block(CustomizeList::Accessory, " Accessory "))
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        frame.render_stateful_widget(list, right[1], &mut self.accessory_list_state);
+    }
+
+    /// Border for a Customize list, highlighted when it has the cursor
+    fn customize_block(&self, list: CustomizeList, title: &'static str) -> Block<'static> {
+        let color = if self.customize_list == list {
+            Color::Yellow
+        } else {
+            Color::DarkGray
+        };
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+    }
+}
+
+/// No accessory, then each accessory in the inventory
+fn accessory_choices(creature: &Creature) -> Vec<Option<String>> {
+    let items = get_all_items();
+    let owned = creature
+        .inventory_counts()
+        .into_iter()
+        .filter_map(|(id, _)| {
+            matches!(items.get(&id)?.kind, ItemKind::Accessory(_)).then_some(Some(id))
+        });
+    std::iter::once(None).chain(owned).collect()
+}
 
-        let colors_para = Paragraph::new(color_text)
-            .block(Block::default().title(" Colors ").borders(Borders::ALL));
-        frame.render_widget(colors_para, chunks[1]);
+/// Key for a care action in the menu
+pub fn care_key(action: CareAction) -> char {
+    match action {
+        CareAction::Feed => 'f',
+        CareAction::Play => 'p',
+        CareAction::Rest => 's',
     }
 }
 
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
+/// A 0-100 stat as a ten-cell bar, turning to the warning color when low
+fn care_bar(label: &str, value: u8, color: Color) -> Line<'static> {
+    let color = charts::threshold_color(
+        value as f64,
+        &[(0.0, palette().negative), (LOW_STAT as f64, color)],
+    );
+    let mut spans = vec![Span::styled(
+        format!("{}: ", label),
+        Style::default().fg(Color::Gray),
+    )];
+    spans.extend(charts::bar(value as f64 / 100.0, 10, color));
+    spans.push(Span::styled(
+        format!(" {}", value),
+        Style::default().fg(Color::White),
+    ));
+    Line::from(spans)
 }
 
 fn format_duration(seconds: u64) -> String {
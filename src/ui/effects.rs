@@ -0,0 +1,94 @@
+use crate::config::EffectsConfig;
+use crate::quiet::{in_range, parse_range};
+use chrono::{Local, NaiveTime};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders},
+    Frame,
+};
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+const FLASH: Duration = Duration::from_millis(300);
+
+/// Events that can ring the bell or flash the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cue {
+    LevelUp,
+    Alert,
+}
+
+/// Bell and border flash, as configured under [effects]
+pub struct Effects {
+    config: EffectsConfig,
+    quiet_hours: Option<(NaiveTime, NaiveTime)>,
+    flash_started: Option<Instant>,
+    /// Set during `[quiet_hours]` and do-not-disturb
+    muted: bool,
+}
+
+impl Effects {
+    pub fn new(config: EffectsConfig) -> Self {
+        let quiet_hours = config.quiet_hours.as_deref().and_then(|range| {
+            let parsed = parse_range(range);
+            if parsed.is_none() {
+                tracing::warn!("Ignoring quiet_hours {:?}; expected HH:MM-HH:MM", range);
+            }
+            parsed
+        });
+        Self {
+            config,
+            quiet_hours,
+            flash_started: None,
+            muted: false,
+        }
+    }
+
+    pub fn trigger(&mut self, cue: Cue) {
+        let enabled = match cue {
+            Cue::LevelUp => self.config.level_up,
+            Cue::Alert => self.config.alerts,
+        };
+        if !enabled || self.muted {
+            return;
+        }
+        if self.config.flash {
+            self.flash_started = Some(Instant::now());
+        }
+        let quiet = self
+            .quiet_hours
+            .is_some_and(|(start, end)| in_range(Local::now().time(), start, end));
+        if self.config.bell && !quiet {
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07");
+            let _ = stdout.flush();
+        }
+    }
+
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+
+    pub fn is_flashing(&self) -> bool {
+        self.flash_started.is_some()
+    }
+
+    /// End a flash that has run its course; returns whether it ended
+    pub fn tick(&mut self) -> bool {
+        if self.flash_started.is_some_and(|t| t.elapsed() >= FLASH) {
+            self.flash_started = None;
+            return true;
+        }
+        false
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if self.is_flashing() {
+            let border = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow).bg(Color::Yellow));
+            frame.render_widget(border, area);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::config::IconStyle;
+use ratatui::buffer::Buffer;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ASCII: AtomicBool = AtomicBool::new(false);
+
+/// Apply `general.icons`; call before rendering
+pub fn configure(style: IconStyle) {
+    let ascii = match style {
+        IconStyle::Auto => locale_lacks_utf8(|name| std::env::var(name).ok()),
+        IconStyle::Unicode => false,
+        IconStyle::Ascii => true,
+    };
+    ASCII.store(ascii, Ordering::Relaxed);
+}
+
+/// Whether to draw in plain ASCII
+pub fn ascii() -> bool {
+    ASCII.load(Ordering::Relaxed)
+}
+
+/// Whether the locale, as the C library would pick it, is set and not
+/// UTF-8. No locale at all says nothing, so Unicode is kept.
+fn locale_lacks_utf8(var: impl Fn(&str) -> Option<String>) -> bool {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .filter_map(var)
+        .find(|value| !value.is_empty())
+        .is_some_and(|locale| {
+            let locale = locale.to_ascii_lowercase();
+            !locale.contains("utf-8") && !locale.contains("utf8")
+        })
+}
+
+/// Swap the box drawing, block and arrow characters that ratatui and the
+/// widgets draw for ASCII stand-ins. Anything else, such as feed text,
+/// is left alone.
+pub fn asciify(buffer: &mut Buffer) {
+    for cell in &mut buffer.content {
+        if let Some(symbol) = ascii_symbol(cell.symbol()) {
+            cell.set_symbol(symbol);
+        }
+    }
+}
+
+fn ascii_symbol(symbol: &str) -> Option<&'static str> {
+    let mut chars = symbol.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    let ascii = match c {
+        '─' | '━' | '═' | '╌' | '┄' => "-",
+        '│' | '┃' | '║' | '╎' | '┆' => "|",
+        '┌' | '┐' | '└' | '┘' | '╭' | '╮' | '╰' | '╯' | '├' | '┤' | '┬' | '┴' | '┼' | '╔' | '╗'
+        | '╚' | '╝' | '┏' | '┓' | '┗' | '┛' => "+",
+        '█' | '▉' | '▊' | '▋' | '▌' | '▍' | '▎' | '▏' | '▓' | '▄' | '▀' => {
+            "#"
+        }
+        '░' | '▒' => ".",
+        '↑' | '▲' => "^",
+        '↓' | '▼' => "v",
+        '←' | '◀' => "<",
+        '→' | '▶' => ">",
+        '•' | '●' => "*",
+        '○' => "o",
+        '…' => ".",
+        '—' | '–' => "-",
+        '‘' | '’' => "'",
+        '“' | '”' => "\"",
+        '·' => ".",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Glyphs drawn by widgets, each with a plain-ASCII stand-in for terminals
+/// that show emoji double-width or not at all
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Icon {
+    Unread,
+    Read,
+    DraftPr,
+    OpenPr,
+    ClosedPr,
+    Commit,
+    Bullet,
+    Ellipsis,
+    Gain,
+    Loss,
+    Live,
+    Final,
+    Warning,
+    Quiet,
+}
+
+impl Icon {
+    pub fn glyph(self) -> &'static str {
+        if ascii() {
+            self.ascii()
+        } else {
+            self.unicode()
+        }
+    }
+
+    fn unicode(self) -> &'static str {
+        match self {
+            Icon::Unread => "●",
+            Icon::Read => "○",
+            Icon::DraftPr => "📝",
+            Icon::OpenPr => "🟢",
+            Icon::ClosedPr => "🔴",
+            Icon::Commit => "🔹",
+            Icon::Bullet => "•",
+            Icon::Ellipsis => "…",
+            Icon::Gain => "▲",
+            Icon::Loss => "▼",
+            Icon::Live => "●",
+            Icon::Final => "○",
+            Icon::Warning => "⚠",
+            Icon::Quiet => "☾",
+        }
+    }
+
+    fn ascii(self) -> &'static str {
+        match self {
+            Icon::Unread => "*",
+            Icon::Read => "-",
+            Icon::DraftPr => "[d]",
+            Icon::OpenPr => "[o]",
+            Icon::ClosedPr => "[x]",
+            Icon::Commit => "-",
+            Icon::Bullet => "*",
+            Icon::Ellipsis => "...",
+            Icon::Gain => "^",
+            Icon::Loss => "v",
+            Icon::Live => "*",
+            Icon::Final => "o",
+            Icon::Warning => "!",
+            Icon::Quiet => "z",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::{Block, Borders, Widget};
+
+    #[test]
+    fn only_a_non_utf8_locale_turns_on_ascii() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert!(!locale_lacks_utf8(env(&[])));
+        assert!(!locale_lacks_utf8(env(&[("LANG", "en_US.UTF-8")])));
+        assert!(locale_lacks_utf8(env(&[("LANG", "C")])));
+        assert!(!locale_lacks_utf8(env(&[
+            ("LC_ALL", "C.utf8"),
+            ("LANG", "C")
+        ])));
+        assert!(locale_lacks_utf8(env(&[
+            ("LC_ALL", ""),
+            ("LC_CTYPE", "POSIX")
+        ])));
+    }
+
+    #[test]
+    fn asciify_redraws_borders() {
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buffer = Buffer::empty(area);
+        Block::default()
+            .borders(Borders::ALL)
+            .render(area, &mut buffer);
+        buffer[(1, 1)].set_symbol("é");
+        asciify(&mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["+--+", "|é |", "+--+"]));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::config::ImageMode;
+use crate::feeds::http;
+use crate::ui::article_text;
+use anyhow::Result;
+use base64::Engine;
+use ratatui::layout::Rect;
+
+/// Largest image downloaded for display
+const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+/// Only the start of a page is searched for its `og:image`
+const MAX_PAGE_BYTES: usize = 1024 * 1024;
+/// Kitty takes image data in pieces of at most this many base64 bytes
+const KITTY_CHUNK: usize = 4096;
+
+/// Terminal graphics protocols that accept encoded image files as-is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    /// Kitty and Ghostty; PNG only
+    Kitty,
+    /// iTerm2 and WezTerm; any format the terminal can decode
+    Iterm,
+}
+
+/// The protocol to use for `mode`, detecting the terminal for `auto`
+pub fn protocol(mode: ImageMode) -> Option<Protocol> {
+    match mode {
+        ImageMode::Off => None,
+        ImageMode::Kitty => Some(Protocol::Kitty),
+        ImageMode::Iterm => Some(Protocol::Iterm),
+        ImageMode::Auto => {
+            let term = std::env::var("TERM").unwrap_or_default();
+            let program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+            if matches!(program.as_str(), "iTerm.app" | "WezTerm") {
+                Some(Protocol::Iterm)
+            } else if std::env::var_os("KITTY_WINDOW_ID").is_some()
+                || matches!(term.as_str(), "xterm-kitty" | "xterm-ghostty")
+            {
+                Some(Protocol::Kitty)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Download the image for an item: `image_url` if it has one, otherwise
+/// the `og:image` of the page at `page_url`
+pub async fn load(image_url: Option<String>, page_url: Option<String>) -> Result<Vec<u8>> {
+    let client = http::client(http::timeout(None));
+    let image_url = match image_url {
+        Some(url) => url,
+        None => {
+            let page_url =
+                page_url.ok_or_else(|| anyhow::anyhow!("No page to look for an image"))?;
+            let response = client.get(&page_url).send().await?.error_for_status()?;
+            let page = http::read_limited(response, MAX_PAGE_BYTES).await?;
+            og_image(&String::from_utf8_lossy(&page))
+                .ok_or_else(|| anyhow::anyhow!("No og:image on {}", page_url))?
+        }
+    };
+
+    let response = client.get(&image_url).send().await?.error_for_status()?;
+    http::read_limited(response, MAX_IMAGE_BYTES).await
+}
+
+/// The `og:image` URL declared in a page's `<meta>` tags
+fn og_image(html: &str) -> Option<String> {
+    html.match_indices("<meta")
+        .filter_map(|(start, _)| {
+            let tag = &html[start + 1..];
+            Some(&tag[..tag.find('>')?])
+        })
+        .find(|tag| {
+            article_text::attribute(tag, "property")
+                .or_else(|| article_text::attribute(tag, "name"))
+                .is_some_and(|name| name == "og:image")
+        })
+        .and_then(|tag| article_text::attribute(tag, "content"))
+        .filter(|url| url.starts_with("http"))
+}
+
+/// Whether `protocol` can draw an image in this format
+pub fn supports(protocol: Protocol, image: &[u8]) -> bool {
+    match protocol {
+        Protocol::Kitty => image.starts_with(b"\x89PNG"),
+        Protocol::Iterm => true,
+    }
+}
+
+/// Escape sequence drawing `image` over `area`, scaled to fit, or `None` if
+/// the protocol can't show this format
+pub fn escape(protocol: Protocol, image: &[u8], area: Rect) -> Option<String> {
+    if !supports(protocol, image) {
+        return None;
+    }
+    let data = base64::engine::general_purpose::STANDARD.encode(image);
+    match protocol {
+        Protocol::Kitty => {
+            let chunks: Vec<&str> = data
+                .as_bytes()
+                .chunks(KITTY_CHUNK)
+                .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+                .collect();
+            let mut out = String::new();
+            for (idx, chunk) in chunks.iter().enumerate() {
+                let more = u8::from(idx + 1 < chunks.len());
+                if idx == 0 {
+                    out.push_str(&format!(
+                        "\x1b_Gf=100,a=T,q=2,C=1,c={},r={},m={};{}\x1b\\",
+                        area.width, area.height, more, chunk
+                    ));
+                } else {
+                    out.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+                }
+            }
+            Some(out)
+        }
+        Protocol::Iterm => Some(format!(
+            "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\x07",
+            image.len(),
+            area.width,
+            area.height,
+            data
+        )),
+    }
+}
+
+/// Escape sequence removing drawn images. iTerm images live in the cells
+/// and vanish once those are redrawn, so only Kitty needs one.
+pub fn clear(protocol: Protocol) -> &'static str {
+    match protocol {
+        Protocol::Kitty => "\x1b_Ga=d,d=A,q=2\x1b\\",
+        Protocol::Iterm => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_og_image_by_property_or_name() {
+        let html = r#"<head><meta charset="utf-8"><meta content="https://x.example/a.png?w=1&amp;h=2" property="og:image"></head>"#;
+        assert_eq!(
+            og_image(html).as_deref(),
+            Some("https://x.example/a.png?w=1&h=2")
+        );
+        assert_eq!(
+            og_image(r#"<meta name='og:image' content='https://x.example/b.jpg'/>"#).as_deref(),
+            Some("https://x.example/b.jpg")
+        );
+        assert_eq!(og_image(r#"<meta property="og:title" content="t">"#), None);
+    }
+
+    #[test]
+    fn kitty_only_takes_png_and_splits_large_images() {
+        let area = Rect::new(0, 0, 20, 10);
+        assert_eq!(escape(Protocol::Kitty, b"\xff\xd8\xff", area), None);
+
+        let mut png = b"\x89PNG".to_vec();
+        png.resize(KITTY_CHUNK, 0);
+        let out = escape(Protocol::Kitty, &png, area).unwrap();
+        assert!(out.starts_with("\x1b_Gf=100,a=T,q=2,C=1,c=20,r=10,m=1;"));
+        assert!(out.contains("\x1b_Gm=0;"));
+    }
+}
@@ -0,0 +1,272 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::collections::HashMap;
+
+/// Entries remembered for each history
+const MAX_HISTORY: usize = 50;
+
+/// What a key did to the input
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputResult {
+    /// Still editing
+    Editing,
+    Submitted(String),
+    Cancelled,
+}
+
+/// Single-line text editor shown as a popup
+#[derive(Default)]
+pub struct TextInput {
+    pub visible: bool,
+    title: String,
+    value: String,
+    /// Cursor position in characters, not bytes
+    cursor: usize,
+    /// Submitted lines, oldest first, kept separately per `history_key` so
+    /// names and URLs don't mix
+    histories: HashMap<&'static str, Vec<String>>,
+    history_key: &'static str,
+    /// Entry being shown while browsing history with Up/Down
+    history_pos: Option<usize>,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the popup with `initial` text and the cursor at its end
+    pub fn open(&mut self, title: impl Into<String>, initial: &str, history_key: &'static str) {
+        self.visible = true;
+        self.title = title.into();
+        self.value = initial.to_string();
+        self.cursor = self.value.chars().count();
+        self.history_key = history_key;
+        self.history_pos = None;
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> InputResult {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+
+        match key.code {
+            KeyCode::Enter => return self.submit(),
+            KeyCode::Esc => {
+                self.visible = false;
+                return InputResult::Cancelled;
+            }
+            KeyCode::Char('c') if ctrl => {
+                self.visible = false;
+                return InputResult::Cancelled;
+            }
+            KeyCode::Char('a') if ctrl => self.cursor = 0,
+            KeyCode::Char('e') if ctrl => self.cursor = self.len(),
+            KeyCode::Char('b') if alt => self.cursor = self.word_start(),
+            KeyCode::Char('f') if alt => self.cursor = self.word_end(),
+            KeyCode::Char('w') if ctrl => self.delete_to(self.word_start()),
+            KeyCode::Backspace if ctrl || alt => self.delete_to(self.word_start()),
+            KeyCode::Char('u') if ctrl => self.delete_to(0),
+            KeyCode::Char('k') if ctrl => self.delete_to(self.len()),
+            KeyCode::Char(c) if !ctrl && !alt => self.insert(c),
+            KeyCode::Backspace => self.delete_to(self.cursor.saturating_sub(1)),
+            KeyCode::Delete => self.delete_to((self.cursor + 1).min(self.len())),
+            KeyCode::Left if ctrl => self.cursor = self.word_start(),
+            KeyCode::Right if ctrl => self.cursor = self.word_end(),
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.len()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.len(),
+            KeyCode::Up => self.browse_history(true),
+            KeyCode::Down => self.browse_history(false),
+            _ => {}
+        }
+        InputResult::Editing
+    }
+
+    /// Insert pasted text at the cursor; line breaks become spaces
+    pub fn paste(&mut self, text: &str) {
+        for c in text.trim_end_matches(['\r', '\n']).chars() {
+            self.insert(if c == '\r' || c == '\n' { ' ' } else { c });
+        }
+    }
+
+    fn submit(&mut self) -> InputResult {
+        self.visible = false;
+        let value = self.value.trim().to_string();
+        if value.is_empty() {
+            return InputResult::Cancelled;
+        }
+
+        let history = self.histories.entry(self.history_key).or_default();
+        history.retain(|entry| *entry != value);
+        history.push(value.clone());
+        if history.len() > MAX_HISTORY {
+            history.remove(0);
+        }
+        InputResult::Submitted(value)
+    }
+
+    fn browse_history(&mut self, older: bool) {
+        let Some(history) = self.histories.get(self.history_key) else {
+            return;
+        };
+        let pos = match (self.history_pos, older) {
+            (None, true) => history.len().checked_sub(1),
+            (None, false) => return,
+            (Some(pos), true) => Some(pos.saturating_sub(1)),
+            (Some(pos), false) => (pos + 1 < history.len()).then_some(pos + 1),
+        };
+
+        self.value = pos.map(|pos| history[pos].clone()).unwrap_or_default();
+        self.cursor = self.len();
+        self.history_pos = pos;
+    }
+
+    fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn byte_offset(&self, chars: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(chars)
+            .map_or(self.value.len(), |(i, _)| i)
+    }
+
+    fn insert(&mut self, c: char) {
+        let at = self.byte_offset(self.cursor);
+        self.value.insert(at, c);
+        self.cursor += 1;
+    }
+
+    /// Delete between the cursor and `to`, leaving the cursor at the start
+    fn delete_to(&mut self, to: usize) {
+        let (start, end) = (self.cursor.min(to), self.cursor.max(to));
+        let range = self.byte_offset(start)..self.byte_offset(end);
+        self.value.replace_range(range, "");
+        self.cursor = start;
+    }
+
+    /// Start of the word before the cursor, skipping whitespace first
+    fn word_start(&self) -> usize {
+        let chars: Vec<char> = self.value.chars().collect();
+        let mut pos = self.cursor;
+        while pos > 0 && chars[pos - 1].is_whitespace() {
+            pos -= 1;
+        }
+        while pos > 0 && !chars[pos - 1].is_whitespace() {
+            pos -= 1;
+        }
+        pos
+    }
+
+    /// End of the word after the cursor, skipping whitespace first
+    fn word_end(&self) -> usize {
+        let chars: Vec<char> = self.value.chars().collect();
+        let mut pos = self.cursor;
+        while pos < chars.len() && chars[pos].is_whitespace() {
+            pos += 1;
+        }
+        while pos < chars.len() && !chars[pos].is_whitespace() {
+            pos += 1;
+        }
+        pos
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let width = area.width.saturating_sub(4).clamp(20, 70).min(area.width);
+        let popup_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + area.height.saturating_sub(3) / 2,
+            width,
+            3.min(area.height),
+        );
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!(" {} ", self.title))
+            .title_bottom(" Enter: confirm | Esc: cancel ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        // Scroll sideways so the cursor stays inside the box
+        let visible = inner.width.saturating_sub(1) as usize;
+        let skip = self.cursor.saturating_sub(visible);
+        let text: String = self.value.chars().skip(skip).take(visible + 1).collect();
+        frame.render_widget(Paragraph::new(text), inner);
+        frame.set_cursor_position(Position::new(
+            inner.x + (self.cursor - skip) as u16,
+            inner.y,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(input: &mut TextInput, code: KeyCode, modifiers: KeyModifiers) -> InputResult {
+        input.handle_key(KeyEvent::new(code, modifiers))
+    }
+
+    fn typed(text: &str) -> TextInput {
+        let mut input = TextInput::new();
+        input.open("Test", "", "test");
+        input.paste(text);
+        input
+    }
+
+    #[test]
+    fn edits_at_the_cursor_across_multibyte_text() {
+        let mut input = typed("héllo");
+        press(&mut input, KeyCode::Left, KeyModifiers::NONE);
+        press(&mut input, KeyCode::Left, KeyModifiers::NONE);
+        press(&mut input, KeyCode::Backspace, KeyModifiers::NONE);
+        press(&mut input, KeyCode::Char('L'), KeyModifiers::SHIFT);
+        assert_eq!(input.value, "héLlo");
+    }
+
+    #[test]
+    fn deletes_the_previous_word() {
+        let mut input = typed("add the feed  ");
+        press(&mut input, KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(input.value, "add the ");
+        press(&mut input, KeyCode::Left, KeyModifiers::CONTROL);
+        press(&mut input, KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(input.value, "the ");
+    }
+
+    #[test]
+    fn recalls_submitted_lines_per_history() {
+        let mut input = typed("first");
+        press(&mut input, KeyCode::Enter, KeyModifiers::NONE);
+        input.open("Test", "", "test");
+        input.paste("second");
+        assert_eq!(
+            press(&mut input, KeyCode::Enter, KeyModifiers::NONE),
+            InputResult::Submitted("second".to_string())
+        );
+
+        input.open("Test", "", "test");
+        press(&mut input, KeyCode::Up, KeyModifiers::NONE);
+        press(&mut input, KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(input.value, "first");
+        press(&mut input, KeyCode::Down, KeyModifiers::NONE);
+        assert_eq!(input.value, "second");
+
+        input.open("Other", "", "other");
+        press(&mut input, KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(input.value, "");
+    }
+}
@@ -0,0 +1,135 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+
+/// Fewest rows a widget needs to show a border, a title, and some content
+pub const MIN_WIDGET_HEIGHT: u16 = 6;
+
+/// Where a widget asked to be placed
+#[derive(Debug, Clone, Copy)]
+pub struct Placement {
+    pub idx: usize,
+    pub row: usize,
+    pub col: usize,
+    pub priority: i32,
+}
+
+/// Lay out widgets for `area`: the configured grid when it fits, otherwise a
+/// single column that drops the lowest-priority widgets once they stop
+/// fitting. The `selected` widget is always kept.
+pub fn arrange(
+    area: Rect,
+    placements: &[Placement],
+    selected: usize,
+    compact_width: u16,
+) -> Vec<(usize, Rect)> {
+    let rows = placements.iter().map(|p| p.row + 1).max().unwrap_or(1) as u16;
+    if area.width < compact_width || area.height / rows < MIN_WIDGET_HEIGHT {
+        stacked(area, placements, selected)
+    } else {
+        grid(area, placements)
+    }
+}
+
+fn grid(area: Rect, placements: &[Placement]) -> Vec<(usize, Rect)> {
+    let max_row = placements.iter().map(|p| p.row).max().unwrap_or(0);
+    let max_col = placements.iter().map(|p| p.col).max().unwrap_or(0);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints((0..=max_row).map(|_| Constraint::Ratio(1, (max_row + 1) as u32)))
+        .split(area);
+
+    let mut areas = Vec::new();
+    for (row_idx, row) in rows.iter().enumerate() {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints((0..=max_col).map(|_| Constraint::Ratio(1, (max_col + 1) as u32)))
+            .split(*row);
+
+        for p in placements.iter().filter(|p| p.row == row_idx) {
+            areas.push((p.idx, cols[p.col]));
+        }
+    }
+    areas
+}
+
+fn stacked(area: Rect, placements: &[Placement], selected: usize) -> Vec<(usize, Rect)> {
+    let fits = (area.height / MIN_WIDGET_HEIGHT).max(1) as usize;
+
+    let mut kept: Vec<Placement> = placements.to_vec();
+    if kept.len() > fits {
+        // Highest priority first, the selected widget ahead of everything;
+        // the sort is stable so ties keep their grid order
+        kept.sort_by_key(|p| (p.idx != selected, std::cmp::Reverse(p.priority)));
+        kept.truncate(fits);
+    }
+    kept.sort_by_key(|p| (p.row, p.col));
+
+    let cells = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(kept.iter().map(|_| Constraint::Ratio(1, kept.len() as u32)))
+        .split(area);
+
+    kept.iter()
+        .zip(cells.iter())
+        .map(|(p, cell)| (p.idx, *cell))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placements() -> Vec<Placement> {
+        vec![
+            Placement {
+                idx: 0,
+                row: 0,
+                col: 0,
+                priority: 0,
+            },
+            Placement {
+                idx: 1,
+                row: 0,
+                col: 1,
+                priority: 5,
+            },
+            Placement {
+                idx: 2,
+                row: 1,
+                col: 0,
+                priority: 1,
+            },
+            Placement {
+                idx: 3,
+                row: 1,
+                col: 1,
+                priority: 0,
+            },
+        ]
+    }
+
+    fn indices(areas: &[(usize, Rect)]) -> Vec<usize> {
+        areas.iter().map(|(idx, _)| *idx).collect()
+    }
+
+    #[test]
+    fn wide_terminals_use_the_grid() {
+        let areas = arrange(Rect::new(0, 0, 120, 40), &placements(), 0, 80);
+        assert_eq!(indices(&areas), vec![0, 1, 2, 3]);
+        assert_eq!(areas[1].1, Rect::new(60, 0, 60, 20));
+    }
+
+    #[test]
+    fn narrow_terminals_stack_widgets_in_grid_order() {
+        let areas = arrange(Rect::new(0, 0, 60, 40), &placements(), 0, 80);
+        assert_eq!(indices(&areas), vec![0, 1, 2, 3]);
+        assert!(areas.iter().all(|(_, rect)| rect.width == 60));
+        assert_eq!(areas[1].1.y, 10);
+    }
+
+    #[test]
+    fn short_terminals_keep_the_selection_and_highest_priorities() {
+        let areas = arrange(Rect::new(0, 0, 60, 18), &placements(), 3, 80);
+        assert_eq!(indices(&areas), vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::logging::{self, log_path, LogBuffer};
+use crate::ui::centered_rect;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use tracing::Level;
+
+/// Overlay showing recent log lines, newest at the bottom
+#[derive(Default)]
+pub struct LogViewer {
+    pub visible: bool,
+    buffer: Option<LogBuffer>,
+    /// Lines scrolled up from the newest entry
+    scroll_offset: usize,
+}
+
+impl LogViewer {
+    pub fn new() -> Self {
+        Self {
+            buffer: logging::buffer(),
+            ..Self::default()
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+        self.scroll_offset = 0;
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset += 1;
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let popup_area = centered_rect(90, 80, area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!(" Log ({}) ", log_path().display()))
+            .title_bottom(" j/k: scroll | L/Esc: close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let Some(ref buffer) = self.buffer else {
+            frame.render_widget(Paragraph::new("Logging is unavailable"), inner);
+            return;
+        };
+
+        let lines = buffer.tail(usize::MAX);
+        let height = inner.height as usize;
+        self.scroll_offset = self.scroll_offset.min(lines.len().saturating_sub(height));
+        let end = lines.len() - self.scroll_offset;
+        let start = end.saturating_sub(height);
+
+        let text: Vec<Line> = lines[start..end]
+            .iter()
+            .map(|line| {
+                let color = match line.level {
+                    Level::ERROR => Color::Red,
+                    Level::WARN => Color::Yellow,
+                    Level::INFO => Color::Green,
+                    _ => Color::DarkGray,
+                };
+                Line::from(vec![
+                    Span::styled(
+                        line.time.format("%H:%M:%S ").to_string(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(format!("{:>5} ", line.level), Style::default().fg(color)),
+                    Span::styled(
+                        format!("{}: ", line.target),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(line.message.clone()),
+                ])
+            })
+            .collect();
+
+        if text.is_empty() {
+            frame.render_widget(Paragraph::new("No log entries yet"), inner);
+        } else {
+            frame.render_widget(Paragraph::new(text), inner);
+        }
+    }
+}
@@ -1,3 +1,41 @@
 pub mod article_reader;
+pub mod article_text;
+pub mod charts;
+pub mod choice;
+pub mod confirm;
 pub mod creature_menu;
+pub mod effects;
+pub mod icons;
+pub mod image;
+pub mod input;
+pub mod layout;
+pub mod log_viewer;
+pub mod palette;
+pub mod qr;
+pub mod snake;
+pub mod spinner;
+pub mod stats_view;
 pub mod widgets;
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+
+/// Create a centered rectangle with given percentage of width and height
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
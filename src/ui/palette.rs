@@ -0,0 +1,60 @@
+use ratatui::style::Color;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Colors for states that would otherwise be told apart by red and green
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Gains, live games, unread items
+    pub positive: Color,
+    /// Losses and failures
+    pub negative: Color,
+}
+
+/// Built-in themes by `general.theme` name. The color-blind ones trade red
+/// and green for blue against orange or yellow.
+const THEMES: [(&str, Palette); 4] = [
+    ("dark", DEFAULT),
+    ("light", DEFAULT),
+    (
+        "deuteranopia",
+        Palette {
+            positive: Color::Indexed(33),
+            negative: Color::Indexed(208),
+        },
+    ),
+    (
+        "protanopia",
+        Palette {
+            positive: Color::Indexed(39),
+            negative: Color::Indexed(220),
+        },
+    ),
+];
+
+const DEFAULT: Palette = Palette {
+    positive: Color::Green,
+    negative: Color::Red,
+};
+
+static THEME: AtomicUsize = AtomicUsize::new(0);
+
+/// Apply `general.theme`; unknown names fall back to `dark`
+pub fn configure(theme: &str) {
+    let index = THEMES
+        .iter()
+        .position(|(name, _)| name.eq_ignore_ascii_case(theme))
+        .unwrap_or_else(|| {
+            tracing::warn!("Unknown theme {:?}; using dark", theme);
+            0
+        });
+    THEME.store(index, Ordering::Relaxed);
+}
+
+/// Names `general.theme` accepts
+pub fn theme_names() -> Vec<&'static str> {
+    THEMES.iter().map(|(name, _)| *name).collect()
+}
+
+pub fn palette() -> Palette {
+    THEMES[THEME.load(Ordering::Relaxed)].1
+}
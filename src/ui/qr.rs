@@ -0,0 +1,158 @@
+use crate::ui::icons;
+use qrcode::{Color as Module, QrCode};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Light modules scanners need around the code
+const QUIET_ZONE: usize = 2;
+
+/// Overlay showing a URL as a QR code, to carry on reading on a phone
+#[derive(Default)]
+pub struct QrOverlay {
+    pub visible: bool,
+    url: String,
+    lines: Vec<Line<'static>>,
+}
+
+impl QrOverlay {
+    /// Show `url`, or return why it can't be encoded
+    pub fn show(&mut self, url: &str) -> Result<(), qrcode::types::QrError> {
+        let code = QrCode::new(url.as_bytes())?;
+        let dark: Vec<bool> = code
+            .to_colors()
+            .into_iter()
+            .map(|module| module == Module::Dark)
+            .collect();
+        self.lines = if icons::ascii() {
+            full_cells(&dark, code.width())
+        } else {
+            half_blocks(&dark, code.width())
+        };
+        self.url = url.to_string();
+        self.visible = true;
+        Ok(())
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let code_width = self.lines.first().map_or(0, |line| line.width()) as u16;
+        let width = (code_width + 2).max(30).min(area.width);
+        let height = (self.lines.len() as u16 + 3).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(" Scan to open ")
+            .title_bottom(" any key: close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        // A clipped code won't scan, so say so instead of drawing part of it
+        let fits = code_width + 2 <= area.width && self.lines.len() as u16 + 3 <= area.height;
+        let mut lines = if fits {
+            self.lines.clone()
+        } else {
+            vec![Line::from("Enlarge the terminal to show the QR code")]
+        };
+        lines.push(Line::styled(
+            self.url.clone(),
+            Style::default().fg(Color::DarkGray),
+        ));
+
+        let text = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(text, popup_area);
+    }
+}
+
+/// Draw a square module grid two rows per line with `▀`: the top module
+/// sets the foreground and the bottom one the background. Colors are fixed
+/// black on white so the code scans whatever the terminal theme.
+fn half_blocks(dark: &[bool], width: usize) -> Vec<Line<'static>> {
+    let size = width + 2 * QUIET_ZONE;
+    let is_dark = module_at(dark, width);
+
+    (0..size)
+        .step_by(2)
+        .map(|y| {
+            let spans: Vec<Span> = (0..size)
+                .map(|x| {
+                    Span::styled(
+                        "▀",
+                        Style::default()
+                            .fg(color(is_dark(x, y)))
+                            .bg(color(is_dark(x, y + 1))),
+                    )
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// The ASCII fallback: one row per line, each module two shaded spaces wide
+fn full_cells(dark: &[bool], width: usize) -> Vec<Line<'static>> {
+    let size = width + 2 * QUIET_ZONE;
+    let is_dark = module_at(dark, width);
+    (0..size)
+        .map(|y| {
+            let spans: Vec<Span> = (0..size)
+                .map(|x| Span::styled("  ", Style::default().bg(color(is_dark(x, y)))))
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Whether the module at `x, y` is dark, counting the quiet zone
+fn module_at(dark: &[bool], width: usize) -> impl Fn(usize, usize) -> bool + '_ {
+    move |x, y| {
+        let (x, y) = (x.wrapping_sub(QUIET_ZONE), y.wrapping_sub(QUIET_ZONE));
+        x < width && y < width && dark[y * width + x]
+    }
+}
+
+fn color(dark: bool) -> Color {
+    if dark {
+        Color::Black
+    } else {
+        Color::White
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_two_module_rows_per_line_inside_a_quiet_zone() {
+        // 1x1 grid with a single dark module
+        let lines = half_blocks(&[true], 1);
+        let size = 1 + 2 * QUIET_ZONE;
+        assert_eq!(lines.len(), size.div_ceil(2));
+        assert!(lines.iter().all(|line| line.spans.len() == size));
+
+        let cell = &lines[QUIET_ZONE / 2].spans[QUIET_ZONE];
+        assert_eq!(cell.style.fg, Some(Color::Black));
+        assert_eq!(cell.style.bg, Some(Color::White));
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::White));
+    }
+}
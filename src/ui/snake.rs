@@ -0,0 +1,258 @@
+use crate::random;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+const BOARD_WIDTH: u16 = 24;
+const BOARD_HEIGHT: u16 = 14;
+/// Time between moves, which is also how often the game needs a tick
+pub const STEP: Duration = Duration::from_millis(150);
+/// Food eaten per point earned
+const FOOD_PER_POINT: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Heading {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Heading {
+    fn opposite(self) -> Heading {
+        match self {
+            Heading::Up => Heading::Down,
+            Heading::Down => Heading::Up,
+            Heading::Left => Heading::Right,
+            Heading::Right => Heading::Left,
+        }
+    }
+}
+
+/// Snake played from the creature menu to earn points
+pub struct Snake {
+    pub visible: bool,
+    /// Head first
+    body: VecDeque<(u16, u16)>,
+    heading: Heading,
+    /// Turn to make on the next move, so two quick keys can't reverse
+    next_heading: Heading,
+    food: (u16, u16),
+    score: u32,
+    over: bool,
+    /// Points from a finished game, until the app collects them
+    reward: Option<u32>,
+    last_step: Instant,
+}
+
+impl Default for Snake {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            body: VecDeque::new(),
+            heading: Heading::Right,
+            next_heading: Heading::Right,
+            food: (0, 0),
+            score: 0,
+            over: false,
+            reward: None,
+            last_step: Instant::now(),
+        }
+    }
+}
+
+impl Snake {
+    /// Open the board with a fresh three-cell snake in the middle
+    pub fn start(&mut self) {
+        let (x, y) = (BOARD_WIDTH / 2, BOARD_HEIGHT / 2);
+        *self = Self {
+            visible: true,
+            body: VecDeque::from([(x, y), (x - 1, y), (x - 2, y)]),
+            ..Self::default()
+        };
+        self.place_food();
+    }
+
+    /// Whether the snake is moving and needs fast ticks
+    pub fn is_running(&self) -> bool {
+        self.visible && !self.over
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        let heading = match key.code {
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('w') => Heading::Up,
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('s') => Heading::Down,
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('a') => Heading::Left,
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('d') => Heading::Right,
+            KeyCode::Enter if self.over => return self.start(),
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.finish();
+                self.visible = false;
+                return;
+            }
+            _ => return,
+        };
+        if heading != self.heading.opposite() {
+            self.next_heading = heading;
+        }
+    }
+
+    /// Move once a step has passed; returns whether anything changed
+    pub fn tick(&mut self) -> bool {
+        if !self.is_running() || self.last_step.elapsed() < STEP {
+            return false;
+        }
+        self.last_step = Instant::now();
+        self.advance();
+        true
+    }
+
+    /// Points earned by a game that just ended
+    pub fn take_reward(&mut self) -> Option<u32> {
+        self.reward.take()
+    }
+
+    fn advance(&mut self) {
+        self.heading = self.next_heading;
+        let (x, y) = self.body[0];
+        let head = match self.heading {
+            Heading::Up => y.checked_sub(1).map(|y| (x, y)),
+            Heading::Down => (y + 1 < BOARD_HEIGHT).then_some((x, y + 1)),
+            Heading::Left => x.checked_sub(1).map(|x| (x, y)),
+            Heading::Right => (x + 1 < BOARD_WIDTH).then_some((x + 1, y)),
+        };
+
+        let eating = head == Some(self.food);
+        if !eating {
+            self.body.pop_back();
+        }
+        match head {
+            Some(head) if !self.body.contains(&head) => self.body.push_front(head),
+            _ => return self.finish(),
+        }
+        if eating {
+            self.score += 1;
+            self.place_food();
+        }
+    }
+
+    fn place_food(&mut self) {
+        let free: Vec<(u16, u16)> = (0..BOARD_HEIGHT)
+            .flat_map(|y| (0..BOARD_WIDTH).map(move |x| (x, y)))
+            .filter(|cell| !self.body.contains(cell))
+            .collect();
+        if free.is_empty() {
+            return self.finish();
+        }
+        self.food = free[(random::unit() * free.len() as f64) as usize];
+    }
+
+    fn finish(&mut self) {
+        if !self.over {
+            self.over = true;
+            self.reward = Some(self.score / FOOD_PER_POINT);
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        // Cells are two columns wide so the board looks square
+        let width = (BOARD_WIDTH * 2 + 2).min(area.width);
+        let height = (BOARD_HEIGHT + 3).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        frame.render_widget(Clear, popup_area);
+
+        let mut lines: Vec<Line> = (0..BOARD_HEIGHT)
+            .map(|y| {
+                let spans: Vec<Span> = (0..BOARD_WIDTH)
+                    .map(|x| {
+                        if self.body.front() == Some(&(x, y)) {
+                            Span::styled("██", Style::default().fg(Color::Yellow))
+                        } else if self.body.contains(&(x, y)) {
+                            Span::styled("██", Style::default().fg(Color::Green))
+                        } else if self.food == (x, y) {
+                            Span::styled("<>", Style::default().fg(Color::Red))
+                        } else {
+                            Span::raw("  ")
+                        }
+                    })
+                    .collect();
+                Line::from(spans)
+            })
+            .collect();
+        lines.push(if self.over {
+            Line::styled(
+                format!(
+                    "Game over! +{} points | Enter: again",
+                    self.score / FOOD_PER_POINT
+                ),
+                Style::default().fg(Color::Yellow),
+            )
+        } else {
+            Line::styled(
+                format!("1 point per {} eaten", FOOD_PER_POINT),
+                Style::default().fg(Color::DarkGray),
+            )
+        });
+
+        let block = Block::default()
+            .title(format!(" Snake - Score {} ", self.score))
+            .title_bottom(" arrows/hjkl: move | Esc: close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Green));
+        let board = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(board, popup_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eats_grows_and_dies_at_the_wall() {
+        let mut snake = Snake::default();
+        snake.start();
+        let (x, y) = snake.body[0];
+        snake.food = (x + 1, y);
+        snake.advance();
+        assert_eq!(snake.score, 1);
+        assert_eq!(snake.body.len(), 4);
+        snake.food = (0, 0);
+
+        while !snake.over {
+            snake.advance();
+        }
+        assert_eq!(snake.body[0], (BOARD_WIDTH - 1, y));
+        assert_eq!(snake.take_reward(), Some(0));
+        assert_eq!(snake.take_reward(), None);
+    }
+
+    #[test]
+    fn cannot_reverse_into_itself() {
+        let mut snake = Snake::default();
+        snake.start();
+        snake.handle_key(KeyEvent::from(KeyCode::Left));
+        snake.food = (0, 0);
+        snake.advance();
+        assert!(!snake.over);
+        assert_eq!(snake.heading, Heading::Right);
+    }
+}
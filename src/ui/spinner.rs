@@ -0,0 +1,25 @@
+use crate::ui::icons;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const ASCII_FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+const FRAME_TIME: Duration = Duration::from_millis(100);
+
+/// The spinner frame for now, so every spinner on screen turns together
+pub fn frame() -> &'static str {
+    static START: OnceLock<Instant> = OnceLock::new();
+    let ticks =
+        (START.get_or_init(Instant::now).elapsed().as_millis() / FRAME_TIME.as_millis()) as usize;
+    let frames: &[&str] = if icons::ascii() {
+        &ASCII_FRAMES
+    } else {
+        &FRAMES
+    };
+    frames[ticks % frames.len()]
+}
+
+/// `label` behind a spinner
+pub fn loading(label: &str) -> String {
+    format!("{} {}", frame(), label)
+}
@@ -0,0 +1,152 @@
+use crate::stats::{format_duration, UsageStats, ACHIEVEMENTS, CHART_DAYS, TABLE_DAYS};
+use crate::ui::centered_rect;
+use crate::ui::charts;
+use crate::ui::palette::palette;
+use chrono::Local;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Width of the per-widget focus time bars
+const BAR_WIDTH: usize = 20;
+
+/// Overlay charting recent usage, per-widget time, and achievements
+#[derive(Default)]
+pub struct StatsView {
+    pub visible: bool,
+}
+
+impl StatsView {
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// `earned` holds the ids of achievements the creature has; without a
+    /// creature they are judged from the stats alone
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        stats: &UsageStats,
+        earned: Option<&[String]>,
+    ) {
+        if !self.visible {
+            return;
+        }
+
+        let popup_area = centered_rect(90, 80, area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(" Stats ")
+            .title_bottom(" U/Esc: close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let today = Local::now().date_naive();
+        let totals = stats.totals();
+        let heading = Style::default().add_modifier(Modifier::BOLD);
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut lines = Vec::new();
+
+        let (active, opened, read, fetches) = stats.today(today).map_or((0, 0, 0, 0), |day| {
+            (
+                day.active_secs,
+                day.sum(|w| w.opened),
+                day.sum(|w| w.read),
+                day.sum(|w| w.fetches),
+            )
+        });
+        lines.push(Line::from(vec![
+            Span::styled("Today     ", heading),
+            Span::raw(format!(
+                "{} active · {} opened · {} read · {} fetches",
+                format_duration(active),
+                opened,
+                read,
+                fetches
+            )),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("All time  ", heading),
+            Span::raw(format!(
+                "{} active over {} days · {} opened · {} read",
+                format_duration(totals.active_secs),
+                totals.days_active,
+                totals.opened,
+                totals.read
+            )),
+        ]));
+        lines.push(Line::default());
+
+        let (minutes, reads) = stats.daily_series(today, CHART_DAYS);
+        lines.push(Line::styled(format!("Last {} days", CHART_DAYS), heading));
+        lines.push(Line::from(vec![
+            Span::styled("  Minutes  ", dim),
+            Span::styled(
+                charts::sparkline(&minutes, CHART_DAYS),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("  Read     ", dim),
+            Span::styled(
+                charts::sparkline(&reads, CHART_DAYS),
+                Style::default().fg(palette().positive),
+            ),
+        ]));
+        lines.push(Line::default());
+
+        let widgets = stats.widgets_over(today, TABLE_DAYS);
+        let most_focused = widgets.first().map_or(0, |w| w.focused_secs).max(1);
+        lines.push(Line::styled(
+            format!("Widgets, last {} days", TABLE_DAYS),
+            heading,
+        ));
+        if widgets.is_empty() {
+            lines.push(Line::styled("  Nothing recorded yet", dim));
+        }
+        for usage in &widgets {
+            let mut spans = vec![Span::raw(format!("  {:<20.20} ", usage.title))];
+            spans.extend(charts::bar(
+                usage.focused_secs as f64 / most_focused as f64,
+                BAR_WIDTH,
+                Color::Cyan,
+            ));
+            spans.push(Span::raw(format!(
+                " {:>7}  {} opened · {} read · {} fetches",
+                format_duration(usage.focused_secs),
+                usage.opened,
+                usage.read,
+                usage.fetches
+            )));
+            lines.push(Line::from(spans));
+        }
+        lines.push(Line::default());
+
+        lines.push(Line::styled("Achievements", heading));
+        for achievement in &ACHIEVEMENTS {
+            let is_earned = match earned {
+                Some(ids) => ids.iter().any(|id| id == achievement.id),
+                None => achievement.is_earned(&totals),
+            };
+            let (mark, style) = if is_earned {
+                ("✓", Style::default().fg(palette().positive))
+            } else {
+                ("·", dim)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} {:<12}", mark, achievement.name), style),
+                Span::styled(achievement.description, dim),
+            ]));
+        }
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
@@ -1,16 +1,38 @@
 use crate::config::CreatureConfig;
-use crate::creature::art::{get_creature_art, get_greeting, get_idle_message};
-use crate::creature::Creature;
+use crate::creature::art::{
+    background_color, dress, get_creature_art, get_greeting, get_idle_message,
+};
+use crate::creature::reactions::{FeedWatcher, Reaction};
+use crate::creature::{get_all_emotes, Creature, Emote, SkillEffect};
 use crate::feeds::{FeedData, FeedFetcher};
-use crate::ui::widgets::FeedWidget;
+use crate::ui::charts;
+use crate::ui::widgets::{actions_hint, Action, FeedWidget, Handled, SelectedItem, WidgetRequest};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph},
+    widgets::{Block, Borders, Paragraph},
     Frame,
 };
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// How long a reaction to the feeds stays on screen
+const REACTION_SECS: u64 = 6;
+/// Times an emote's frames play through
+const EMOTE_LOOPS: usize = 3;
+/// Interactions only cheer the creature up this often, so mashing keys
+/// doesn't max out its happiness
+const CHEER_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Things to do with the creature while its widget is focused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interaction {
+    Pet,
+    Play,
+    /// Play the emote picked with j/k
+    Emote,
+}
 
 pub struct CreatureWidget {
     config: CreatureConfig,
@@ -20,11 +42,24 @@ pub struct CreatureWidget {
     last_frame_time: Instant,
     show_greeting: bool,
     greeting_timer: Option<Instant>,
+    watcher: FeedWatcher,
+    /// Reaction being shown, and when it started
+    reaction: Option<(Reaction, Instant)>,
+    /// Index into the unlocked emotes, picked with j/k
+    emote_choice: usize,
+    /// Emote being played, when it started, and the frame last drawn
+    emote: Option<(Emote, Instant, usize)>,
+    last_cheer: Option<Instant>,
 }
 
 impl CreatureWidget {
     pub fn new(config: CreatureConfig, creature: Creature) -> Self {
         Self {
+            watcher: FeedWatcher::new(&config),
+            reaction: None,
+            emote_choice: 0,
+            emote: None,
+            last_cheer: None,
             config,
             creature,
             selected: false,
@@ -43,12 +78,77 @@ impl CreatureWidget {
         &mut self.creature
     }
 
-    /// Update animation frame
-    pub fn tick(&mut self) {
+    /// Whether a short-lived animation such as the greeting is playing
+    pub fn is_animating(&self) -> bool {
+        self.show_greeting || self.reaction.is_some() || self.emote.is_some()
+    }
+
+    /// Pet, play with, or emote with the creature, playing an emote.
+    /// Returns whether it cheered the creature up.
+    pub fn interact(&mut self, interaction: Interaction) -> bool {
+        let emote_id = match interaction {
+            Interaction::Pet => Some("happy".to_string()),
+            Interaction::Play => Some("excited".to_string()),
+            Interaction::Emote => self.chosen_emote(),
+        };
+        let Some(emote) = emote_id.and_then(|id| get_all_emotes().remove(&id)) else {
+            return false;
+        };
+        self.emote = Some((emote, Instant::now(), 0));
+
+        if self
+            .last_cheer
+            .is_some_and(|t| t.elapsed() < CHEER_COOLDOWN)
+        {
+            return false;
+        }
+        self.last_cheer = Some(Instant::now());
+        self.creature.cheer_up();
+        true
+    }
+
+    /// With the News Digest skill, the top story takes turns with the idle
+    /// chatter, about five seconds each
+    fn digest_story(&self) -> Option<&str> {
+        if self.animation_frame / 10 % 2 == 1
+            || !self.creature.has_skill_effect(&SkillEffect::NewsDigest)
+        {
+            return None;
+        }
+        self.watcher.top_story()
+    }
+
+    fn chosen_emote(&self) -> Option<String> {
+        let emotes = &self.creature.unlocked_emotes;
+        if emotes.is_empty() {
+            return None;
+        }
+        Some(emotes[self.emote_choice % emotes.len()].clone())
+    }
+
+    /// Frame of the playing emote, or `None` once it has finished
+    fn emote_frame(emote: &Emote, started: Instant) -> Option<usize> {
+        let step = started.elapsed().as_millis() as usize / emote.duration_ms.max(1) as usize;
+        (step < emote.frames.len() * EMOTE_LOOPS).then(|| step % emote.frames.len().max(1))
+    }
+
+    /// Let the creature look at fresh feed data; returns its reaction, if
+    /// any
+    pub fn observe(&mut self, data: &FeedData) -> Option<Reaction> {
+        let reaction = self.watcher.observe(data)?;
+        self.reaction = Some((reaction.clone(), Instant::now()));
+        Some(reaction)
+    }
+
+    /// Update animation frame; returns whether anything visible changed
+    pub fn tick(&mut self) -> bool {
+        let mut changed = false;
+
         // Animate every 500ms
         if self.last_frame_time.elapsed().as_millis() > 500 {
             self.animation_frame = self.animation_frame.wrapping_add(1);
             self.last_frame_time = Instant::now();
+            changed = true;
         }
 
         // Hide greeting after 5 seconds
@@ -56,17 +156,40 @@ impl CreatureWidget {
             if timer.elapsed().as_secs() > 5 {
                 self.show_greeting = false;
                 self.greeting_timer = None;
+                changed = true;
             }
         }
+
+        if self
+            .reaction
+            .as_ref()
+            .is_some_and(|(_, started)| started.elapsed().as_secs() >= REACTION_SECS)
+        {
+            self.reaction = None;
+            changed = true;
+        }
+
+        if let Some((emote, started, drawn)) = &mut self.emote {
+            match Self::emote_frame(emote, *started) {
+                Some(frame) if frame == *drawn => {}
+                Some(frame) => {
+                    *drawn = frame;
+                    changed = true;
+                }
+                None => {
+                    self.emote = None;
+                    changed = true;
+                }
+            }
+        }
+
+        changed
     }
 }
 
 impl FeedWidget for CreatureWidget {
     fn id(&self) -> String {
-        format!(
-            "creature-{}-{}",
-            self.config.position.row, self.config.position.col
-        )
+        self.config.id.clone().unwrap_or_default()
     }
 
     fn title(&self) -> &str {
@@ -77,20 +200,23 @@ impl FeedWidget for CreatureWidget {
         (self.config.position.row, self.config.position.col)
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default().fg(Color::White)
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .title(format!(
                 " {} - {} (Lv.{}) ",
                 self.config.title, self.creature.name, self.creature.level
             ))
             .borders(Borders::ALL)
             .border_style(border_style);
+        if selected {
+            block = block.title_bottom(actions_hint(&self.actions()));
+        }
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -130,11 +256,13 @@ impl FeedWidget for CreatureWidget {
     }
 
     fn scroll_up(&mut self) {
-        // Could be used to cycle through emotes or view stats
+        let count = self.creature.unlocked_emotes.len().max(1);
+        self.emote_choice = (self.emote_choice % count + count - 1) % count;
     }
 
     fn scroll_down(&mut self) {
-        // Could be used to cycle through emotes or view stats
+        let count = self.creature.unlocked_emotes.len().max(1);
+        self.emote_choice = (self.emote_choice + 1) % count;
     }
 
     fn set_selected(&mut self, selected: bool) {
@@ -149,6 +277,58 @@ impl FeedWidget for CreatureWidget {
         Some(self)
     }
 
+    fn handle_key(&mut self, key: KeyEvent) -> Handled {
+        let interaction = match key.code {
+            KeyCode::Char('p') => Interaction::Pet,
+            KeyCode::Char('P') => Interaction::Play,
+            KeyCode::Char('e') => Interaction::Emote,
+            _ => return Handled::Ignored,
+        };
+        if self.interact(interaction) {
+            Handled::Request(WidgetRequest::Save)
+        } else {
+            Handled::Consumed
+        }
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        let emotes = get_all_emotes();
+        let emote = self
+            .chosen_emote()
+            .and_then(|id| emotes.get(&id))
+            .map_or("-".to_string(), |e| e.name.clone());
+        vec![
+            Action::new("p", "pet"),
+            Action::new("P", "play"),
+            Action::new("e", emote),
+            Action::new("j/k", "pick emote"),
+        ]
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let creature = &self.creature;
+        Some(SelectedItem {
+            title: format!("{} the {}", creature.name, creature.species.name()),
+            url: None,
+            description: Some(format!(
+                "Feeling {:?}. Energy {}, happiness {}. {} points, {}-day streak (best {}).",
+                creature.mood,
+                creature.stats.energy,
+                creature.stats.happiness,
+                creature.points,
+                creature.streak,
+                creature.best_streak
+            )),
+            image_url: None,
+            source: self.config.title.clone(),
+            metadata: Some(format!(
+                "Lv.{} | {} XP to next level",
+                creature.level,
+                creature.xp_to_next_level()
+            )),
+        })
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
@@ -157,21 +337,19 @@ impl FeedWidget for CreatureWidget {
 impl CreatureWidget {
     fn render_creature_art(&self, frame: &mut Frame, area: Rect) {
         let outfit = self.creature.equipped_outfit.as_deref();
-        let art_lines = get_creature_art(
-            &self.creature.species,
-            &self.creature.mood,
-            outfit,
-            self.animation_frame,
+        let mood = match &self.reaction {
+            Some((reaction, _)) => reaction.mood(),
+            None => self.creature.mood.clone(),
+        };
+        let lines = dress(
+            get_creature_art(&self.creature.species, &mood, outfit, self.animation_frame),
+            &self.creature.appearance,
         );
+        let background = background_color(&self.creature.appearance).unwrap_or(Color::Reset);
 
-        let color = self.creature.appearance.primary_color.to_ratatui_color();
-
-        let lines: Vec<Line> = art_lines
-            .iter()
-            .map(|line| Line::from(Span::styled(line.as_str(), Style::default().fg(color))))
-            .collect();
-
-        let art = Paragraph::new(lines).alignment(Alignment::Center);
+        let art = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .style(Style::default().bg(background));
         frame.render_widget(art, area);
     }
 
@@ -186,16 +364,7 @@ impl CreatureWidget {
             self.creature.level + 1
         );
 
-        let gauge = Gauge::default()
-            .block(Block::default())
-            .gauge_style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .percent((progress * 100.0) as u16)
-            .label(label);
+        let gauge = charts::gauge(progress, label, Color::Cyan);
 
         frame.render_widget(gauge, area);
     }
@@ -216,6 +385,12 @@ impl CreatureWidget {
                 Style::default().fg(Color::Green),
             ),
             Span::raw("  |  "),
+            Span::styled("Streak: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format!("{}d", self.creature.streak),
+                Style::default().fg(Color::LightRed),
+            ),
+            Span::raw("  |  "),
             Span::styled("Mood: ", Style::default().fg(Color::White)),
             Span::styled(
                 self.creature.mood.emoji(),
@@ -228,8 +403,15 @@ impl CreatureWidget {
     }
 
     fn render_message(&self, frame: &mut Frame, area: Rect) {
-        let message = if self.show_greeting {
+        let message = if let Some((emote, _, frame)) = &self.emote {
+            let pose = emote.frames.get(*frame).map_or("", String::as_str);
+            format!("{} *{}* {}", self.creature.name, emote.name, pose)
+        } else if let Some((reaction, _)) = &self.reaction {
+            reaction.message(&self.creature.name)
+        } else if self.show_greeting {
             get_greeting(&self.creature.mood, &self.creature.name)
+        } else if let Some(story) = self.digest_story() {
+            format!("{}: Top story: {}", self.creature.name, story)
         } else {
             let idle = get_idle_message(self.animation_frame);
             format!("{}: {}", self.creature.name, idle)
@@ -0,0 +1,190 @@
+use crate::config::DigestConfig;
+use crate::digest::{self, DigestItem, Ranker};
+use crate::feeds::{FeedData, FeedFetcher};
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{FeedWidget, SelectedItem};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::collections::HashMap;
+
+pub struct DigestWidget {
+    config: DigestConfig,
+    ranker: Ranker,
+    /// Ranked items from each widget's latest data, by widget id
+    candidates: HashMap<String, Vec<DigestItem>>,
+    items: Vec<DigestItem>,
+    scroll_state: ListState,
+    selected: bool,
+}
+
+impl DigestWidget {
+    pub fn new(config: DigestConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            ranker: Ranker::new(
+                &config.keywords,
+                &config.favorite_teams,
+                config.big_move_percent,
+            ),
+            config,
+            candidates: HashMap::new(),
+            items: Vec::new(),
+            scroll_state,
+            selected: false,
+        }
+    }
+
+    /// Take in fresh data from another widget and re-rank
+    pub fn observe(&mut self, widget_id: &str, source: &str, data: &FeedData) {
+        if matches!(
+            data,
+            FeedData::Loading | FeedData::Progress { .. } | FeedData::Error(_)
+        ) {
+            return;
+        }
+        self.candidates
+            .insert(widget_id.to_string(), self.ranker.rank(source, data));
+        self.items = digest::top(
+            self.candidates.values().flatten().cloned(),
+            self.config.max_items,
+        );
+        let last = self.items.len().saturating_sub(1);
+        if self.scroll_state.selected().is_some_and(|i| i > last) {
+            self.scroll_state.select(Some(last));
+        }
+    }
+}
+
+impl FeedWidget for DigestWidget {
+    fn id(&self) -> String {
+        self.config.id.clone().unwrap_or_default()
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.items.is_empty() {
+            let waiting =
+                List::new(vec![ListItem::new("Waiting for the other widgets...")]).block(block);
+            frame.render_widget(waiting, area);
+            return;
+        }
+
+        let (list, mut state) =
+            virtual_list(&self.items, &mut self.scroll_state, area, 2, |i, item| {
+                let title_line = Line::from(vec![
+                    Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
+                    Span::styled(&item.title, Style::default().fg(Color::White)),
+                ]);
+
+                let mut meta_parts = vec![
+                    Span::styled("   ", Style::default()),
+                    Span::styled(&item.source, Style::default().fg(Color::Cyan)),
+                ];
+                if !item.reason.is_empty() {
+                    meta_parts.push(Span::styled(
+                        format!(" | {}", item.reason),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                ListItem::new(vec![title_line, Line::from(meta_parts)])
+            });
+
+        let list = list.block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    /// The digest is fed through `observe` rather than a fetcher of its own
+    fn update_data(&mut self, _data: FeedData) {}
+
+    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+        Box::new(DigestFetcher)
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.items.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let item = self.items.get(self.scroll_state.selected()?)?;
+        Some(SelectedItem {
+            title: item.title.clone(),
+            url: item.url.clone(),
+            description: None,
+            image_url: None,
+            source: item.source.clone(),
+            metadata: (!item.reason.is_empty()).then(|| item.reason.clone()),
+        })
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
+
+struct DigestFetcher;
+
+#[async_trait::async_trait]
+impl FeedFetcher for DigestFetcher {
+    async fn fetch(&self) -> anyhow::Result<FeedData> {
+        Ok(FeedData::Loading)
+    }
+}
@@ -1,7 +1,13 @@
 use crate::config::GithubConfig;
 use crate::feeds::github::GithubFetcher;
-use crate::feeds::{FeedData, FeedFetcher, GithubDashboard};
-use crate::ui::widgets::FeedWidget;
+use crate::feeds::{http, FeedData, FeedFetcher, GithubDashboard};
+use crate::ui::icons::Icon;
+use crate::ui::palette::palette;
+use crate::ui::spinner;
+use crate::ui::widgets::{
+    actions_hint, error_footer, Action, FeedWidget, Handled, SelectedItem, WidgetRequest,
+};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -22,6 +28,8 @@ pub struct GithubWidget {
     dashboard: GithubDashboard,
     current_tab: DashboardTab,
     loading: bool,
+    /// Requests done and in total while loading
+    progress: Option<(usize, usize)>,
     error: Option<String>,
     scroll_state: ListState,
     selected: bool,
@@ -48,6 +56,7 @@ impl GithubWidget {
             dashboard: GithubDashboard::default(),
             current_tab,
             loading: true,
+            progress: None,
             error: None,
             scroll_state,
             selected: false,
@@ -92,6 +101,31 @@ impl GithubWidget {
         self.scroll_state.select(Some(0));
     }
 
+    pub fn has_unread(&self) -> bool {
+        self.dashboard.notifications.iter().any(|n| n.unread)
+    }
+
+    /// Show every notification as read, returning a fetcher to tell GitHub
+    pub fn mark_all_read(&mut self) -> GithubFetcher {
+        for notification in &mut self.dashboard.notifications {
+            notification.unread = false;
+        }
+        self.fetcher()
+    }
+
+    fn fetcher(&self) -> GithubFetcher {
+        GithubFetcher::new(
+            self.config.all_accounts(),
+            self.config.show_notifications,
+            self.config.show_pull_requests,
+            self.config.show_commits,
+            self.config.max_notifications,
+            self.config.max_pull_requests,
+            self.config.max_commits,
+            http::timeout(self.config.timeout_secs),
+        )
+    }
+
     fn get_available_tabs(&self) -> Vec<DashboardTab> {
         let mut tabs = Vec::new();
         if self.config.show_notifications {
@@ -112,13 +146,17 @@ impl GithubWidget {
             .iter()
             .enumerate()
             .map(|(i, notif)| {
-                let unread_indicator = if notif.unread { "● " } else { "○ " };
+                let unread_indicator = if notif.unread {
+                    Icon::Unread
+                } else {
+                    Icon::Read
+                };
                 let title_line = Line::from(vec![
                     Span::styled(
-                        format!("{}{} ", unread_indicator, i + 1),
+                        format!("{} {} ", unread_indicator.glyph(), i + 1),
                         if notif.unread {
                             Style::default()
-                                .fg(Color::Green)
+                                .fg(palette().positive)
                                 .add_modifier(Modifier::BOLD)
                         } else {
                             Style::default().fg(Color::DarkGray)
@@ -129,7 +167,7 @@ impl GithubWidget {
 
                 let meta_line = Line::from(vec![
                     Span::styled(
-                        format!("   {} | ", notif.repository),
+                        format!("   {} | ", repository(&notif.account, &notif.repository)),
                         Style::default().fg(Color::Cyan),
                     ),
                     Span::styled(
@@ -151,16 +189,16 @@ impl GithubWidget {
             .enumerate()
             .map(|(_i, pr)| {
                 let status_icon = if pr.draft {
-                    "📝 "
+                    Icon::DraftPr
                 } else if pr.state == "open" {
-                    "🟢 "
+                    Icon::OpenPr
                 } else {
-                    "🔴 "
+                    Icon::ClosedPr
                 };
 
                 let title_line = Line::from(vec![
                     Span::styled(
-                        format!("{}#{} ", status_icon, pr.number),
+                        format!("{} #{} ", status_icon.glyph(), pr.number),
                         Style::default()
                             .fg(Color::Green)
                             .add_modifier(Modifier::BOLD),
@@ -170,7 +208,7 @@ impl GithubWidget {
 
                 let meta_line = Line::from(vec![
                     Span::styled(
-                        format!("   {} | ", pr.repository),
+                        format!("   {} | ", repository(&pr.account, &pr.repository)),
                         Style::default().fg(Color::Cyan),
                     ),
                     Span::styled(
@@ -196,7 +234,7 @@ impl GithubWidget {
             .map(|(_i, commit)| {
                 let title_line = Line::from(vec![
                     Span::styled(
-                        format!("🔹 {} ", &commit.sha),
+                        format!("{} {} ", Icon::Commit.glyph(), &commit.sha),
                         Style::default()
                             .fg(Color::Yellow)
                             .add_modifier(Modifier::BOLD),
@@ -206,7 +244,7 @@ impl GithubWidget {
 
                 let meta_line = Line::from(vec![
                     Span::styled(
-                        format!("   {} | ", commit.repository),
+                        format!("   {} | ", repository(&commit.account, &commit.repository)),
                         Style::default().fg(Color::Cyan),
                     ),
                     Span::styled(
@@ -220,14 +258,28 @@ impl GithubWidget {
             })
             .collect()
     }
+
+    fn loading_text(&self) -> String {
+        match self.progress {
+            Some((done, total)) if total > 1 => {
+                spinner::loading(&format!("Loaded {}/{} sections", done, total))
+            }
+            _ => spinner::loading("Loading dashboard..."),
+        }
+    }
+}
+
+/// Repository name, prefixed with the account it came from when tagged
+fn repository(account: &Option<String>, repository: &str) -> String {
+    match account {
+        Some(account) => format!("[{}] {}", account, repository),
+        None => repository.to_string(),
+    }
 }
 
 impl FeedWidget for GithubWidget {
     fn id(&self) -> String {
-        format!(
-            "github-{}-{}",
-            self.config.position.row, self.config.position.col
-        )
+        self.config.id.clone().unwrap_or_default()
     }
 
     fn title(&self) -> &str {
@@ -238,7 +290,7 @@ impl FeedWidget for GithubWidget {
         (self.config.position.row, self.config.position.col)
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
         } else {
@@ -279,26 +331,35 @@ impl FeedWidget for GithubWidget {
             .unwrap_or(0);
 
         let title = format!(" {} ", self.config.title);
-        let block = Block::default()
+        let mut block = Block::default()
             .title(title)
             .borders(Borders::ALL)
             .border_style(border_style);
+        if selected {
+            block = block.title_bottom(actions_hint(&self.actions()));
+        }
 
         if self.loading
             && self.dashboard.notifications.is_empty()
             && self.dashboard.pull_requests.is_empty()
             && self.dashboard.commits.is_empty()
         {
-            let loading_text = List::new(vec![ListItem::new("Loading dashboard...")]).block(block);
+            let loading_text = List::new(vec![ListItem::new(self.loading_text())]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
 
         if let Some(ref error) = self.error {
-            let error_text =
-                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
-            frame.render_widget(error_text, area);
-            return;
+            if self.dashboard.notifications.is_empty()
+                && self.dashboard.pull_requests.is_empty()
+                && self.dashboard.commits.is_empty()
+            {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
         }
 
         // Render tabs
@@ -361,6 +422,7 @@ impl FeedWidget for GithubWidget {
         match data {
             FeedData::Github(dashboard) => {
                 self.dashboard = dashboard;
+                self.progress = None;
                 self.error = None;
             }
             FeedData::Error(e) => {
@@ -369,21 +431,16 @@ impl FeedWidget for GithubWidget {
             FeedData::Loading => {
                 self.loading = true;
             }
+            FeedData::Progress { done, total } => {
+                self.loading = true;
+                self.progress = Some((done, total));
+            }
             _ => {}
         }
     }
 
     fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
-        Box::new(GithubFetcher::new(
-            self.config.token.clone(),
-            self.config.username.clone(),
-            self.config.show_notifications,
-            self.config.show_pull_requests,
-            self.config.show_commits,
-            self.config.max_notifications,
-            self.config.max_pull_requests,
-            self.config.max_commits,
-        ))
+        Box::new(self.fetcher())
     }
 
     fn scroll_up(&mut self) {
@@ -408,15 +465,102 @@ impl FeedWidget for GithubWidget {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn handle_key(&mut self, key: KeyEvent) -> Handled {
+        match key.code {
+            KeyCode::Left | KeyCode::Char('h') => self.prev_tab(),
+            KeyCode::Right | KeyCode::Char('l') => self.next_tab(),
+            KeyCode::Char('M') if self.has_unread() => {
+                return Handled::Request(WidgetRequest::MarkAllRead)
+            }
+            _ => return Handled::Ignored,
+        }
+        Handled::Consumed
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        let mut actions = vec![Action::new("h/l", "switch tab")];
+        if self.has_unread() {
+            actions.push(Action::new("M", "mark all read"));
+        }
+        actions
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let item = match self.current_tab {
+            DashboardTab::Notifications => {
+                let notif = self.dashboard.notifications.get(idx)?;
+                SelectedItem {
+                    title: notif.title.clone(),
+                    url: Some(
+                        web_url(&notif.url)
+                            .unwrap_or_else(|| format!("https://github.com/{}", notif.repository)),
+                    ),
+                    description: None,
+                    image_url: None,
+                    source: notif.repository.clone(),
+                    metadata: Some(format!("{} | {}", notif.notification_type, notif.reason)),
+                }
+            }
+            DashboardTab::PullRequests => {
+                let pr = self.dashboard.pull_requests.get(idx)?;
+                SelectedItem {
+                    title: format!("#{} {}", pr.number, pr.title),
+                    url: Some(format!(
+                        "https://github.com/{}/pull/{}",
+                        pr.repository, pr.number
+                    )),
+                    description: None,
+                    image_url: None,
+                    source: pr.repository.clone(),
+                    metadata: Some(format!(
+                        "{} | by {} | {} comments",
+                        pr.state, pr.author, pr.comments
+                    )),
+                }
+            }
+            DashboardTab::Commits => {
+                let commit = self.dashboard.commits.get(idx)?;
+                SelectedItem {
+                    title: commit.message.clone(),
+                    url: Some(commit.url.clone()),
+                    description: None,
+                    image_url: None,
+                    source: commit.repository.clone(),
+                    metadata: Some(format!("{} | by {}", commit.sha, commit.author)),
+                }
+            }
+        };
+        Some(item)
+    }
+
     fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
         Some(self)
     }
 
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
 }
+
+/// Turn an API URL such as `https://api.github.com/repos/o/r/pulls/1` into
+/// the page a browser can show
+fn web_url(api_url: &str) -> Option<String> {
+    let path = api_url.strip_prefix("https://api.github.com/repos/")?;
+    let path = path
+        .replacen("/pulls/", "/pull/", 1)
+        .replacen("/commits/", "/commit/", 1);
+    Some(format!("https://github.com/{}", path))
+}
@@ -1,7 +1,10 @@
-use crate::config::HackernewsConfig;
+use crate::config::{HackernewsConfig, SortOrder};
 use crate::feeds::hackernews::HnFetcher;
-use crate::feeds::{FeedData, FeedFetcher, HnStory};
-use crate::ui::widgets::{FeedWidget, SelectedItem};
+use crate::feeds::{http, FeedData, FeedFetcher, HnStory};
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{actions_hint, error_footer, Action, FeedWidget, Handled, SelectedItem};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -9,10 +12,16 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
+use std::cmp::Reverse;
+
+const SORT_ORDERS: &[SortOrder] = &[SortOrder::Feed, SortOrder::Score, SortOrder::Title];
 
 pub struct HackernewsWidget {
     config: HackernewsConfig,
+    /// Stories in HN ranking, kept to return to the feed order
+    received: Vec<HnStory>,
     stories: Vec<HnStory>,
+    sort: SortOrder,
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
@@ -24,23 +33,32 @@ impl HackernewsWidget {
         let mut scroll_state = ListState::default();
         scroll_state.select(Some(0));
 
+        let sort = config.sort.within(SORT_ORDERS);
         Self {
             config,
+            received: Vec::new(),
             stories: Vec::new(),
+            sort,
             loading: true,
             error: None,
             scroll_state,
             selected: false,
         }
     }
+
+    fn apply_sort(&mut self) {
+        self.stories = self.received.clone();
+        match self.sort {
+            SortOrder::Score => self.stories.sort_by_key(|s| Reverse(s.score)),
+            SortOrder::Title => self.stories.sort_by_cached_key(|s| s.title.to_lowercase()),
+            _ => {}
+        }
+    }
 }
 
 impl FeedWidget for HackernewsWidget {
     fn id(&self) -> String {
-        format!(
-            "hackernews-{}-{}",
-            self.config.position.row, self.config.position.col
-        )
+        self.config.id.clone().unwrap_or_default()
     }
 
     fn title(&self) -> &str {
@@ -51,36 +69,44 @@ impl FeedWidget for HackernewsWidget {
         (self.config.position.row, self.config.position.col)
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default().fg(Color::White)
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .title(format!(" {} ", self.config.title))
             .borders(Borders::ALL)
             .border_style(border_style);
+        if selected {
+            block = block.title_bottom(actions_hint(&self.actions()));
+        }
 
         if self.loading && self.stories.is_empty() {
-            let loading_text = List::new(vec![ListItem::new("Loading...")]).block(block);
+            let loading_text =
+                List::new(vec![ListItem::new(spinner::loading("Loading..."))]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
 
         if let Some(ref error) = self.error {
-            let error_text =
-                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
-            frame.render_widget(error_text, area);
-            return;
+            if self.stories.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
         }
 
-        let items: Vec<ListItem> = self
-            .stories
-            .iter()
-            .enumerate()
-            .map(|(i, story)| {
+        let (list, mut state) = virtual_list(
+            &self.stories,
+            &mut self.scroll_state,
+            area,
+            2,
+            |i, story| {
                 let title_line = Line::from(vec![
                     Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
                     Span::styled(&story.title, Style::default().fg(Color::White)),
@@ -102,16 +128,15 @@ impl FeedWidget for HackernewsWidget {
                 ]);
 
                 ListItem::new(vec![title_line, meta_line])
-            })
-            .collect();
+            },
+        );
 
-        let list = List::new(items).block(block).highlight_style(
+        let list = list.block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
         );
 
-        let mut state = self.scroll_state.clone();
         frame.render_stateful_widget(list, area, &mut state);
     }
 
@@ -119,7 +144,8 @@ impl FeedWidget for HackernewsWidget {
         self.loading = false;
         match data {
             FeedData::HackerNews(stories) => {
-                self.stories = stories;
+                self.received = stories;
+                self.apply_sort();
                 self.error = None;
             }
             FeedData::Error(e) => {
@@ -136,6 +162,7 @@ impl FeedWidget for HackernewsWidget {
         Box::new(HnFetcher::new(
             self.config.story_type.clone(),
             self.config.story_count,
+            http::timeout(self.config.timeout_secs),
         ))
     }
 
@@ -155,10 +182,30 @@ impl FeedWidget for HackernewsWidget {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn handle_key(&mut self, key: KeyEvent) -> Handled {
+        match key.code {
+            KeyCode::Char('S') => {
+                self.sort = self.sort.next_within(SORT_ORDERS);
+                self.apply_sort();
+                self.scroll_state.select(Some(0));
+                Handled::Consumed
+            }
+            _ => Handled::Ignored,
+        }
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        vec![Action::new("S", format!("sort: {}", self.sort.name()))]
+    }
+
     fn get_selected_item(&self) -> Option<SelectedItem> {
         let idx = self.scroll_state.selected()?;
         let story = self.stories.get(idx)?;
@@ -173,6 +220,7 @@ impl FeedWidget for HackernewsWidget {
             title: story.title.clone(),
             url,
             description: None,
+            image_url: None,
             source: "Hacker News".to_string(),
             metadata: Some(format!(
                 "{} points | {} comments | by {}",
@@ -181,8 +229,12 @@ impl FeedWidget for HackernewsWidget {
         })
     }
 
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     /// Get the HN discussion URL for the selected story
-     fn get_selected_discussion_url(&self) -> Option<String>{
+    fn get_selected_discussion_url(&self) -> Option<String> {
         let idx = self.scroll_state.selected()?;
         let story = self.stories.get(idx)?;
         Some(format!("https://news.ycombinator.com/item?id={}", story.id))
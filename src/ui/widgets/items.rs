@@ -0,0 +1,185 @@
+use crate::config::Position;
+use crate::feeds::{FeedData, FeedFetcher, GenericItem};
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{error_footer, FeedWidget, SelectedItem};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+type FetcherFactory = Box<dyn Fn() -> Box<dyn FeedFetcher> + Send + Sync>;
+
+/// A plain item list shared by sources that produce `FeedData::Items`
+pub struct ItemsWidget {
+    id: String,
+    title: String,
+    position: Position,
+    make_fetcher: FetcherFactory,
+    items: Vec<GenericItem>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    selected: bool,
+}
+
+impl ItemsWidget {
+    pub fn new(
+        id: String,
+        title: String,
+        position: Position,
+        make_fetcher: impl Fn() -> Box<dyn FeedFetcher> + Send + Sync + 'static,
+    ) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            id,
+            title,
+            position,
+            make_fetcher: Box::new(make_fetcher),
+            items: Vec::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            selected: false,
+        }
+    }
+}
+
+impl FeedWidget for ItemsWidget {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.position.row, self.position.col)
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let mut block = Block::default()
+            .title(format!(" {} ", self.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.items.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(spinner::loading("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            if self.items.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
+        }
+
+        let (list, mut state) =
+            virtual_list(&self.items, &mut self.scroll_state, area, 1, |i, item| {
+                let mut lines = vec![Line::from(vec![
+                    Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
+                    Span::styled(&item.title, Style::default().fg(Color::White)),
+                ])];
+                if let Some(ref meta) = item.meta {
+                    lines.push(Line::from(vec![
+                        Span::raw("   "),
+                        Span::styled(meta, Style::default().fg(Color::DarkGray)),
+                    ]));
+                }
+                ListItem::new(lines)
+            });
+
+        let list = list.block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Items(items) => {
+                self.items = items;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+        (self.make_fetcher)()
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.items.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let item = self.items.get(idx)?;
+
+        Some(SelectedItem {
+            title: item.title.clone(),
+            url: item.url.clone(),
+            description: item.description.clone(),
+            image_url: None,
+            source: self.title.clone(),
+            metadata: item.meta.clone(),
+        })
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
@@ -1,14 +1,31 @@
 pub mod creature;
+pub mod digest;
 pub mod github;
 pub mod hackernews;
+pub mod items;
+pub mod odds;
 pub mod rss;
+pub mod script;
 pub mod sports;
 pub mod stocks;
+pub mod virtual_list;
+pub mod wallet;
 pub mod youtube;
 
-use crate::feeds::{FeedData, FeedFetcher};
-use ratatui::{layout::Rect, Frame};
+use crate::config::WidgetConfig;
+use crate::creature::Creature;
+use crate::feeds::graphql::{GraphqlFetcher, ItemMapping};
+use crate::feeds::scrape::ScrapeFetcher;
+use crate::feeds::sse::SseFetcher;
+use crate::feeds::wasm::WasmFetcher;
+use crate::feeds::webhook::{WebhookFetcher, WebhookState};
+use crate::feeds::{http, FeedData, FeedFetcher};
+use crate::ui::icons::Icon;
+use crate::ui::palette::palette;
+use crossterm::event::KeyEvent;
+use ratatui::{layout::Rect, style::Style, text::Line, Frame};
 use std::any::Any;
+use std::sync::Arc;
 
 /// Information about a selected feed item for reading or opening
 #[derive(Debug, Clone)]
@@ -16,26 +33,111 @@ pub struct SelectedItem {
     pub title: String,
     pub url: Option<String>,
     pub description: Option<String>,
+    /// Thumbnail shown by the article reader; otherwise the page's
+    /// `og:image` is used
+    pub image_url: Option<String>,
     pub source: String,
     pub metadata: Option<String>,
 }
 
+/// What became of a key offered to the focused widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handled {
+    /// Not one of the widget's keys, so the app's bindings apply
+    Ignored,
+    /// Dealt with by the widget
+    Consumed,
+    /// Taken by the widget, which needs the app to finish the job
+    Request(WidgetRequest),
+}
+
+/// Follow-up work a widget can't do on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WidgetRequest {
+    /// Save the creature, whose state the widget changed
+    Save,
+    /// Mark every GitHub notification read, after confirming
+    MarkAllRead,
+    /// Ask for a feed URL to follow
+    AddFeed,
+    /// Write the upcoming games to a calendar file
+    ExportCalendar,
+}
+
+/// A key a widget handles itself, listed in its footer while focused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Action {
+    pub key: &'static str,
+    pub description: String,
+}
+
+impl Action {
+    pub fn new(key: &'static str, description: impl Into<String>) -> Self {
+        Self {
+            key,
+            description: description.into(),
+        }
+    }
+}
+
+/// Actions as a footer hint such as ` p: pet | P: play `
+pub fn actions_hint(actions: &[Action]) -> String {
+    let keys: Vec<String> = actions
+        .iter()
+        .map(|action| format!("{}: {}", action.key, action.description))
+        .collect();
+    format!(" {} ", keys.join(" | "))
+}
+
+/// Footer for a failed refresh, shown under the data from the last good one
+pub fn error_footer(error: &str) -> Line<'static> {
+    let error = error.lines().next().unwrap_or_default();
+    Line::styled(
+        format!(" {} {} (r: retry) ", Icon::Warning.glyph(), error),
+        Style::default().fg(palette().negative),
+    )
+    .right_aligned()
+}
+
 pub trait FeedWidget: Send + Sync {
     fn id(&self) -> String;
     fn title(&self) -> &str;
     fn position(&self) -> (usize, usize);
-    fn render(&self, frame: &mut Frame, area: Rect, selected: bool);
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool);
     fn update_data(&mut self, data: FeedData);
     fn create_fetcher(&self) -> Box<dyn FeedFetcher>;
     fn scroll_up(&mut self);
     fn scroll_down(&mut self);
     fn set_selected(&mut self, selected: bool);
 
-    /// Get the currently selected item's information
-    fn get_selected_item(&self) -> Option<SelectedItem> {
+    /// Jump back to the first item
+    fn scroll_to_top(&mut self) {}
+
+    /// Keys of the widget's own, offered before the app's bindings while
+    /// it has focus
+    fn handle_key(&mut self, _key: KeyEvent) -> Handled {
+        Handled::Ignored
+    }
+
+    /// The keys `handle_key` takes, for the footer hint
+    fn actions(&self) -> Vec<Action> {
+        Vec::new()
+    }
+
+    /// Why the last refresh failed, if it did
+    fn error(&self) -> Option<&str> {
         None
     }
 
+    /// Pick up background work the widget started itself; returns whether
+    /// anything changed on screen
+    fn poll(&mut self) -> bool {
+        false
+    }
+
+    /// Get the currently selected item's information, for the detail view
+    fn get_selected_item(&self) -> Option<SelectedItem>;
+
     /// For downcasting to concrete types
     fn as_any(&self) -> Option<&dyn Any> {
         None
@@ -46,6 +148,109 @@ pub trait FeedWidget: Send + Sync {
         None
     }
     fn get_selected_discussion_url(&self) -> Option<String>;
+}
 
-    
+/// Build the widget described by a config entry
+pub fn create_widget(config: &WidgetConfig, creature: &Creature) -> Box<dyn FeedWidget> {
+    match config {
+        WidgetConfig::Hackernews(cfg) => Box::new(hackernews::HackernewsWidget::new(cfg.clone())),
+        WidgetConfig::Stocks(cfg) => Box::new(stocks::StocksWidget::new(cfg.clone())),
+        WidgetConfig::Rss(cfg) => Box::new(rss::RssWidget::new(cfg.clone())),
+        WidgetConfig::Sports(cfg) => Box::new(sports::SportsWidget::new(cfg.clone())),
+        WidgetConfig::Github(cfg) => Box::new(github::GithubWidget::new(cfg.clone())),
+        WidgetConfig::Script(cfg) => Box::new(script::ScriptWidget::new(cfg.clone())),
+        WidgetConfig::Wasm(cfg) => {
+            let cfg = cfg.clone();
+            Box::new(items::ItemsWidget::new(
+                config.id().to_string(),
+                cfg.title.clone(),
+                cfg.position.clone(),
+                move || {
+                    Box::new(WasmFetcher::new(
+                        &cfg.module,
+                        cfg.args.clone(),
+                        http::timeout(cfg.timeout_secs),
+                    ))
+                },
+            ))
+        }
+        WidgetConfig::Scrape(cfg) => {
+            let cfg = cfg.clone();
+            Box::new(items::ItemsWidget::new(
+                config.id().to_string(),
+                cfg.title.clone(),
+                cfg.position.clone(),
+                move || {
+                    Box::new(ScrapeFetcher::new(
+                        cfg.url.clone(),
+                        cfg.item_selector.clone(),
+                        cfg.title_selector.clone(),
+                        cfg.link_selector.clone(),
+                        cfg.description_selector.clone(),
+                        cfg.max_items,
+                        http::timeout(cfg.timeout_secs),
+                    ))
+                },
+            ))
+        }
+        WidgetConfig::Graphql(cfg) => {
+            let cfg = cfg.clone();
+            Box::new(items::ItemsWidget::new(
+                config.id().to_string(),
+                cfg.title.clone(),
+                cfg.position.clone(),
+                move || {
+                    Box::new(GraphqlFetcher::new(
+                        cfg.endpoint.clone(),
+                        cfg.query.clone(),
+                        cfg.variables.clone(),
+                        cfg.auth_header.clone(),
+                        ItemMapping {
+                            items_path: cfg.items_path.clone(),
+                            title_field: cfg.title_field.clone(),
+                            url_field: cfg.url_field.clone(),
+                            description_field: cfg.description_field.clone(),
+                            meta_field: cfg.meta_field.clone(),
+                        },
+                        cfg.max_items,
+                        http::timeout(cfg.timeout_secs),
+                    ))
+                },
+            ))
+        }
+        WidgetConfig::Webhook(cfg) => {
+            // Shared so every fetcher reads from the same listener
+            let state = Arc::new(WebhookState::new(&cfg.bind, cfg.port, cfg.max_events));
+            Box::new(items::ItemsWidget::new(
+                config.id().to_string(),
+                cfg.title.clone(),
+                cfg.position.clone(),
+                move || Box::new(WebhookFetcher::new(state.clone())),
+            ))
+        }
+        WidgetConfig::Sse(cfg) => {
+            let cfg = cfg.clone();
+            Box::new(items::ItemsWidget::new(
+                config.id().to_string(),
+                cfg.title.clone(),
+                cfg.position.clone(),
+                move || {
+                    Box::new(SseFetcher::new(
+                        cfg.url.clone(),
+                        cfg.title_template.clone(),
+                        cfg.url_template.clone(),
+                        cfg.max_events,
+                        http::timeout(cfg.timeout_secs),
+                    ))
+                },
+            ))
+        }
+        WidgetConfig::Youtube(cfg) => Box::new(youtube::YoutubeWidget::new(cfg.clone())),
+        WidgetConfig::Creature(cfg) => {
+            Box::new(creature::CreatureWidget::new(cfg.clone(), creature.clone()))
+        }
+        WidgetConfig::Digest(cfg) => Box::new(digest::DigestWidget::new(cfg.clone())),
+        WidgetConfig::Wallet(cfg) => Box::new(wallet::WalletWidget::new(cfg.clone())),
+        WidgetConfig::Odds(cfg) => Box::new(odds::OddsWidget::new(cfg.clone())),
+    }
 }
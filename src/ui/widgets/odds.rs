@@ -0,0 +1,326 @@
+use crate::config::OddsConfig;
+use crate::feeds::odds::OddsFetcher;
+use crate::feeds::{http, FeedData, FeedFetcher, GameOdds};
+use crate::ui::icons::Icon;
+use crate::ui::palette::palette;
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{error_footer, FeedWidget, SelectedItem};
+use chrono::Local;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Which way each line moved since the previous refresh; `None` if it held
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Movement {
+    moneyline: Option<Ordering>,
+    spread: Option<Ordering>,
+    total: Option<Ordering>,
+}
+
+impl Movement {
+    fn between(old: &GameOdds, new: &GameOdds) -> Self {
+        let moved = |old: Option<f64>, new: Option<f64>| match (old, new) {
+            (Some(old), Some(new)) => new.partial_cmp(&old).filter(|o| o.is_ne()),
+            _ => None,
+        };
+        Self {
+            moneyline: moved(old.moneyline.map(|m| m.home), new.moneyline.map(|m| m.home)),
+            spread: moved(old.spread.map(|s| s.point), new.spread.map(|s| s.point)),
+            total: moved(old.total.map(|t| t.point), new.total.map(|t| t.point)),
+        }
+    }
+}
+
+pub struct OddsWidget {
+    config: OddsConfig,
+    games: Vec<GameOdds>,
+    /// Line movement by game id
+    movement: HashMap<String, Movement>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    selected: bool,
+}
+
+impl OddsWidget {
+    pub fn new(config: OddsConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            games: Vec::new(),
+            movement: HashMap::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            selected: false,
+        }
+    }
+}
+
+/// A line with an arrow if it moved since the last refresh
+fn line_spans<'a>(label: String, moved: Option<Ordering>) -> Vec<Span<'a>> {
+    let mut spans = vec![Span::styled(label, Style::default().fg(Color::White))];
+    match moved {
+        Some(Ordering::Greater) => spans.push(Span::styled(
+            Icon::Gain.glyph(),
+            Style::default().fg(palette().positive),
+        )),
+        Some(Ordering::Less) => spans.push(Span::styled(
+            Icon::Loss.glyph(),
+            Style::default().fg(palette().negative),
+        )),
+        _ => {}
+    }
+    spans
+}
+
+impl FeedWidget for OddsWidget {
+    fn id(&self) -> String {
+        self.config.id.clone().unwrap_or_default()
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let mut block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.games.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(spinner::loading("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            if self.games.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
+        }
+
+        if self.games.is_empty() {
+            let no_games = List::new(vec![ListItem::new("No upcoming games")]).block(block);
+            frame.render_widget(no_games, area);
+            return;
+        }
+
+        let (list, mut state) =
+            virtual_list(&self.games, &mut self.scroll_state, area, 2, |_, game| {
+                let mut game_line = vec![
+                    Span::styled(
+                        format!("[{}] ", game.league),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(
+                        format!("{} @ {}", game.away_team, game.home_team),
+                        Style::default().fg(Color::White),
+                    ),
+                ];
+                if let Some(start) = game.commence_time {
+                    game_line.push(Span::styled(
+                        format!("  {}", start.with_timezone(&Local).format("%a %H:%M")),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                let moved = self.movement.get(&game.id).copied().unwrap_or_default();
+                let mut lines_line = vec![Span::raw("    ")];
+                if let Some(ml) = game.moneyline {
+                    lines_line.extend(line_spans(
+                        format!("ML {:+}/{:+}", ml.away, ml.home),
+                        moved.moneyline,
+                    ));
+                    lines_line.push(Span::raw("  "));
+                }
+                if let Some(spread) = game.spread {
+                    lines_line.extend(line_spans(
+                        format!("Spread {:+}", spread.point),
+                        moved.spread,
+                    ));
+                    lines_line.push(Span::raw("  "));
+                }
+                if let Some(total) = game.total {
+                    lines_line.extend(line_spans(format!("O/U {}", total.point), moved.total));
+                }
+
+                ListItem::new(vec![Line::from(game_line), Line::from(lines_line)])
+            });
+
+        let list = list.block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Odds(games) => {
+                let previous: HashMap<&str, &GameOdds> =
+                    self.games.iter().map(|g| (g.id.as_str(), g)).collect();
+                self.movement = games
+                    .iter()
+                    .filter_map(|game| {
+                        let old = previous.get(game.id.as_str())?;
+                        Some((game.id.clone(), Movement::between(old, game)))
+                    })
+                    .collect();
+                self.games = games;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+        Box::new(OddsFetcher::new(
+            self.config.api_key.clone(),
+            self.config.leagues.clone(),
+            self.config.favorite_teams.clone(),
+            self.config.regions.clone(),
+            self.config.bookmaker.clone(),
+            http::timeout(self.config.timeout_secs),
+        ))
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.games.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let game = self.games.get(self.scroll_state.selected()?)?;
+        let mut lines = Vec::new();
+        if let Some(ml) = game.moneyline {
+            lines.push(format!(
+                "Moneyline: {} {:+}, {} {:+}",
+                game.away_team, ml.away, game.home_team, ml.home
+            ));
+        }
+        if let Some(spread) = game.spread {
+            lines.push(format!(
+                "Spread: {} {:+} ({:+}), {} {:+} ({:+})",
+                game.home_team,
+                spread.point,
+                spread.home_price,
+                game.away_team,
+                -spread.point,
+                spread.away_price
+            ));
+        }
+        if let Some(total) = game.total {
+            lines.push(format!(
+                "Total: {} (over {:+}, under {:+})",
+                total.point, total.over_price, total.under_price
+            ));
+        }
+        Some(SelectedItem {
+            title: format!("{} @ {}", game.away_team, game.home_team),
+            url: None,
+            description: Some(lines.join("\n\n")),
+            image_url: None,
+            source: format!("{} · {}", game.league, game.bookmaker),
+            metadata: game
+                .commence_time
+                .map(|t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()),
+        })
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::{Moneyline, Total};
+
+    #[test]
+    fn arrows_follow_the_lines_that_moved() {
+        let game = |home: f64, total: f64| GameOdds {
+            id: "abc".to_string(),
+            league: "NBA".to_string(),
+            home_team: "Boston Celtics".to_string(),
+            away_team: "Miami Heat".to_string(),
+            commence_time: None,
+            bookmaker: "FanDuel".to_string(),
+            moneyline: Some(Moneyline { home, away: 350.0 }),
+            spread: None,
+            total: Some(Total {
+                point: total,
+                over_price: -110.0,
+                under_price: -110.0,
+            }),
+        };
+        let moved = Movement::between(&game(-450.0, 207.5), &game(-500.0, 207.5));
+        assert_eq!(
+            moved,
+            Movement {
+                moneyline: Some(Ordering::Less),
+                spread: None,
+                total: None,
+            }
+        );
+    }
+}
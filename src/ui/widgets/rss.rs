@@ -1,7 +1,12 @@
-use crate::config::RssConfig;
+use crate::config::{RssConfig, SortOrder};
 use crate::feeds::rss::RssFetcher;
-use crate::feeds::{FeedData, FeedFetcher, RssItem};
-use crate::ui::widgets::{FeedWidget, SelectedItem};
+use crate::feeds::{http, FeedData, FeedFetcher, RssItem};
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{
+    actions_hint, error_footer, Action, FeedWidget, Handled, SelectedItem, WidgetRequest,
+};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -9,11 +14,17 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
+use std::cmp::Reverse;
+
+const SORT_ORDERS: &[SortOrder] = &[SortOrder::Date, SortOrder::Source, SortOrder::Title];
 
 pub struct RssWidget {
     config: RssConfig,
     items: Vec<RssItem>,
+    sort: SortOrder,
     loading: bool,
+    /// Requests done and in total while loading
+    progress: Option<(usize, usize)>,
     error: Option<String>,
     scroll_state: ListState,
     selected: bool,
@@ -24,23 +35,52 @@ impl RssWidget {
         let mut scroll_state = ListState::default();
         scroll_state.select(Some(0));
 
+        let sort = config.sort.within(SORT_ORDERS);
         Self {
             config,
             items: Vec::new(),
+            sort,
             loading: true,
+            progress: None,
             error: None,
             scroll_state,
             selected: false,
         }
     }
+
+    /// Start following another feed; false if it is already followed
+    pub fn add_feed(&mut self, url: String) -> bool {
+        if self.config.feeds.contains(&url) {
+            return false;
+        }
+        self.config.feeds.push(url);
+        self.loading = true;
+        true
+    }
+
+    /// Items always go newest first, so that order holds within a source
+    fn apply_sort(&mut self) {
+        self.items.sort_by_key(|item| Reverse(item.published_at));
+        match self.sort {
+            SortOrder::Source => self.items.sort_by(|a, b| a.source.cmp(&b.source)),
+            SortOrder::Title => self.items.sort_by_cached_key(|i| i.title.to_lowercase()),
+            _ => {}
+        }
+    }
+
+    fn loading_text(&self) -> String {
+        match self.progress {
+            Some((done, total)) if total > 1 => {
+                spinner::loading(&format!("Loaded {}/{} feeds", done, total))
+            }
+            _ => spinner::loading("Loading..."),
+        }
+    }
 }
 
 impl FeedWidget for RssWidget {
     fn id(&self) -> String {
-        format!(
-            "rss-{}-{}",
-            self.config.position.row, self.config.position.col
-        )
+        self.config.id.clone().unwrap_or_default()
     }
 
     fn title(&self) -> &str {
@@ -51,36 +91,39 @@ impl FeedWidget for RssWidget {
         (self.config.position.row, self.config.position.col)
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default().fg(Color::White)
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .title(format!(" {} ", self.config.title))
             .borders(Borders::ALL)
             .border_style(border_style);
+        if selected {
+            block = block.title_bottom(actions_hint(&self.actions()));
+        }
 
         if self.loading && self.items.is_empty() {
-            let loading_text = List::new(vec![ListItem::new("Loading...")]).block(block);
+            let loading_text = List::new(vec![ListItem::new(self.loading_text())]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
 
         if let Some(ref error) = self.error {
-            let error_text =
-                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
-            frame.render_widget(error_text, area);
-            return;
+            if self.items.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
         }
 
-        let items: Vec<ListItem> = self
-            .items
-            .iter()
-            .enumerate()
-            .map(|(i, item)| {
+        let (list, mut state) =
+            virtual_list(&self.items, &mut self.scroll_state, area, 2, |i, item| {
                 let title_line = Line::from(vec![
                     Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
                     Span::styled(&item.title, Style::default().fg(Color::White)),
@@ -101,16 +144,14 @@ impl FeedWidget for RssWidget {
                 let meta_line = Line::from(meta_parts);
 
                 ListItem::new(vec![title_line, meta_line])
-            })
-            .collect();
+            });
 
-        let list = List::new(items).block(block).highlight_style(
+        let list = list.block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
         );
 
-        let mut state = self.scroll_state.clone();
         frame.render_stateful_widget(list, area, &mut state);
     }
 
@@ -119,6 +160,8 @@ impl FeedWidget for RssWidget {
         match data {
             FeedData::Rss(items) => {
                 self.items = items;
+                self.apply_sort();
+                self.progress = None;
                 self.error = None;
             }
             FeedData::Error(e) => {
@@ -127,6 +170,10 @@ impl FeedWidget for RssWidget {
             FeedData::Loading => {
                 self.loading = true;
             }
+            FeedData::Progress { done, total } => {
+                self.loading = true;
+                self.progress = Some((done, total));
+            }
             _ => {}
         }
     }
@@ -135,6 +182,7 @@ impl FeedWidget for RssWidget {
         Box::new(RssFetcher::new(
             self.config.feeds.clone(),
             self.config.max_items,
+            http::timeout(self.config.timeout_secs),
         ))
     }
 
@@ -154,10 +202,34 @@ impl FeedWidget for RssWidget {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn handle_key(&mut self, key: KeyEvent) -> Handled {
+        match key.code {
+            KeyCode::Char('a') => Handled::Request(WidgetRequest::AddFeed),
+            KeyCode::Char('S') => {
+                self.sort = self.sort.next_within(SORT_ORDERS);
+                self.apply_sort();
+                self.scroll_state.select(Some(0));
+                Handled::Consumed
+            }
+            _ => Handled::Ignored,
+        }
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        vec![
+            Action::new("a", "add feed"),
+            Action::new("S", format!("sort: {}", self.sort.name())),
+        ]
+    }
+
     fn get_selected_item(&self) -> Option<SelectedItem> {
         let idx = self.scroll_state.selected()?;
         let item = self.items.get(idx)?;
@@ -166,12 +238,22 @@ impl FeedWidget for RssWidget {
             title: item.title.clone(),
             url: item.link.clone(),
             description: item.description.clone(),
+            image_url: None,
             source: item.source.clone(),
             metadata: item.published.clone(),
         })
     }
 
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
-        None
+        let idx = self.scroll_state.selected()?;
+        self.items.get(idx)?.discussion.clone()
     }
 }
@@ -0,0 +1,170 @@
+use crate::config::ScriptConfig;
+use crate::feeds::script::ScriptFetcher;
+use crate::feeds::{http, FeedData, FeedFetcher, ScriptLine};
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{error_footer, FeedWidget, SelectedItem};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+pub struct ScriptWidget {
+    config: ScriptConfig,
+    lines: Vec<ScriptLine>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    selected: bool,
+}
+
+impl ScriptWidget {
+    pub fn new(config: ScriptConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            lines: Vec::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            selected: false,
+        }
+    }
+}
+
+impl FeedWidget for ScriptWidget {
+    fn id(&self) -> String {
+        self.config.id.clone().unwrap_or_default()
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let mut block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.lines.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(spinner::loading("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            if self.lines.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
+        }
+
+        let (list, mut state) =
+            virtual_list(&self.lines, &mut self.scroll_state, area, 1, |_, line| {
+                let style = if line.url.is_some() {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                ListItem::new(line.text.as_str()).style(style)
+            });
+
+        let list = list.block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Script(lines) => {
+                self.lines = lines;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+        Box::new(ScriptFetcher::new(
+            &self.config.script,
+            self.config.args.clone(),
+            http::timeout(self.config.timeout_secs),
+        ))
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.lines.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let line = self.lines.get(idx)?;
+
+        Some(SelectedItem {
+            title: line.text.clone(),
+            url: line.url.clone(),
+            description: None,
+            image_url: None,
+            source: self.config.title.clone(),
+            metadata: None,
+        })
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
@@ -1,7 +1,17 @@
 use crate::config::SportsConfig;
 use crate::feeds::sports::SportsFetcher;
-use crate::feeds::{FeedData, FeedFetcher, SportsEvent};
-use crate::ui::widgets::FeedWidget;
+use crate::feeds::{http, FeedData, FeedFetcher, SportsEvent};
+use crate::ical;
+use crate::ui::charts;
+use crate::ui::icons::Icon;
+use crate::ui::palette::palette;
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{
+    actions_hint, error_footer, Action, FeedWidget, Handled, SelectedItem, WidgetRequest,
+};
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -9,10 +19,24 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Cells in the win probability bar
+const WIN_BAR_WIDTH: usize = 8;
+/// Win chances this close to even mark a game as close
+const CLOSE_GAME_MARGIN: f64 = 0.15;
+
+fn game_key(event: &SportsEvent) -> String {
+    format!("{}|{}|{}", event.league, event.away_team, event.home_team)
+}
 
 pub struct SportsWidget {
     config: SportsConfig,
     events: Vec<SportsEvent>,
+    /// How far each live game's home win chance moved since the last
+    /// refresh, by game
+    swings: HashMap<String, f64>,
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
@@ -27,20 +51,85 @@ impl SportsWidget {
         Self {
             config,
             events: Vec::new(),
+            swings: HashMap::new(),
             loading: true,
             error: None,
             scroll_state,
             selected: false,
         }
     }
+
+    /// A bar of the leading team's chance to win, with how far it swung
+    /// since the last refresh
+    fn win_probability_spans(&self, event: &SportsEvent) -> Vec<Span<'static>> {
+        let Some(home) = event.home_win_probability else {
+            return Vec::new();
+        };
+        let (leader, chance) = if home >= 0.5 {
+            (&event.home_team, home)
+        } else {
+            (&event.away_team, 1.0 - home)
+        };
+        let color = if (home - 0.5).abs() < CLOSE_GAME_MARGIN {
+            Color::Yellow
+        } else {
+            Color::Cyan
+        };
+
+        let mut spans = vec![Span::raw("  ")];
+        spans.extend(charts::bar(chance, WIN_BAR_WIDTH, color));
+        spans.push(Span::styled(
+            format!(" {:.0}% {}", chance * 100.0, leader),
+            Style::default().fg(color),
+        ));
+        if let Some(&swing) = self.swings.get(&game_key(event)) {
+            // Swings are shown for the leader, who may just have taken over
+            let swing = if home >= 0.5 { swing } else { -swing };
+            let swing_color = if swing >= 0.0 {
+                palette().positive
+            } else {
+                palette().negative
+            };
+            spans.push(Span::styled(
+                format!(" {:+.0}%", swing * 100.0),
+                Style::default().fg(swing_color),
+            ));
+        }
+        spans
+    }
+
+    /// `calendar` from the config, or a file named after the widget
+    fn calendar_path(&self) -> PathBuf {
+        let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        match self.config.calendar.as_deref() {
+            Some(path) => match path.strip_prefix("~/") {
+                Some(rest) => home.join(rest),
+                None => PathBuf::from(path),
+            },
+            None => home.join(".feedtui").join(format!("{}.ics", self.id())),
+        }
+    }
+
+    /// Write the upcoming games to the calendar file, returning where it went
+    /// and how many games it holds
+    pub fn export_calendar(&self) -> Result<(PathBuf, usize)> {
+        let path = self.calendar_path();
+        let (calendar, count) = ical::games_calendar(
+            &self.events,
+            &self.config.favorite_teams,
+            chrono::Utc::now(),
+        );
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, calendar).with_context(|| format!("writing {}", path.display()))?;
+        Ok((path, count))
+    }
 }
 
 impl FeedWidget for SportsWidget {
     fn id(&self) -> String {
-        format!(
-            "sports-{}-{}",
-            self.config.position.row, self.config.position.col
-        )
+        self.config.id.clone().unwrap_or_default()
     }
 
     fn title(&self) -> &str {
@@ -51,29 +140,35 @@ impl FeedWidget for SportsWidget {
         (self.config.position.row, self.config.position.col)
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default().fg(Color::White)
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .title(format!(" {} ", self.config.title))
             .borders(Borders::ALL)
             .border_style(border_style);
 
         if self.loading && self.events.is_empty() {
-            let loading_text = List::new(vec![ListItem::new("Loading...")]).block(block);
+            let loading_text =
+                List::new(vec![ListItem::new(spinner::loading("Loading..."))]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
 
         if let Some(ref error) = self.error {
-            let error_text =
-                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
-            frame.render_widget(error_text, area);
-            return;
+            if self.events.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
+        } else if selected {
+            block = block.title_bottom(actions_hint(&self.actions()));
         }
 
         if self.events.is_empty() {
@@ -82,21 +177,21 @@ impl FeedWidget for SportsWidget {
             return;
         }
 
-        let items: Vec<ListItem> = self
-            .events
-            .iter()
-            .map(|event| {
+        // Rows call `win_probability_spans`, which borrows all of `self`
+        let mut scroll_state = std::mem::take(&mut self.scroll_state);
+        let (list, mut state) =
+            virtual_list(&self.events, &mut scroll_state, area, 2, |_, event| {
                 let score_text = match (event.home_score, event.away_score) {
                     (Some(h), Some(a)) => format!("{} - {}", h, a),
                     _ => "vs".to_string(),
                 };
 
-                let status_color = match event.status.to_lowercase().as_str() {
-                    s if s.contains("final") => Color::Gray,
+                let (status_icon, status_color) = match event.status.to_lowercase().as_str() {
+                    s if s.contains("final") => (Icon::Final.glyph(), Color::Gray),
                     s if s.contains("progress") || s.contains("half") || s.contains("quarter") => {
-                        Color::Green
+                        (Icon::Live.glyph(), palette().positive)
                     }
-                    _ => Color::Yellow,
+                    _ => (" ", Color::Yellow),
                 };
 
                 let game_line = Line::from(vec![
@@ -114,31 +209,55 @@ impl FeedWidget for SportsWidget {
                     Span::styled(&event.home_team, Style::default().fg(Color::White)),
                 ]);
 
-                let status_line = Line::from(vec![
-                    Span::styled("      ", Style::default()),
+                let mut status_spans = vec![
+                    Span::styled(
+                        format!("    {} ", status_icon),
+                        Style::default().fg(status_color),
+                    ),
                     Span::styled(&event.status, Style::default().fg(status_color)),
-                ]);
+                ];
+                if !event.status.to_lowercase().contains("final") {
+                    status_spans.extend(self.win_probability_spans(event));
+                }
+                let status_line = Line::from(status_spans);
 
                 ListItem::new(vec![game_line, status_line])
-            })
-            .collect();
+            });
 
-        let list = List::new(items).block(block).highlight_style(
+        let list = list.block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
         );
 
-        let mut state = self.scroll_state.clone();
         frame.render_stateful_widget(list, area, &mut state);
+        self.scroll_state = scroll_state;
     }
 
     fn update_data(&mut self, data: FeedData) {
         self.loading = false;
         match data {
             FeedData::Sports(events) => {
+                let previous: HashMap<String, f64> = self
+                    .events
+                    .iter()
+                    .filter_map(|e| Some((game_key(e), e.home_win_probability?)))
+                    .collect();
+                self.swings = events
+                    .iter()
+                    .filter_map(|e| {
+                        let key = game_key(e);
+                        let swing = e.home_win_probability? - previous.get(&key)?;
+                        (swing.abs() >= 0.005).then_some((key, swing))
+                    })
+                    .collect();
                 self.events = events;
                 self.error = None;
+                if self.config.calendar.is_some() {
+                    if let Err(e) = self.export_calendar() {
+                        tracing::warn!("Could not update the games calendar: {:#}", e);
+                    }
+                }
             }
             FeedData::Error(e) => {
                 self.error = Some(e);
@@ -151,7 +270,10 @@ impl FeedWidget for SportsWidget {
     }
 
     fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
-        Box::new(SportsFetcher::new(self.config.leagues.clone()))
+        Box::new(SportsFetcher::new(
+            self.config.leagues.clone(),
+            http::timeout(self.config.timeout_secs),
+        ))
     }
 
     fn scroll_up(&mut self) {
@@ -170,10 +292,64 @@ impl FeedWidget for SportsWidget {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let event = self.events.get(idx)?;
+
+        let mut score = match (event.away_score, event.home_score) {
+            (Some(away), Some(home)) => format!("{} - {}", away, home),
+            _ => event.start_time.clone().unwrap_or_default(),
+        };
+        if let Some(home) = event.home_win_probability {
+            score.push_str(&format!(
+                "\n\n{} {:.0}% to win, {} {:.0}%",
+                event.home_team,
+                home * 100.0,
+                event.away_team,
+                (1.0 - home) * 100.0
+            ));
+        }
+        Some(SelectedItem {
+            title: format!("{} @ {}", event.away_team, event.home_team),
+            url: None,
+            description: Some(score),
+            image_url: None,
+            source: event.league.clone(),
+            metadata: Some(event.status.clone()),
+        })
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Handled {
+        match key.code {
+            KeyCode::Char('E') => Handled::Request(WidgetRequest::ExportCalendar),
+            _ => Handled::Ignored,
+        }
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        vec![Action::new("E", "export games")]
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
@@ -1,7 +1,15 @@
 use crate::config::StocksConfig;
-use crate::feeds::stocks::StocksFetcher;
-use crate::feeds::{FeedData, FeedFetcher, StockQuote};
-use crate::ui::widgets::FeedWidget;
+use crate::feeds::stocks::{self, StocksFetcher};
+use crate::feeds::{http, FeedData, FeedFetcher, RssItem, StockQuote};
+use crate::ui::charts;
+use crate::ui::icons::Icon;
+use crate::ui::palette::palette;
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{actions_hint, error_footer, Action, FeedWidget, Handled, SelectedItem};
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use futures::FutureExt;
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -9,14 +17,33 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
+use std::collections::{HashMap, VecDeque};
+use tokio::task::JoinHandle;
+
+/// Sparkline cells, each holding two prices
+const SPARKLINE_WIDTH: usize = 8;
+/// Prices kept per symbol for the sparkline
+const HISTORY_LEN: usize = SPARKLINE_WIDTH * 2;
 
 pub struct StocksWidget {
     config: StocksConfig,
     quotes: Vec<StockQuote>,
+    /// Recent prices by symbol, oldest first
+    history: HashMap<String, VecDeque<f64>>,
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
     selected: bool,
+    /// Headlines for one symbol, shown in place of the quotes
+    news: Option<SymbolNews>,
+}
+
+struct SymbolNews {
+    symbol: String,
+    items: Vec<RssItem>,
+    task: Option<JoinHandle<Result<Vec<RssItem>>>>,
+    error: Option<String>,
+    scroll_state: ListState,
 }
 
 impl StocksWidget {
@@ -27,20 +54,95 @@ impl StocksWidget {
         Self {
             config,
             quotes: Vec::new(),
+            history: HashMap::new(),
             loading: true,
             error: None,
             scroll_state,
             selected: false,
+            news: None,
         }
     }
+
+    /// Start fetching headlines for the selected symbol
+    fn open_news(&mut self) {
+        let Some(quote) = self
+            .scroll_state
+            .selected()
+            .and_then(|idx| self.quotes.get(idx))
+        else {
+            return;
+        };
+        let symbol = quote.symbol.clone();
+        let timeout = http::timeout(self.config.timeout_secs);
+        let task = {
+            let symbol = symbol.clone();
+            tokio::spawn(async move { stocks::fetch_news(&symbol, timeout).await })
+        };
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+        self.news = Some(SymbolNews {
+            symbol,
+            items: Vec::new(),
+            task: Some(task),
+            error: None,
+            scroll_state,
+        });
+    }
+
+    fn close_news(&mut self) {
+        if let Some(task) = self.news.take().and_then(|news| news.task) {
+            task.abort();
+        }
+    }
+
+    fn render_news(news: &mut SymbolNews, frame: &mut Frame, area: Rect, block: Block) {
+        if news.task.is_some() {
+            let loading =
+                List::new(vec![ListItem::new(spinner::loading("Loading news..."))]).block(block);
+            frame.render_widget(loading, area);
+            return;
+        }
+        if let Some(ref error) = news.error {
+            let error_text =
+                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+            frame.render_widget(error_text, area);
+            return;
+        }
+        if news.items.is_empty() {
+            let empty = List::new(vec![ListItem::new("No recent news")]).block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let (list, mut state) =
+            virtual_list(&news.items, &mut news.scroll_state, area, 2, |_, item| {
+                let mut meta = vec![
+                    Span::styled("   ", Style::default()),
+                    Span::styled(&item.source, Style::default().fg(Color::Cyan)),
+                ];
+                if let Some(ref published) = item.published {
+                    meta.push(Span::styled(
+                        format!(" | {}", published),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                ListItem::new(vec![
+                    Line::from(Span::styled(&item.title, Style::default().fg(Color::White))),
+                    Line::from(meta),
+                ])
+            });
+        let list = list.block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+        frame.render_stateful_widget(list, area, &mut state);
+    }
 }
 
 impl FeedWidget for StocksWidget {
     fn id(&self) -> String {
-        format!(
-            "stocks-{}-{}",
-            self.config.position.row, self.config.position.col
-        )
+        self.config.id.clone().unwrap_or_default()
     }
 
     fn title(&self) -> &str {
@@ -51,39 +153,57 @@ impl FeedWidget for StocksWidget {
         (self.config.position.row, self.config.position.col)
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default().fg(Color::White)
         };
 
-        let block = Block::default()
-            .title(format!(" {} ", self.config.title))
+        let title = match self.news {
+            Some(ref news) => format!(" {} news ", news.symbol),
+            None => format!(" {} ", self.config.title),
+        };
+        let mut block = Block::default()
+            .title(title)
             .borders(Borders::ALL)
             .border_style(border_style);
 
+        if self.news.is_some() {
+            if selected {
+                block = block.title_bottom(actions_hint(&self.actions()));
+            }
+            if let Some(ref mut news) = self.news {
+                Self::render_news(news, frame, area, block);
+            }
+            return;
+        }
+
         if self.loading && self.quotes.is_empty() {
-            let loading_text = List::new(vec![ListItem::new("Loading...")]).block(block);
+            let loading_text =
+                List::new(vec![ListItem::new(spinner::loading("Loading..."))]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
 
         if let Some(ref error) = self.error {
-            let error_text =
-                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
-            frame.render_widget(error_text, area);
-            return;
+            if self.quotes.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
+        } else if selected {
+            block = block.title_bottom(actions_hint(&self.actions()));
         }
 
-        let items: Vec<ListItem> = self
-            .quotes
-            .iter()
-            .map(|quote| {
-                let change_color = if quote.change >= 0.0 {
-                    Color::Green
+        let (list, mut state) =
+            virtual_list(&self.quotes, &mut self.scroll_state, area, 2, |_, quote| {
+                let (change_icon, change_color) = if quote.change >= 0.0 {
+                    (Icon::Gain, palette().positive)
                 } else {
-                    Color::Red
+                    (Icon::Loss, palette().negative)
                 };
 
                 let change_symbol = if quote.change >= 0.0 { "+" } else { "" };
@@ -101,25 +221,35 @@ impl FeedWidget for StocksWidget {
                     ),
                 ]);
 
-                let change_line = Line::from(vec![Span::styled(
+                let mut change_spans = vec![Span::styled(
                     format!(
-                        "      {}{:.2} ({}{:.2}%)",
-                        change_symbol, quote.change, change_symbol, quote.change_percent
+                        "    {} {}{:.2} ({}{:.2}%)",
+                        change_icon.glyph(),
+                        change_symbol,
+                        quote.change,
+                        change_symbol,
+                        quote.change_percent
                     ),
                     Style::default().fg(change_color),
-                )]);
+                )];
+                if let Some(prices) = self.history.get(&quote.symbol).filter(|p| p.len() > 1) {
+                    let prices: Vec<f64> = prices.iter().copied().collect();
+                    change_spans.push(Span::styled(
+                        format!(" {}", charts::sparkline(&prices, SPARKLINE_WIDTH)),
+                        Style::default().fg(change_color),
+                    ));
+                }
+                let change_line = Line::from(change_spans);
 
                 ListItem::new(vec![symbol_line, change_line])
-            })
-            .collect();
+            });
 
-        let list = List::new(items).block(block).highlight_style(
+        let list = list.block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
         );
 
-        let mut state = self.scroll_state.clone();
         frame.render_stateful_widget(list, area, &mut state);
     }
 
@@ -127,6 +257,13 @@ impl FeedWidget for StocksWidget {
         self.loading = false;
         match data {
             FeedData::Stocks(quotes) => {
+                for quote in &quotes {
+                    let prices = self.history.entry(quote.symbol.clone()).or_default();
+                    prices.push_back(quote.price);
+                    if prices.len() > HISTORY_LEN {
+                        prices.pop_front();
+                    }
+                }
                 self.quotes = quotes;
                 self.error = None;
             }
@@ -141,10 +278,19 @@ impl FeedWidget for StocksWidget {
     }
 
     fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
-        Box::new(StocksFetcher::new(self.config.symbols.clone()))
+        Box::new(StocksFetcher::new(
+            self.config.symbols.clone(),
+            http::timeout(self.config.timeout_secs),
+        ))
     }
 
     fn scroll_up(&mut self) {
+        if let Some(ref mut news) = self.news {
+            if let Some(selected) = news.scroll_state.selected() {
+                news.scroll_state.select(Some(selected.saturating_sub(1)));
+            }
+            return;
+        }
         if let Some(selected) = self.scroll_state.selected() {
             if selected > 0 {
                 self.scroll_state.select(Some(selected - 1));
@@ -153,6 +299,13 @@ impl FeedWidget for StocksWidget {
     }
 
     fn scroll_down(&mut self) {
+        if let Some(ref mut news) = self.news {
+            if let Some(selected) = news.scroll_state.selected() {
+                let last = news.items.len().saturating_sub(1);
+                news.scroll_state.select(Some((selected + 1).min(last)));
+            }
+            return;
+        }
         if let Some(selected) = self.scroll_state.selected() {
             if selected < self.quotes.len().saturating_sub(1) {
                 self.scroll_state.select(Some(selected + 1));
@@ -160,10 +313,88 @@ impl FeedWidget for StocksWidget {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        match self.news {
+            Some(ref mut news) => news.scroll_state.select(Some(0)),
+            None => self.scroll_state.select(Some(0)),
+        }
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        if let Some(ref news) = self.news {
+            let item = news.items.get(news.scroll_state.selected()?)?;
+            return Some(SelectedItem {
+                title: item.title.clone(),
+                url: item.link.clone(),
+                description: None,
+                image_url: None,
+                source: item.source.clone(),
+                metadata: item.published.clone(),
+            });
+        }
+        let idx = self.scroll_state.selected()?;
+        let quote = self.quotes.get(idx)?;
+        let title = if quote.name.is_empty() || quote.name == quote.symbol {
+            quote.symbol.clone()
+        } else {
+            format!("{} ({})", quote.name, quote.symbol)
+        };
+        Some(SelectedItem {
+            title,
+            url: Some(format!("https://finance.yahoo.com/quote/{}", quote.symbol)),
+            description: Some(format!(
+                "${:.2}, {:+.2} ({:+.2}%) today",
+                quote.price, quote.change, quote.change_percent
+            )),
+            image_url: None,
+            source: self.config.title.clone(),
+            metadata: None,
+        })
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Handled {
+        match key.code {
+            KeyCode::Esc | KeyCode::Backspace if self.news.is_some() => self.close_news(),
+            // In the news, Enter falls through to open the article reader
+            KeyCode::Enter if self.news.is_none() && !self.quotes.is_empty() => self.open_news(),
+            _ => return Handled::Ignored,
+        }
+        Handled::Consumed
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        if self.news.is_some() {
+            vec![Action::new("Enter", "read"), Action::new("Esc", "back")]
+        } else {
+            vec![Action::new("Enter", "news")]
+        }
+    }
+
+    fn poll(&mut self) -> bool {
+        let Some(news) = self.news.as_mut() else {
+            return false;
+        };
+        let Some(task) = news.task.as_mut().filter(|task| task.is_finished()) else {
+            return false;
+        };
+        let result = task.now_or_never();
+        news.task = None;
+        match result.map(|r| r.map_err(anyhow::Error::from).and_then(|r| r)) {
+            Some(Ok(items)) => news.items = items,
+            Some(Err(e)) => news.error = Some(format!("{:#}", e)),
+            None => {}
+        }
+        true
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
@@ -0,0 +1,140 @@
+use ratatui::{
+    layout::Rect,
+    widgets::{List, ListItem, ListState},
+};
+
+/// Rows built below the viewport so small scrolls don't land on empty space
+const OVERSCAN: usize = 4;
+
+/// The range of a list worth building for one frame
+#[derive(Debug, PartialEq, Eq)]
+pub struct Window {
+    pub start: usize,
+    pub end: usize,
+    /// Selection relative to `start`
+    pub selected: Option<usize>,
+}
+
+/// Keep the selection on screen the way `List` would: the view stays at
+/// the previous frame's `offset` until the selection leaves it
+pub fn window(
+    len: usize,
+    selected: Option<usize>,
+    offset: usize,
+    viewport_rows: usize,
+    min_row_height: usize,
+) -> Window {
+    let visible = (viewport_rows / min_row_height.max(1)).max(1);
+    let start = match selected {
+        Some(sel) if sel < offset => sel,
+        Some(sel) if sel >= offset + visible => sel + 1 - visible,
+        _ => offset,
+    }
+    .min(len);
+    Window {
+        start,
+        end: (start + visible + OVERSCAN).min(len),
+        selected: selected.map(|sel| sel.saturating_sub(start)),
+    }
+}
+
+/// Build a `List` holding only the rows that can appear in `area` (drawn
+/// inside a bordered block). `make_row` gets each item's index in the full
+/// list; `min_row_height` is the fewest lines a row can take. The view's
+/// position is kept in `state`'s offset for the next frame.
+pub fn virtual_list<'a, T>(
+    items: &'a [T],
+    state: &mut ListState,
+    area: Rect,
+    min_row_height: usize,
+    make_row: impl Fn(usize, &'a T) -> ListItem<'a>,
+) -> (List<'a>, ListState) {
+    let window = window(
+        items.len(),
+        state.selected(),
+        state.offset(),
+        area.height.saturating_sub(2) as usize,
+        min_row_height,
+    );
+
+    *state.offset_mut() = window.start;
+
+    let rows: Vec<ListItem> = items[window.start..window.end]
+        .iter()
+        .enumerate()
+        .map(|(i, item)| make_row(window.start + i, item))
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(window.selected);
+    (List::new(rows), state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_only_the_first_screen_until_selection_passes_it() {
+        assert_eq!(
+            window(500, Some(3), 0, 20, 2),
+            Window {
+                start: 0,
+                end: 14,
+                selected: Some(3)
+            }
+        );
+    }
+
+    #[test]
+    fn scrolls_so_the_selection_is_the_last_visible_row() {
+        assert_eq!(
+            window(500, Some(100), 0, 20, 2),
+            Window {
+                start: 91,
+                end: 105,
+                selected: Some(9)
+            }
+        );
+    }
+
+    #[test]
+    fn scrolling_back_up_keeps_the_view_until_the_selection_leaves_it() {
+        assert_eq!(
+            window(500, Some(99), 91, 20, 2),
+            Window {
+                start: 91,
+                end: 105,
+                selected: Some(8)
+            }
+        );
+        assert_eq!(
+            window(500, Some(90), 91, 20, 2),
+            Window {
+                start: 90,
+                end: 104,
+                selected: Some(0)
+            }
+        );
+    }
+
+    #[test]
+    fn short_lists_are_built_whole() {
+        assert_eq!(
+            window(3, Some(2), 0, 20, 1),
+            Window {
+                start: 0,
+                end: 3,
+                selected: Some(2)
+            }
+        );
+        assert_eq!(
+            window(0, None, 0, 20, 1),
+            Window {
+                start: 0,
+                end: 0,
+                selected: None
+            }
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::config::WalletConfig;
+use crate::feeds::wallet::WalletFetcher;
+use crate::feeds::{http, FeedData, FeedFetcher, WalletHolding};
+use crate::ui::icons::Icon;
+use crate::ui::palette::palette;
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{error_footer, FeedWidget, SelectedItem};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+pub struct WalletWidget {
+    config: WalletConfig,
+    holdings: Vec<WalletHolding>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    selected: bool,
+}
+
+impl WalletWidget {
+    pub fn new(config: WalletConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            holdings: Vec::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            selected: false,
+        }
+    }
+
+    fn money(&self, amount: f64) -> String {
+        if self.config.currency.eq_ignore_ascii_case("usd") {
+            format!("${:.2}", amount)
+        } else {
+            format!("{:.2} {}", amount, self.config.currency.to_uppercase())
+        }
+    }
+
+    /// Value of every holding whose balance could be read
+    fn total(&self) -> f64 {
+        self.holdings.iter().filter_map(WalletHolding::value).sum()
+    }
+}
+
+/// Short form of a long address, e.g. `bc1qar0s…5mdq`
+fn short_address(address: &str) -> String {
+    let chars: Vec<char> = address.chars().collect();
+    if chars.len() <= 14 {
+        return address.to_string();
+    }
+    let head: String = chars[..8].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}…{}", head, tail)
+}
+
+impl FeedWidget for WalletWidget {
+    fn id(&self) -> String {
+        self.config.id.clone().unwrap_or_default()
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let title = if self.holdings.is_empty() {
+            format!(" {} ", self.config.title)
+        } else {
+            format!(" {} · {} ", self.config.title, self.money(self.total()))
+        };
+        let mut block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.holdings.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(spinner::loading("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            if self.holdings.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
+        }
+
+        // Rows are priced with `self.money`, so the state is held apart
+        let mut scroll_state = std::mem::take(&mut self.scroll_state);
+        let (list, mut state) =
+            virtual_list(&self.holdings, &mut scroll_state, area, 2, |_, holding| {
+                let name = holding
+                    .label
+                    .clone()
+                    .unwrap_or_else(|| short_address(&holding.address));
+                let mut balance_line = vec![Span::styled(
+                    format!("{:<14}", name),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                )];
+                match (holding.balance, holding.value()) {
+                    (Some(balance), Some(value)) => balance_line.push(Span::styled(
+                        format!(" {:.8} {}  {}", balance, holding.asset, self.money(value)),
+                        Style::default().fg(Color::White),
+                    )),
+                    _ => balance_line.push(Span::styled(
+                        format!(" {} balance unavailable", holding.asset),
+                        Style::default().fg(Color::DarkGray),
+                    )),
+                }
+
+                let (change_icon, change_color) = if holding.change_percent_24h >= 0.0 {
+                    (Icon::Gain, palette().positive)
+                } else {
+                    (Icon::Loss, palette().negative)
+                };
+                let price_line = Line::from(Span::styled(
+                    format!(
+                        "    {} {} {} ({:+.2}% 24h)",
+                        change_icon.glyph(),
+                        holding.asset,
+                        self.money(holding.price),
+                        holding.change_percent_24h
+                    ),
+                    Style::default().fg(change_color),
+                ));
+
+                ListItem::new(vec![Line::from(balance_line), price_line])
+            });
+
+        let list = list.block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        frame.render_stateful_widget(list, area, &mut state);
+        self.scroll_state = scroll_state;
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Wallet(holdings) => {
+                self.holdings = holdings;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+        Box::new(WalletFetcher::new(
+            self.config.addresses.clone(),
+            self.config.currency.clone(),
+            self.config.eth_rpc.clone(),
+            http::timeout(self.config.timeout_secs),
+        ))
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.holdings.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let holding = self.holdings.get(self.scroll_state.selected()?)?;
+        let url = match holding.asset.as_str() {
+            "BTC" => format!("https://blockstream.info/address/{}", holding.address),
+            _ => format!("https://etherscan.io/address/{}", holding.address),
+        };
+        let description = match (holding.balance, holding.value()) {
+            (Some(balance), Some(value)) => format!(
+                "{} {} worth {} at {} each",
+                balance,
+                holding.asset,
+                self.money(value),
+                self.money(holding.price)
+            ),
+            _ => "The balance could not be read from the explorer.".to_string(),
+        };
+        Some(SelectedItem {
+            title: holding
+                .label
+                .clone()
+                .unwrap_or_else(|| holding.address.clone()),
+            url: Some(url),
+            description: Some(description),
+            image_url: None,
+            source: self.config.title.clone(),
+            metadata: Some(holding.address.clone()),
+        })
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
@@ -1,7 +1,10 @@
-use crate::config::YoutubeConfig;
+use crate::config::{SortOrder, YoutubeConfig};
 use crate::feeds::youtube::YoutubeFetcher;
-use crate::feeds::{FeedData, FeedFetcher, YoutubeVideo};
-use crate::ui::widgets::{FeedWidget, SelectedItem};
+use crate::feeds::{http, FeedData, FeedFetcher, YoutubeVideo};
+use crate::ui::spinner;
+use crate::ui::widgets::virtual_list::virtual_list;
+use crate::ui::widgets::{actions_hint, error_footer, Action, FeedWidget, Handled, SelectedItem};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -10,9 +13,19 @@ use ratatui::{
     Frame,
 };
 
+const SORT_ORDERS: &[SortOrder] = &[
+    SortOrder::Feed,
+    SortOrder::Date,
+    SortOrder::Source,
+    SortOrder::Title,
+];
+
 pub struct YoutubeWidget {
     config: YoutubeConfig,
+    /// Videos in the order the API returned them
+    received: Vec<YoutubeVideo>,
     videos: Vec<YoutubeVideo>,
+    sort: SortOrder,
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
@@ -24,23 +37,34 @@ impl YoutubeWidget {
         let mut scroll_state = ListState::default();
         scroll_state.select(Some(0));
 
+        let sort = config.sort.within(SORT_ORDERS);
         Self {
             config,
+            received: Vec::new(),
             videos: Vec::new(),
+            sort,
             loading: true,
             error: None,
             scroll_state,
             selected: false,
         }
     }
+
+    fn apply_sort(&mut self) {
+        self.videos = self.received.clone();
+        match self.sort {
+            // `published` is a YYYY-MM-DD date, so it sorts as text
+            SortOrder::Date => self.videos.sort_by(|a, b| b.published.cmp(&a.published)),
+            SortOrder::Source => self.videos.sort_by(|a, b| a.channel.cmp(&b.channel)),
+            SortOrder::Title => self.videos.sort_by_cached_key(|v| v.title.to_lowercase()),
+            _ => {}
+        }
+    }
 }
 
 impl FeedWidget for YoutubeWidget {
     fn id(&self) -> String {
-        format!(
-            "youtube-{}-{}",
-            self.config.position.row, self.config.position.col
-        )
+        self.config.id.clone().unwrap_or_default()
     }
 
     fn title(&self) -> &str {
@@ -51,30 +75,38 @@ impl FeedWidget for YoutubeWidget {
         (self.config.position.row, self.config.position.col)
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default().fg(Color::White)
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .title(format!(" {} ", self.config.title))
             .borders(Borders::ALL)
             .border_style(border_style);
+        if selected {
+            block = block.title_bottom(actions_hint(&self.actions()));
+        }
 
         if self.loading && self.videos.is_empty() {
-            let loading_text =
-                List::new(vec![ListItem::new("Loading YouTube videos...")]).block(block);
+            let loading_text = List::new(vec![ListItem::new(spinner::loading(
+                "Loading YouTube videos...",
+            ))])
+            .block(block);
             frame.render_widget(loading_text, area);
             return;
         }
 
         if let Some(ref error) = self.error {
-            let error_text =
-                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
-            frame.render_widget(error_text, area);
-            return;
+            if self.videos.is_empty() {
+                let error_text =
+                    List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+                frame.render_widget(error_text, area);
+                return;
+            }
+            block = block.title_bottom(error_footer(error));
         }
 
         if self.videos.is_empty() {
@@ -83,11 +115,8 @@ impl FeedWidget for YoutubeWidget {
             return;
         }
 
-        let items: Vec<ListItem> = self
-            .videos
-            .iter()
-            .enumerate()
-            .map(|(i, video)| {
+        let (list, mut state) =
+            virtual_list(&self.videos, &mut self.scroll_state, area, 2, |i, video| {
                 // Title line with numbering
                 let title_line = Line::from(vec![
                     Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
@@ -122,16 +151,14 @@ impl FeedWidget for YoutubeWidget {
                 let meta_line = Line::from(meta_parts);
 
                 ListItem::new(vec![title_line, meta_line])
-            })
-            .collect();
+            });
 
-        let list = List::new(items).block(block).highlight_style(
+        let list = list.block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
         );
 
-        let mut state = self.scroll_state.clone();
         frame.render_stateful_widget(list, area, &mut state);
     }
 
@@ -139,7 +166,8 @@ impl FeedWidget for YoutubeWidget {
         self.loading = false;
         match data {
             FeedData::Youtube(videos) => {
-                self.videos = videos;
+                self.received = videos;
+                self.apply_sort();
                 self.error = None;
             }
             FeedData::Error(e) => {
@@ -158,6 +186,7 @@ impl FeedWidget for YoutubeWidget {
             self.config.channels.clone(),
             self.config.search_query.clone(),
             self.config.max_videos,
+            http::timeout(self.config.timeout_secs),
         ))
     }
 
@@ -177,10 +206,30 @@ impl FeedWidget for YoutubeWidget {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn handle_key(&mut self, key: KeyEvent) -> Handled {
+        match key.code {
+            KeyCode::Char('S') => {
+                self.sort = self.sort.next_within(SORT_ORDERS);
+                self.apply_sort();
+                self.scroll_state.select(Some(0));
+                Handled::Consumed
+            }
+            _ => Handled::Ignored,
+        }
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        vec![Action::new("S", format!("sort: {}", self.sort.name()))]
+    }
+
     fn get_selected_item(&self) -> Option<SelectedItem> {
         let idx = self.scroll_state.selected()?;
         let video = self.videos.get(idx)?;
@@ -200,11 +249,16 @@ impl FeedWidget for YoutubeWidget {
             title: video.title.clone(),
             url,
             description: Some(video.description.clone()),
+            image_url: video.thumbnail_url.clone(),
             source: video.channel.clone(),
             metadata: Some(metadata_parts.join(" | ")),
         })
     }
 
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
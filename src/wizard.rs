@@ -0,0 +1,872 @@
+use crate::app::App;
+use crate::config::{Config, GeneralConfig, WidgetConfig};
+use crate::doctor::{self, ApiKey};
+use crate::feeds::{http, rss};
+use crate::ui::choice::{ChoiceDialog, ChoiceResult};
+use crate::ui::layout::{self, Placement};
+use crate::ui::palette;
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use serde_json::json;
+use std::path::Path;
+
+/// Widgets per row of the generated layout
+const COLUMNS: usize = 3;
+const DEFAULT_FEED: &str = "https://feeds.arstechnica.com/arstechnica/technology-lab";
+
+/// Widgets the wizard can set up, in the order they are offered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Creature,
+    Hackernews,
+    Stocks,
+    Rss,
+    Sports,
+    Github,
+    Youtube,
+}
+
+impl Kind {
+    const ALL: [Kind; 7] = [
+        Kind::Creature,
+        Kind::Hackernews,
+        Kind::Stocks,
+        Kind::Rss,
+        Kind::Sports,
+        Kind::Github,
+        Kind::Youtube,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Creature => "Tui creature companion",
+            Kind::Hackernews => "Hacker News",
+            Kind::Stocks => "Stock ticker",
+            Kind::Rss => "RSS feeds",
+            Kind::Sports => "Sports scores",
+            Kind::Github => "GitHub dashboard",
+            Kind::Youtube => "YouTube videos",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Kind::Creature => "Tui",
+            Kind::Hackernews => "Hacker News",
+            Kind::Stocks => "Portfolio",
+            Kind::Rss => "Tech News",
+            Kind::Sports => "Sports",
+            Kind::Github => "GitHub Dashboard",
+            Kind::Youtube => "YouTube",
+        }
+    }
+
+    fn enabled_by_default(self) -> bool {
+        matches!(
+            self,
+            Kind::Creature | Kind::Hackernews | Kind::Stocks | Kind::Rss
+        )
+    }
+
+    /// What the widget looks like, with made-up items
+    fn preview(self) -> &'static [&'static str] {
+        match self {
+            Kind::Creature => &[
+                "  (•ᴗ•)   Tui  Lv 3",
+                "  XP ▰▰▰▰▱▱ 412/600",
+                "",
+                "A companion that levels up while you read.",
+            ],
+            Kind::Hackernews => &[
+                "1. Show HN: A tiny terminal dashboard",
+                "   312 points | 87 comments",
+                "2. The history of the spreadsheet",
+                "   154 points | 42 comments",
+            ],
+            Kind::Stocks => &[
+                "AAPL   227.52  ▲ +1.24%",
+                "GOOGL  171.03  ▼ -0.37%",
+                "MSFT   428.15  ▲ +0.82%",
+                "",
+                "Enter on a symbol shows its news.",
+            ],
+            Kind::Rss => &[
+                "New GPU drivers land in the kernel",
+                "   Ars Technica | 2h ago",
+                "Inside the chip shortage",
+                "   Ars Technica | 5h ago",
+            ],
+            Kind::Sports => &[
+                "NBA  Celtics 98 - 95 Lakers  Q4 2:31",
+                "NFL  Chiefs 24 - 17 Bills   Final",
+                "",
+                "Live scores from ESPN, no key needed.",
+            ],
+            Kind::Github => &[
+                "● Review requested: Fix flaky test",
+                "   octo/widgets | PullRequest",
+                "#42 Add dark mode  +120 -8",
+                "",
+                "Needs a personal access token.",
+            ],
+            Kind::Youtube => &[
+                "Building a TUI in Rust",
+                "   Some Channel | 12k views",
+                "",
+                "Needs a YouTube Data API v3 key.",
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    General,
+    Widgets,
+    Details,
+    Layout,
+}
+
+impl Step {
+    fn number(self) -> usize {
+        match self {
+            Step::General => 1,
+            Step::Widgets => 2,
+            Step::Details => 3,
+            Step::Layout => 4,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Step::General => "General",
+            Step::Widgets => "Widgets",
+            Step::Details => "Details",
+            Step::Layout => "Layout",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldId {
+    Refresh,
+    Theme,
+    Symbols,
+    Feed,
+    Leagues,
+    GithubUser,
+    GithubToken,
+    YoutubeKey,
+    YoutubeSearch,
+}
+
+impl FieldId {
+    const ALL: [FieldId; 9] = [
+        FieldId::Refresh,
+        FieldId::Theme,
+        FieldId::Symbols,
+        FieldId::Feed,
+        FieldId::Leagues,
+        FieldId::GithubUser,
+        FieldId::GithubToken,
+        FieldId::YoutubeKey,
+        FieldId::YoutubeSearch,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            FieldId::Refresh => "Refresh interval (seconds)",
+            FieldId::Theme => "Theme",
+            FieldId::Symbols => "Stock symbols",
+            FieldId::Feed => "Website or feed URL",
+            FieldId::Leagues => "Leagues",
+            FieldId::GithubUser => "GitHub username",
+            FieldId::GithubToken => "GitHub token",
+            FieldId::YoutubeKey => "YouTube API key",
+            FieldId::YoutubeSearch => "YouTube search",
+        }
+    }
+
+    /// The widget the field configures; general settings have none
+    fn kind(self) -> Option<Kind> {
+        match self {
+            FieldId::Refresh | FieldId::Theme => None,
+            FieldId::Symbols => Some(Kind::Stocks),
+            FieldId::Feed => Some(Kind::Rss),
+            FieldId::Leagues => Some(Kind::Sports),
+            FieldId::GithubUser | FieldId::GithubToken => Some(Kind::Github),
+            FieldId::YoutubeKey | FieldId::YoutubeSearch => Some(Kind::Youtube),
+        }
+    }
+
+    fn secret(self) -> bool {
+        matches!(self, FieldId::GithubToken | FieldId::YoutubeKey)
+    }
+
+    fn initial(self) -> &'static str {
+        match self {
+            FieldId::Refresh => "60",
+            FieldId::Theme => "dark",
+            FieldId::Symbols => "AAPL, GOOGL, MSFT",
+            FieldId::Feed => DEFAULT_FEED,
+            FieldId::Leagues => "nba, nfl",
+            FieldId::YoutubeSearch => "rust programming",
+            FieldId::GithubUser | FieldId::GithubToken | FieldId::YoutubeKey => "",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Check {
+    Unchecked,
+    Valid(String),
+    Invalid(String),
+}
+
+struct Field {
+    id: FieldId,
+    value: String,
+    check: Check,
+}
+
+/// What the caller should do after a key
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    None,
+    /// Check the current step's fields, which may take network calls
+    Validate,
+    Save,
+    Quit,
+}
+
+/// `feedtui init`: pick widgets, fill in what they need, and preview the
+/// layout before anything is written
+struct Wizard {
+    step: Step,
+    /// Index-aligned with `Kind::ALL`
+    enabled: Vec<bool>,
+    fields: Vec<Field>,
+    /// Highlighted row of the current step
+    cursor: usize,
+    /// Enabled widgets in layout order
+    order: Vec<Kind>,
+    message: Option<String>,
+    /// Set once failed checks were pointed out, so Enter again moves on
+    keep_invalid: bool,
+    themes: Vec<&'static str>,
+    choice: ChoiceDialog,
+    feed_choices: Vec<rss::DiscoveredFeed>,
+}
+
+/// Entry point for `feedtui init`; false if it was quit without saving
+pub async fn run_wizard(path: &Path) -> Result<bool> {
+    let mut wizard = Wizard::new();
+    let mut terminal = App::setup_terminal()?;
+
+    let saved = loop {
+        terminal.draw(|frame| wizard.render(frame))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match wizard.handle_key(key) {
+            Action::None => {}
+            Action::Validate => {
+                wizard.message = Some("Checking…".to_string());
+                terminal.draw(|frame| wizard.render(frame))?;
+                wizard.validate().await;
+            }
+            Action::Save => break true,
+            Action::Quit => break false,
+        }
+    };
+
+    App::restore_terminal(&mut terminal)?;
+    if saved {
+        std::fs::write(path, toml::to_string_pretty(&wizard.config())?)?;
+    }
+    Ok(saved)
+}
+
+impl Wizard {
+    fn new() -> Self {
+        Self {
+            step: Step::General,
+            enabled: Kind::ALL.iter().map(|k| k.enabled_by_default()).collect(),
+            fields: FieldId::ALL
+                .iter()
+                .map(|&id| Field {
+                    id,
+                    value: id.initial().to_string(),
+                    check: Check::Unchecked,
+                })
+                .collect(),
+            cursor: 0,
+            order: Vec::new(),
+            message: None,
+            keep_invalid: false,
+            themes: palette::theme_names(),
+            choice: ChoiceDialog::new(),
+            feed_choices: Vec::new(),
+        }
+    }
+
+    fn is_enabled(&self, kind: Kind) -> bool {
+        Kind::ALL
+            .iter()
+            .position(|k| *k == kind)
+            .is_some_and(|i| self.enabled[i])
+    }
+
+    /// Indexes into `fields` shown on the current step
+    fn visible_fields(&self) -> Vec<usize> {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| match (self.step, field.id.kind()) {
+                (Step::General, None) => true,
+                (Step::Details, Some(kind)) => self.is_enabled(kind),
+                _ => false,
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn value(&self, id: FieldId) -> &str {
+        self.fields
+            .iter()
+            .find(|f| f.id == id)
+            .map_or("", |f| f.value.trim())
+    }
+
+    /// Rows the cursor moves over on the current step
+    fn rows(&self) -> usize {
+        match self.step {
+            Step::General | Step::Details => self.visible_fields().len(),
+            Step::Widgets => Kind::ALL.len(),
+            Step::Layout => self.order.len(),
+        }
+    }
+
+    fn go_to(&mut self, step: Step) {
+        if step == Step::Layout {
+            // Keep the order already arranged, adding newly enabled widgets
+            // at the end
+            self.order.retain(|kind| {
+                Kind::ALL
+                    .iter()
+                    .position(|k| k == kind)
+                    .is_some_and(|i| self.enabled[i])
+            });
+            for (i, kind) in Kind::ALL.iter().enumerate() {
+                if self.enabled[i] && !self.order.contains(kind) {
+                    self.order.push(*kind);
+                }
+            }
+        }
+        self.step = step;
+        self.cursor = 0;
+        self.message = None;
+        self.keep_invalid = false;
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if self.choice.visible {
+            match self.choice.handle_key(key) {
+                ChoiceResult::Chosen(i) => {
+                    if let Some(feed) = self.feed_choices.get(i).cloned() {
+                        self.set_feed(&feed);
+                    }
+                }
+                ChoiceResult::Cancelled => self.feed_choices.clear(),
+                ChoiceResult::Choosing => {}
+            }
+            return Action::None;
+        }
+
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Action::Quit;
+        }
+
+        let rows = self.rows();
+        match key.code {
+            KeyCode::Esc => {
+                return match self.step {
+                    Step::General => Action::Quit,
+                    Step::Widgets => {
+                        self.go_to(Step::General);
+                        Action::None
+                    }
+                    Step::Details => {
+                        self.go_to(Step::Widgets);
+                        Action::None
+                    }
+                    Step::Layout => {
+                        let back = if self.has_details() {
+                            Step::Details
+                        } else {
+                            Step::Widgets
+                        };
+                        self.go_to(back);
+                        Action::None
+                    }
+                };
+            }
+            KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => self.move_widget(false),
+            KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => self.move_widget(true),
+            KeyCode::Char('K') if self.step == Step::Layout => self.move_widget(false),
+            KeyCode::Char('J') if self.step == Step::Layout => self.move_widget(true),
+            KeyCode::Up | KeyCode::BackTab => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => {
+                self.cursor = (self.cursor + 1).min(rows.saturating_sub(1))
+            }
+            KeyCode::Enter => return self.next(),
+            _ => match self.step {
+                Step::Widgets => {
+                    if key.code == KeyCode::Char(' ') {
+                        self.enabled[self.cursor] = !self.enabled[self.cursor];
+                        self.message = None;
+                    }
+                }
+                Step::General | Step::Details => self.edit_field(key),
+                Step::Layout => {}
+            },
+        }
+        Action::None
+    }
+
+    /// Whether any enabled widget has settings to fill in
+    fn has_details(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|field| field.id.kind().is_some_and(|kind| self.is_enabled(kind)))
+    }
+
+    fn edit_field(&mut self, key: KeyEvent) {
+        let Some(&idx) = self.visible_fields().get(self.cursor) else {
+            return;
+        };
+        let themes = &self.themes;
+        let field = &mut self.fields[idx];
+        if field.id == FieldId::Theme {
+            let current = themes.iter().position(|t| *t == field.value).unwrap_or(0);
+            let next = match key.code {
+                KeyCode::Left => (current + themes.len() - 1) % themes.len(),
+                KeyCode::Right | KeyCode::Char(' ') => (current + 1) % themes.len(),
+                _ => return,
+            };
+            field.value = themes[next].to_string();
+            return;
+        }
+        match key.code {
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                field.value.clear()
+            }
+            KeyCode::Char(c) => field.value.push(c),
+            KeyCode::Backspace => {
+                field.value.pop();
+            }
+            _ => return,
+        }
+        field.check = Check::Unchecked;
+        self.keep_invalid = false;
+    }
+
+    /// Enter: move on to the next step once this one checks out
+    fn next(&mut self) -> Action {
+        match self.step {
+            Step::General | Step::Details => {
+                let fields = self.visible_fields();
+                if fields
+                    .iter()
+                    .any(|&i| self.fields[i].check == Check::Unchecked)
+                {
+                    return Action::Validate;
+                }
+                let invalid = fields
+                    .iter()
+                    .any(|&i| matches!(self.fields[i].check, Check::Invalid(_)));
+                // A bad refresh interval can't be written at all
+                let blocking = self.step == Step::General && invalid;
+                if invalid && (blocking || !self.keep_invalid) {
+                    self.message = Some(if blocking {
+                        "Fix the fields marked ✗".to_string()
+                    } else {
+                        "Fix the fields marked ✗, or press Enter again to keep them".to_string()
+                    });
+                    self.keep_invalid = !blocking;
+                    return Action::None;
+                }
+                let next = match self.step {
+                    Step::General => Step::Widgets,
+                    _ => Step::Layout,
+                };
+                self.go_to(next);
+            }
+            Step::Widgets => {
+                if !self.enabled.contains(&true) {
+                    self.message = Some("Pick at least one widget".to_string());
+                } else if self.has_details() {
+                    self.go_to(Step::Details);
+                } else {
+                    self.go_to(Step::Layout);
+                }
+            }
+            Step::Layout => return Action::Save,
+        }
+        Action::None
+    }
+
+    fn move_widget(&mut self, later: bool) {
+        if self.step != Step::Layout || self.order.is_empty() {
+            return;
+        }
+        let to = if later {
+            (self.cursor + 1).min(self.order.len() - 1)
+        } else {
+            self.cursor.saturating_sub(1)
+        };
+        self.order.swap(self.cursor, to);
+        self.cursor = to;
+    }
+
+    /// Check every field on the current step that changed since it was
+    /// last checked
+    async fn validate(&mut self) {
+        self.message = None;
+        for idx in self.visible_fields() {
+            if self.fields[idx].check != Check::Unchecked {
+                continue;
+            }
+            let id = self.fields[idx].id;
+            let value = self.fields[idx].value.trim().to_string();
+            let check = match id {
+                FieldId::Refresh => match value.parse::<u64>() {
+                    Ok(secs) if secs > 0 => Check::Valid(String::new()),
+                    _ => Check::Invalid("a whole number of seconds".to_string()),
+                },
+                FieldId::Theme => Check::Valid(String::new()),
+                FieldId::Symbols | FieldId::Leagues | FieldId::GithubUser => {
+                    if list(&value).is_empty() {
+                        Check::Invalid("required".to_string())
+                    } else {
+                        Check::Valid(String::new())
+                    }
+                }
+                FieldId::YoutubeSearch => Check::Valid(String::new()),
+                FieldId::GithubToken => key_check(ApiKey::Github, &value).await,
+                FieldId::YoutubeKey => key_check(ApiKey::Youtube, &value).await,
+                FieldId::Feed => match rss::discover(&value, http::timeout(None)).await {
+                    Ok(feeds) if feeds.len() == 1 => {
+                        self.set_feed(&feeds[0]);
+                        continue;
+                    }
+                    Ok(feeds) => {
+                        let title = format!("{} feeds found", feeds.len());
+                        self.choice
+                            .open(title, feeds.iter().map(|f| f.label()).collect());
+                        self.feed_choices = feeds;
+                        Check::Invalid("pick one of the feeds found".to_string())
+                    }
+                    Err(e) => Check::Invalid(format!("{:#}", e)),
+                },
+            };
+            self.fields[idx].check = check;
+        }
+    }
+
+    fn set_feed(&mut self, feed: &rss::DiscoveredFeed) {
+        if let Some(field) = self.fields.iter_mut().find(|f| f.id == FieldId::Feed) {
+            field.value = feed.url.clone();
+            field.check = Check::Valid(feed.title.clone().unwrap_or_default());
+        }
+        self.feed_choices.clear();
+    }
+
+    /// The config to write, with widgets placed `COLUMNS` to a row in layout
+    /// order
+    fn config(&self) -> Config {
+        let general = GeneralConfig {
+            refresh_interval_secs: self.value(FieldId::Refresh).parse().unwrap_or(60),
+            theme: self.value(FieldId::Theme).to_string(),
+            ..GeneralConfig::default()
+        };
+        let widgets = self
+            .order
+            .iter()
+            .enumerate()
+            .map(|(i, &kind)| self.widget(kind, i / COLUMNS, i % COLUMNS))
+            .collect();
+        Config {
+            general,
+            widgets,
+            ..Config::default()
+        }
+    }
+
+    fn widget(&self, kind: Kind, row: usize, col: usize) -> WidgetConfig {
+        let mut widget = match kind {
+            Kind::Creature => json!({ "type": "creature", "show_on_startup": true }),
+            Kind::Hackernews => json!({
+                "type": "hackernews",
+                "story_count": 10,
+                "story_type": "top",
+            }),
+            Kind::Stocks => json!({
+                "type": "stocks",
+                "symbols": list(self.value(FieldId::Symbols))
+                    .iter()
+                    .map(|s| s.to_uppercase())
+                    .collect::<Vec<_>>(),
+            }),
+            Kind::Rss => json!({
+                "type": "rss",
+                "feeds": [self.value(FieldId::Feed)],
+                "max_items": 10,
+            }),
+            Kind::Sports => json!({
+                "type": "sports",
+                "leagues": list(&self.value(FieldId::Leagues).to_lowercase()),
+            }),
+            Kind::Github => json!({
+                "type": "github",
+                "token": self.value(FieldId::GithubToken),
+                "username": self.value(FieldId::GithubUser),
+            }),
+            Kind::Youtube => {
+                let search = self.value(FieldId::YoutubeSearch);
+                json!({
+                    "type": "youtube",
+                    "api_key": self.value(FieldId::YoutubeKey),
+                    "search_query": (!search.is_empty()).then_some(search),
+                })
+            }
+        };
+        widget["title"] = json!(kind.title());
+        widget["position"] = json!({ "row": row, "col": col });
+        serde_json::from_value(widget).expect("wizard widgets have every required field")
+    }
+
+    fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(3),
+                Constraint::Length(2),
+            ])
+            .split(area);
+
+        let header = Line::from(vec![
+            Span::styled(
+                " feedtui setup ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("step {}/4: {}", self.step.number(), self.step.name()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(header), chunks[0]);
+
+        match self.step {
+            Step::General | Step::Details => self.render_fields(frame, chunks[1]),
+            Step::Widgets => self.render_widgets(frame, chunks[1]),
+            Step::Layout => self.render_layout(frame, chunks[1]),
+        }
+
+        let help = match self.step {
+            Step::General => "↑↓ move | ←→ change theme | Enter next | Esc quit",
+            Step::Widgets => "↑↓ move | Space toggle | Enter next | Esc back",
+            Step::Details => "↑↓ move | type to edit | Enter check and continue | Esc back",
+            Step::Layout => "↑↓ select | J/K or Shift+↑↓ move widget | Enter save | Esc back",
+        };
+        let mut footer = vec![Line::styled(help, Style::default().fg(Color::DarkGray))];
+        if let Some(message) = &self.message {
+            footer.insert(
+                0,
+                Line::styled(message.as_str(), Style::default().fg(Color::Yellow)),
+            );
+        }
+        frame.render_widget(Paragraph::new(footer), chunks[2]);
+
+        self.choice.render(frame, area);
+    }
+
+    fn render_fields(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = Vec::new();
+        for (row, &idx) in self.visible_fields().iter().enumerate() {
+            let field = &self.fields[idx];
+            let selected = row == self.cursor;
+            let value = if field.id.secret() {
+                "•".repeat(field.value.chars().count())
+            } else {
+                field.value.clone()
+            };
+            let (mark, note, color) = match &field.check {
+                Check::Unchecked => (" ", String::new(), Color::DarkGray),
+                Check::Valid(note) => ("✓", note.clone(), palette::palette().positive),
+                Check::Invalid(note) => ("✗", note.clone(), palette::palette().negative),
+            };
+            let label_style = if selected {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let cursor = if selected && field.id != FieldId::Theme {
+                "▏"
+            } else {
+                ""
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", mark), Style::default().fg(color)),
+                Span::styled(format!("{:<28}", field.id.label()), label_style),
+                Span::styled(value, Style::default().fg(Color::White)),
+                Span::styled(cursor, Style::default().fg(Color::Cyan)),
+            ]));
+            if !note.is_empty() {
+                lines.push(Line::styled(
+                    format!("    {}", note),
+                    Style::default().fg(color),
+                ));
+            }
+        }
+        let title = match self.step {
+            Step::General => " General ",
+            _ => " Widget settings ",
+        };
+        let form = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(form, area);
+    }
+
+    fn render_widgets(&self, frame: &mut Frame, area: Rect) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(area);
+
+        let items: Vec<ListItem> = Kind::ALL
+            .iter()
+            .zip(&self.enabled)
+            .map(|(kind, enabled)| {
+                let mark = if *enabled { "[x]" } else { "[ ]" };
+                ListItem::new(format!("{} {}", mark, kind.name()))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().title(" Widgets ").borders(Borders::ALL))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let mut state = ListState::default().with_selected(Some(self.cursor));
+        frame.render_stateful_widget(list, columns[0], &mut state);
+
+        let kind = Kind::ALL[self.cursor];
+        let preview = Paragraph::new(
+            kind.preview()
+                .iter()
+                .map(|line| Line::raw(*line))
+                .collect::<Vec<_>>(),
+        )
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .title(format!(" Preview: {} ", kind.title()))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(preview, columns[1]);
+    }
+
+    fn render_layout(&self, frame: &mut Frame, area: Rect) {
+        let placements: Vec<Placement> = (0..self.order.len())
+            .map(|i| Placement {
+                idx: i,
+                row: i / COLUMNS,
+                col: i % COLUMNS,
+                priority: 0,
+            })
+            .collect();
+        for (idx, rect) in layout::arrange(area, &placements, self.cursor, 0) {
+            let kind = self.order[idx];
+            let border = if idx == self.cursor {
+                Style::default().fg(Color::Cyan)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            let preview = Paragraph::new(
+                kind.preview()
+                    .iter()
+                    .map(|line| Line::raw(*line))
+                    .collect::<Vec<_>>(),
+            )
+            .block(
+                Block::default()
+                    .title(format!(" {} ", kind.title()))
+                    .borders(Borders::ALL)
+                    .border_style(border),
+            );
+            frame.render_widget(preview, rect);
+        }
+    }
+}
+
+/// Comma-separated entries, trimmed, without empty ones
+fn list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+async fn key_check(kind: ApiKey, key: &str) -> Check {
+    match doctor::validate_key(kind, key).await {
+        Ok(()) => Check::Valid(String::new()),
+        Err(problem) => Check::Invalid(problem),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn written_config_reads_back() {
+        let mut wizard = Wizard::new();
+        wizard.enabled = vec![true; Kind::ALL.len()];
+        wizard.go_to(Step::Layout);
+        wizard.fields[2].value = "aapl, googl,,MSFT ".to_string();
+
+        let written = toml::to_string_pretty(&wizard.config()).unwrap();
+        let config: Config = toml::from_str(&written).unwrap();
+        assert_eq!(config.widgets.len(), Kind::ALL.len());
+        match &config.widgets[2] {
+            WidgetConfig::Stocks(cfg) => {
+                assert_eq!(cfg.symbols, ["AAPL", "GOOGL", "MSFT"]);
+                assert_eq!((cfg.position.row, cfg.position.col), (0, 2));
+            }
+            other => panic!("expected stocks, got {}", other.kind()),
+        }
+        assert!(config.widgets.iter().all(|w| w.id().is_empty()));
+    }
+}